@@ -275,6 +275,14 @@ pub enum Error {
 	#[error("Invalid regular expression: {0:?}")]
 	InvalidRegex(String),
 
+	/// The GeoJSON could not be converted to a geometry
+	#[error("Invalid GeoJSON: {0}")]
+	InvalidGeoJson(String),
+
+	/// The ISO 8601 duration could not be converted to a duration
+	#[error("Invalid ISO 8601 duration: {0}")]
+	InvalidIsoDuration(String),
+
 	/// Invalid timeout
 	#[error("Invalid timeout: {0:?} seconds")]
 	InvalidTimeout(u64),
@@ -609,6 +617,14 @@ pub enum Error {
 		check: String,
 	},
 
+	/// The specified value did not conform to the kind it was validated against
+	#[error("Found {value} for `{path}`, but expected a {check}")]
+	KindCheck {
+		value: String,
+		path: String,
+		check: String,
+	},
+
 	/// The specified field did not conform to the field ASSERT clause
 	#[error(
 		"Found changed value for field `{field}`, with record `{thing}`, but field is readonly"
@@ -1234,6 +1250,13 @@ pub enum Error {
 	/// Tried to use an idiom RepeatRecurse symbol in a position where it is not supported
 	#[error("Can not construct a recursion plan when an instruction is provided")]
 	RecursionInstructionPlanConflict,
+
+	/// A parsed value exceeded one of the limits it was parsed with
+	#[error("The parsed value exceeded the maximum {limit} of {max}")]
+	ParseLimitExceeded {
+		limit: &'static str,
+		max: usize,
+	},
 }
 
 impl From<Error> for String {
@@ -337,6 +337,7 @@ pub async fn db_user(
 				ns: Some(ns.to_owned()),
 				db: Some(db.to_owned()),
 				id: Some(user),
+				roles: Some(u.roles.iter().map(|v| v.to_string()).collect()),
 				..Claims::default()
 			};
 			// Log the authenticated database info
@@ -421,6 +422,7 @@ pub async fn ns_user(
 				jti: Some(Uuid::new_v4().to_string()),
 				ns: Some(ns.to_owned()),
 				id: Some(user),
+				roles: Some(u.roles.iter().map(|v| v.to_string()).collect()),
 				..Claims::default()
 			};
 			// Log the authenticated namespace info
@@ -470,6 +472,7 @@ pub async fn root_user(
 				exp: expiration(u.duration.token)?,
 				jti: Some(Uuid::new_v4().to_string()),
 				id: Some(user),
+				roles: Some(u.roles.iter().map(|v| v.to_string()).collect()),
 				..Claims::default()
 			};
 			// Log the authenticated root info
@@ -140,6 +140,57 @@ impl Array {
 	pub fn is_empty(&self) -> bool {
 		self.0.is_empty()
 	}
+	/// Split the array into owned arrays of `size` values each.
+	///
+	/// The last array holds the remainder and may be shorter than `size`.
+	/// Returns no arrays if `size` is zero.
+	pub fn chunks(&self, size: usize) -> Vec<Array> {
+		if size == 0 {
+			return Vec::new();
+		}
+		self.0.chunks(size).map(|c| Array(c.to_vec())).collect()
+	}
+	/// Return every contiguous run of `size` values as an owned array.
+	///
+	/// Returns no arrays if `size` is zero or larger than the array.
+	pub fn windows(&self, size: usize) -> Vec<Array> {
+		if size == 0 {
+			return Vec::new();
+		}
+		self.0.windows(size).map(|w| Array(w.to_vec())).collect()
+	}
+	/// Flatten nested arrays up to `depth` levels deep.
+	///
+	/// Arrays nested deeper than `depth` are kept as they are, and a
+	/// `depth` of zero returns the array unchanged. Each level is
+	/// flattened in turn rather than recursively, so deeply nested
+	/// input can not exhaust the stack.
+	pub fn flatten_deep(self, depth: usize) -> Array {
+		let mut out = self;
+		for _ in 0..depth {
+			if !out.iter().any(|v| matches!(v, Value::Array(_))) {
+				break;
+			}
+			out = Flatten::flatten(out);
+		}
+		out
+	}
+	/// Binary search the array for a value with the `target` key.
+	///
+	/// The array must already be sorted by the same key, such as a
+	/// result ordered by that field, otherwise the result is
+	/// meaningless. Returns the index of a matching value, or the
+	/// index where one could be inserted to keep the array sorted.
+	///
+	/// This is named apart from the `binary_search_by_key` slice method,
+	/// which is reachable through `Deref` and takes the key by value.
+	pub fn search_sorted_by_key<K: Ord, F: Fn(&Value) -> K>(
+		&self,
+		target: &K,
+		key: F,
+	) -> Result<usize, usize> {
+		self.0.binary_search_by(|v| key(v).cmp(target))
+	}
 }
 
 impl Array {
@@ -540,3 +591,77 @@ impl Windows<Array> for Array {
 			.into())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::Array;
+	use crate::sql::{Part, Value};
+	use crate::syn::Parse;
+
+	#[test]
+	fn chunks_exact() {
+		let arr = Array::from(vec![1, 2, 3, 4]);
+		let res = arr.chunks(2);
+		assert_eq!(res, vec![Array::from(vec![1, 2]), Array::from(vec![3, 4])]);
+	}
+
+	#[test]
+	fn chunks_remainder() {
+		let arr = Array::from(vec![1, 2, 3, 4, 5]);
+		let res = arr.chunks(2);
+		assert_eq!(
+			res,
+			vec![Array::from(vec![1, 2]), Array::from(vec![3, 4]), Array::from(vec![5])]
+		);
+	}
+
+	#[test]
+	fn chunks_oversized() {
+		let arr = Array::from(vec![1, 2, 3]);
+		assert_eq!(arr.chunks(10), vec![arr.clone()]);
+		assert!(Array::new().chunks(10).is_empty());
+	}
+
+	#[test]
+	fn chunks_zero() {
+		assert!(Array::from(vec![1, 2, 3]).chunks(0).is_empty());
+	}
+
+	#[test]
+	fn windows_sizes() {
+		let arr = Array::from(vec![1, 2, 3]);
+		assert_eq!(arr.windows(2), vec![Array::from(vec![1, 2]), Array::from(vec![2, 3])]);
+		assert_eq!(arr.windows(3), vec![arr.clone()]);
+		assert!(arr.windows(4).is_empty());
+		assert!(arr.windows(0).is_empty());
+	}
+
+	#[test]
+	fn flatten_deep_levels() {
+		let arr = Array::parse("[1, [2, [3, [4]]], 5]");
+		assert_eq!(arr.clone().flatten_deep(0), arr);
+		assert_eq!(arr.clone().flatten_deep(1), Array::parse("[1, 2, [3, [4]], 5]"));
+		assert_eq!(arr.clone().flatten_deep(2), Array::parse("[1, 2, 3, [4], 5]"));
+		assert_eq!(arr.clone().flatten_deep(3), Array::parse("[1, 2, 3, 4, 5]"));
+	}
+
+	#[test]
+	fn flatten_deep_beyond_nesting() {
+		let arr = Array::parse("[[1, []], [[2]], 3]");
+		assert_eq!(arr.clone().flatten_deep(10), Array::parse("[1, 2, 3]"));
+		assert_eq!(arr.flatten_deep(usize::MAX), Array::parse("[1, 2, 3]"));
+		assert_eq!(Array::new().flatten_deep(5), Array::new());
+	}
+
+	#[test]
+	fn search_sorted_by_key_present_and_absent() {
+		let arr = Array::parse("[{ id: 1 }, { id: 3 }, { id: 5 }, { id: 7 }]");
+		let id = |v: &Value| v.pick(&[Part::from("id")]);
+		assert_eq!(arr.search_sorted_by_key(&Value::from(1), id), Ok(0));
+		assert_eq!(arr.search_sorted_by_key(&Value::from(5), id), Ok(2));
+		assert_eq!(arr.search_sorted_by_key(&Value::from(0), id), Err(0));
+		assert_eq!(arr.search_sorted_by_key(&Value::from(4), id), Err(2));
+		assert_eq!(arr.search_sorted_by_key(&Value::from(8), id), Err(4));
+		assert_eq!(Array::new().search_sorted_by_key(&Value::from(1), id), Err(0));
+	}
+}
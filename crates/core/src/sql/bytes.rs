@@ -1,4 +1,8 @@
-use base64::{engine::general_purpose::STANDARD_NO_PAD, Engine};
+use crate::err::Error;
+use base64::{
+	engine::general_purpose::{STANDARD_NO_PAD, URL_SAFE_NO_PAD},
+	Engine,
+};
 use revision::revisioned;
 use serde::{
 	de::{self, Visitor},
@@ -17,6 +21,32 @@ impl Bytes {
 	pub fn into_inner(self) -> Vec<u8> {
 		self.0
 	}
+
+	/// Encode these bytes as URL-safe base64, without padding
+	pub fn to_base64(&self) -> String {
+		URL_SAFE_NO_PAD.encode(&self.0)
+	}
+
+	/// Decode bytes from URL-safe base64, without padding
+	pub fn from_base64(s: &str) -> Result<Bytes, Error> {
+		match URL_SAFE_NO_PAD.decode(s) {
+			Ok(v) => Ok(Bytes(v)),
+			Err(_) => Err(Error::TryFrom(s.to_owned(), "base64 bytes")),
+		}
+	}
+
+	/// Encode these bytes as a lowercase hexadecimal string
+	pub fn to_hex(&self) -> String {
+		hex::encode(&self.0)
+	}
+
+	/// Decode bytes from a hexadecimal string, in either case
+	pub fn from_hex(s: &str) -> Result<Bytes, Error> {
+		match hex::decode(s) {
+			Ok(v) => Ok(Bytes(v)),
+			Err(_) => Err(Error::TryFrom(s.to_owned(), "hex bytes")),
+		}
+	}
 }
 
 impl From<Vec<u8>> for Bytes {
@@ -93,4 +123,27 @@ mod tests {
 		let deserialized = Value::from(serialized);
 		assert_eq!(val, deserialized);
 	}
+
+	#[test]
+	fn base64() {
+		let val = Bytes(vec![0, 1, 2, 250, 251, 255]);
+		let encoded = val.to_base64();
+		assert_eq!(encoded, "AAEC-vv_");
+		assert_eq!(Bytes::from_base64(&encoded).unwrap(), val);
+		assert_eq!(Bytes::default().to_base64(), "");
+		assert_eq!(Bytes::from_base64("").unwrap(), Bytes::default());
+		assert!(Bytes::from_base64("AAEC+vv/").is_err());
+	}
+
+	#[test]
+	fn hex() {
+		let val = Bytes(vec![0, 1, 2, 250, 251, 255]);
+		let encoded = val.to_hex();
+		assert_eq!(encoded, "000102fafbff");
+		assert_eq!(Bytes::from_hex(&encoded).unwrap(), val);
+		assert_eq!(Bytes::from_hex("000102FAFBFF").unwrap(), val);
+		assert_eq!(Bytes::default().to_hex(), "");
+		assert_eq!(Bytes::from_hex("").unwrap(), Bytes::default());
+		assert!(Bytes::from_hex("0g").is_err());
+	}
 }
@@ -20,6 +20,16 @@ pub struct Closure {
 }
 
 impl Closure {
+	/// The number of parameters this closure accepts
+	pub fn arity(&self) -> usize {
+		self.args.len()
+	}
+
+	/// The kinds of the parameters of this closure, in order
+	pub fn param_kinds(&self) -> impl ExactSizeIterator<Item = &Kind> {
+		self.args.iter().map(|(_, kind)| kind)
+	}
+
 	pub(crate) async fn compute(
 		&self,
 		stk: &mut Stk,
@@ -84,3 +94,25 @@ impl fmt::Display for Closure {
 		write!(f, " {}", self.body)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::syn::Parse;
+
+	#[test]
+	fn arity_and_param_kinds() {
+		let value = Value::parse("|$a: int, $b: option<string>| $a");
+		let closure = value.as_closure().unwrap();
+		assert_eq!(closure.arity(), 2);
+		assert_eq!(
+			closure.param_kinds().collect::<Vec<_>>(),
+			[&Kind::Int, &Kind::Option(Box::new(Kind::String))]
+		);
+	}
+
+	#[test]
+	fn not_a_closure() {
+		assert_eq!(Value::parse("42").as_closure(), None);
+	}
+}
@@ -2,7 +2,7 @@ use crate::err::Error;
 use crate::sql::duration::Duration;
 use crate::sql::strand::Strand;
 use crate::syn;
-use chrono::{offset::LocalResult, DateTime, SecondsFormat, TimeZone, Utc};
+use chrono::{offset::LocalResult, DateTime, Datelike, NaiveDate, SecondsFormat, TimeZone, Utc};
 use revision::revisioned;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
@@ -93,7 +93,45 @@ impl Deref for Datetime {
 	}
 }
 
+/// Numeric timestamps with an absolute value at or above this threshold are
+/// treated as milliseconds since the Unix epoch, rather than seconds. As
+/// seconds, this threshold is in the year 5138, whereas as milliseconds it
+/// is in the year 1973.
+const EPOCH_MILLIS_THRESHOLD: u64 = 100_000_000_000;
+
 impl Datetime {
+	/// Parse a datetime from one of several commonly used formats
+	///
+	/// The formats are attempted in the following order:
+	///
+	/// 1. An RFC 3339 datetime, such as `2024-01-31T12:30:00Z`
+	/// 2. A date, such as `2024-01-31`, which is interpreted as midnight UTC
+	/// 3. A number of seconds or milliseconds since the Unix epoch
+	///
+	/// Numbers with an absolute value of at least 100,000,000,000 are
+	/// interpreted as milliseconds, and all other numbers as seconds.
+	pub fn parse_flexible(s: &str) -> Result<Datetime, Error> {
+		let s = s.trim();
+		if let Ok(v) = DateTime::parse_from_rfc3339(s) {
+			return Ok(Self(v.with_timezone(&Utc)));
+		}
+		if let Ok(v) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+			if let Some(v) = v.and_hms_opt(0, 0, 0) {
+				return Ok(Self(v.and_utc()));
+			}
+		}
+		if let Ok(v) = s.parse::<i64>() {
+			let v = match v.unsigned_abs() >= EPOCH_MILLIS_THRESHOLD {
+				true => Utc.timestamp_millis_opt(v),
+				false => Utc.timestamp_opt(v, 0),
+			};
+			if let LocalResult::Single(v) = v {
+				return Ok(Self(v));
+			}
+		}
+		Err(Error::TryFrom(s.to_owned(), "datetime"))
+	}
+
 	/// Convert the Datetime to a raw String
 	pub fn to_raw(&self) -> String {
 		self.0.to_rfc3339_opts(SecondsFormat::AutoSi, true)
@@ -108,6 +146,50 @@ impl Datetime {
 	pub fn to_i64(&self) -> Option<i64> {
 		self.0.timestamp_nanos_opt()
 	}
+
+	/// Returns the ISO 8601 week number, from 1 to 53
+	///
+	/// Weeks start on Monday, and the first week of a year is the one containing its first
+	/// Thursday. Dates in late December or early January may therefore belong to a week of
+	/// the adjacent year, as given by [`iso_year`](Datetime::iso_year).
+	pub fn iso_week_number(&self) -> u32 {
+		self.0.iso_week().week()
+	}
+
+	/// Returns the ISO 8601 week-numbering year
+	///
+	/// This differs from the calendar year for dates in a week which spans the new year. For
+	/// example, 2024-12-30 is in week 1 of 2025, and 2021-01-01 is in week 53 of 2020.
+	pub fn iso_year(&self) -> i32 {
+		self.0.iso_week().year()
+	}
+
+	/// Returns the quarter of the year, from 1 to 4
+	pub fn quarter(&self) -> u8 {
+		(self.0.month0() / 3 + 1) as u8
+	}
+
+	/// Returns the day of the year, from 1 to 366
+	pub fn day_of_year(&self) -> u32 {
+		self.0.ordinal()
+	}
+
+	/// Iterates from `start` up to, but excluding, `end` in increments of `step`
+	///
+	/// Yields nothing if the step is zero or if `start` is not before `end`. Iteration
+	/// stops early if a step would go beyond the range of a datetime.
+	pub fn step_range(
+		start: Datetime,
+		end: Datetime,
+		step: Duration,
+	) -> impl Iterator<Item = Datetime> {
+		let step =
+			chrono::Duration::from_std(step.0).ok().filter(|s| *s > chrono::Duration::zero());
+		let first = step.map(|_| start.0);
+		std::iter::successors(first, move |v| v.checked_add_signed(step?))
+			.take_while(move |v| *v < end.0)
+			.map(Datetime::from)
+	}
 }
 
 impl Display for Datetime {
@@ -135,3 +217,123 @@ impl TrySub for Datetime {
 			.map(Duration::from)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_flexible_rfc3339() {
+		let dt = Datetime::parse_flexible("2024-01-31T12:30:00+01:00").unwrap();
+		assert_eq!(dt.to_raw(), "2024-01-31T11:30:00Z");
+	}
+
+	#[test]
+	fn parse_flexible_date() {
+		let dt = Datetime::parse_flexible("2024-01-31").unwrap();
+		assert_eq!(dt.to_raw(), "2024-01-31T00:00:00Z");
+	}
+
+	#[test]
+	fn parse_flexible_epoch_seconds() {
+		let dt = Datetime::parse_flexible("1706704200").unwrap();
+		assert_eq!(dt.to_raw(), "2024-01-31T12:30:00Z");
+	}
+
+	#[test]
+	fn parse_flexible_epoch_millis() {
+		let dt = Datetime::parse_flexible("1706704200123").unwrap();
+		assert_eq!(dt.to_raw(), "2024-01-31T12:30:00.123Z");
+	}
+
+	#[test]
+	fn iso_week_year_boundaries() {
+		for (date, week, year) in [
+			// The last days of December can belong to the first week of the next year
+			("2024-12-29", 52, 2024),
+			("2024-12-30", 1, 2025),
+			("2025-12-29", 1, 2026),
+			// The first days of January can belong to the last week of the previous year
+			("2021-01-01", 53, 2020),
+			("2021-01-03", 53, 2020),
+			("2021-01-04", 1, 2021),
+			("2023-01-01", 52, 2022),
+			// Years starting on a Thursday have 53 weeks
+			("2026-12-31", 53, 2026),
+		] {
+			let dt = Datetime::parse_flexible(date).unwrap();
+			assert_eq!(dt.iso_week_number(), week, "{date}");
+			assert_eq!(dt.iso_year(), year, "{date}");
+		}
+	}
+
+	#[test]
+	fn quarter() {
+		for (date, quarter) in [
+			("2024-01-01", 1),
+			("2024-03-31", 1),
+			("2024-04-01", 2),
+			("2024-06-30", 2),
+			("2024-07-01", 3),
+			("2024-09-30", 3),
+			("2024-10-01", 4),
+			("2024-12-31", 4),
+		] {
+			assert_eq!(Datetime::parse_flexible(date).unwrap().quarter(), quarter, "{date}");
+		}
+	}
+
+	#[test]
+	fn day_of_year_leap_years() {
+		for (date, day) in [
+			("2024-01-01", 1),
+			("2024-02-29", 60),
+			("2024-03-01", 61),
+			("2024-12-31", 366),
+			("2023-03-01", 60),
+			("2023-12-31", 365),
+			("2000-12-31", 366),
+			("1900-12-31", 365),
+		] {
+			assert_eq!(Datetime::parse_flexible(date).unwrap().day_of_year(), day, "{date}");
+		}
+	}
+
+	#[test]
+	fn parse_flexible_invalid() {
+		assert!(Datetime::parse_flexible("not a datetime").is_err());
+		assert!(Datetime::parse_flexible("2024-13-01").is_err());
+		assert!(Datetime::parse_flexible("").is_err());
+	}
+
+	#[test]
+	fn parse_flexible_extreme_timestamps() {
+		assert!(Datetime::parse_flexible(&i64::MIN.to_string()).is_err());
+		assert!(Datetime::parse_flexible(&i64::MAX.to_string()).is_err());
+	}
+
+	#[test]
+	fn step_range_hourly() {
+		let start = Datetime::try_from("2024-01-31T00:00:00Z").unwrap();
+		let end = Datetime::try_from("2024-02-01T00:00:00Z").unwrap();
+		let hours: Vec<Datetime> =
+			Datetime::step_range(start.clone(), end.clone(), Duration::from_hours(1)).collect();
+		assert_eq!(hours.len(), 24);
+		assert_eq!(hours[0], start);
+		assert_eq!(hours[23].to_raw(), "2024-01-31T23:00:00Z");
+		let steps = Datetime::step_range(start.clone(), end, Duration::from_mins(90));
+		assert_eq!(steps.last().unwrap().to_raw(), "2024-01-31T22:30:00Z");
+	}
+
+	#[test]
+	fn step_range_empty() {
+		let start = Datetime::try_from("2024-01-31T00:00:00Z").unwrap();
+		let end = Datetime::try_from("2024-02-01T00:00:00Z").unwrap();
+		let zero = Datetime::step_range(start.clone(), end.clone(), Duration::default());
+		assert_eq!(zero.count(), 0);
+		let reversed = Datetime::step_range(end.clone(), start.clone(), Duration::from_hours(1));
+		assert_eq!(reversed.count(), 0);
+		let same = Datetime::step_range(start.clone(), start, Duration::from_hours(1));
+		assert_eq!(same.count(), 0);
+	}
+}
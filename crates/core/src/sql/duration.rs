@@ -166,6 +166,112 @@ impl Duration {
 	pub fn from_weeks(days: u64) -> Duration {
 		time::Duration::from_secs(days * SECONDS_PER_WEEK).into()
 	}
+	/// Convert the duration to an ISO 8601 duration, such as `PT1H30M`
+	///
+	/// Years and weeks are written as days, as ISO 8601 does not allow weeks to be
+	/// combined with other units. Anything below a second is written as a fraction
+	/// of the seconds.
+	pub fn to_iso8601(&self) -> String {
+		let secs = self.0.as_secs();
+		let nano = self.0.subsec_nanos();
+		// Ensure no empty output
+		if secs == 0 && nano == 0 {
+			return "PT0S".to_owned();
+		}
+		let days = secs / SECONDS_PER_DAY;
+		let secs = secs % SECONDS_PER_DAY;
+		let hour = secs / SECONDS_PER_HOUR;
+		let secs = secs % SECONDS_PER_HOUR;
+		let mins = secs / SECONDS_PER_MINUTE;
+		let secs = secs % SECONDS_PER_MINUTE;
+		let mut out = "P".to_owned();
+		if days > 0 {
+			out.push_str(&format!("{days}D"));
+		}
+		if hour == 0 && mins == 0 && secs == 0 && nano == 0 {
+			return out;
+		}
+		out.push('T');
+		if hour > 0 {
+			out.push_str(&format!("{hour}H"));
+		}
+		if mins > 0 {
+			out.push_str(&format!("{mins}M"));
+		}
+		if secs > 0 || nano > 0 {
+			out.push_str(&secs.to_string());
+			if nano > 0 {
+				let fraction = format!("{nano:09}");
+				out.push('.');
+				out.push_str(fraction.trim_end_matches('0'));
+			}
+			out.push('S');
+		}
+		out
+	}
+	/// Parse an ISO 8601 duration, such as `PT1H30M` or `P1W`
+	///
+	/// Years are read as 365 days, like the `y` unit of SurrealQL, while months are
+	/// rejected as they have no fixed length. Only the seconds may have a fraction,
+	/// with either a `.` or a `,` as the decimal separator.
+	pub fn from_iso8601(s: &str) -> Result<Duration, Error> {
+		let invalid = |message: &str| Error::InvalidIsoDuration(message.to_owned());
+		let Some(rest) = s.strip_prefix('P') else {
+			return Err(invalid("expected the duration to start with `P`"));
+		};
+		let (date, time) = match rest.split_once('T') {
+			Some((_, "")) => return Err(invalid("expected a time component after `T`")),
+			Some((date, time)) => (date, time),
+			None if rest.is_empty() => return Err(invalid("expected at least one component")),
+			None => (rest, ""),
+		};
+		let date_units = [('Y', SECONDS_PER_YEAR), ('W', SECONDS_PER_WEEK), ('D', SECONDS_PER_DAY)];
+		let time_units = [('H', SECONDS_PER_HOUR), ('M', SECONDS_PER_MINUTE), ('S', 1)];
+		let mut secs = 0u64;
+		let mut nano = 0u32;
+		for (mut part, units, is_time) in [(date, date_units, false), (time, time_units, true)] {
+			// Units must appear in order, and at most once
+			let mut units = units.iter();
+			while !part.is_empty() {
+				let Some(end) = part.find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')
+				else {
+					return Err(invalid("expected a unit after each number"));
+				};
+				let (number, rest) = part.split_at(end);
+				let unit = rest.chars().next().unwrap_or_default();
+				part = &rest[unit.len_utf8()..];
+				if unit == 'M' && !is_time {
+					return Err(invalid("months are not supported as they have no fixed length"));
+				}
+				let Some((_, multiplier)) = units.find(|(u, _)| *u == unit) else {
+					return Err(Error::InvalidIsoDuration(format!("unexpected unit `{unit}`")));
+				};
+				let (whole, fraction) = match number.split_once(['.', ',']) {
+					Some((whole, fraction)) => (whole, Some(fraction)),
+					None => (number, None),
+				};
+				let whole: u64 =
+					whole.parse().map_err(|_| invalid("expected a number before each unit"))?;
+				secs = whole
+					.checked_mul(*multiplier)
+					.and_then(|v| secs.checked_add(v))
+					.ok_or_else(|| invalid("the duration is too large"))?;
+				if let Some(fraction) = fraction {
+					if unit != 'S' || !is_time {
+						return Err(invalid("only the seconds may have a fraction"));
+					}
+					if fraction.is_empty()
+						|| fraction.len() > 9
+						|| !fraction.bytes().all(|b| b.is_ascii_digit())
+					{
+						return Err(invalid("expected up to nine digits in the fraction"));
+					}
+					nano = format!("{fraction:0<9}").parse().unwrap_or_default();
+				}
+			}
+		}
+		Ok(Duration::new(secs, nano))
+	}
 }
 
 impl fmt::Display for Duration {
@@ -385,3 +491,47 @@ impl InfoStructure for Duration {
 		self.to_string().into()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn iso8601_round_trip() {
+		let cases = [
+			("PT1H30M", Duration::from_mins(90)),
+			("P2D", Duration::from_days(2)),
+			("P1DT12H", Duration::from_hours(36)),
+			("PT1.5S", Duration::from_millis(1500)),
+			("PT0.000000001S", Duration::from_nanos(1)),
+			("P400DT1M0.25S", Duration::new(400 * SECONDS_PER_DAY + 60, 250_000_000)),
+			("PT0S", Duration::default()),
+		];
+		for (iso, duration) in cases {
+			assert_eq!(duration.to_iso8601(), iso);
+			assert_eq!(Duration::from_iso8601(iso).unwrap(), duration, "{iso}");
+		}
+	}
+
+	#[test]
+	fn iso8601_units() {
+		let parse = |s: &str| Duration::from_iso8601(s).unwrap();
+		assert_eq!(parse("P1W"), Duration::from_weeks(1));
+		assert_eq!(parse("P1Y2W3D"), Duration::from_days(365 + 14 + 3));
+		assert_eq!(parse("PT90M"), Duration::from_mins(90));
+		assert_eq!(parse("PT1,25S"), Duration::from_millis(1250));
+		assert_eq!(parse("P1W").to_iso8601(), "P7D");
+	}
+
+	#[test]
+	fn iso8601_invalid() {
+		for iso in [
+			"", "1H", "P", "PT", "P1M", "P1", "PT1.5M", "P1.5D", "PT1S1H", "PT1H1H", "P-1D",
+			"PT1.S",
+		] {
+			assert!(Duration::from_iso8601(iso).is_err(), "{iso}");
+		}
+		assert!(Duration::from_iso8601("P99999999999999999999D").is_err());
+	}
+}
@@ -53,6 +53,32 @@ impl Expression {
 }
 
 impl Expression {
+	/// Checks whether this is a binary expression which binds weaker than the given operator.
+	fn binds_weaker_than(&self, o: &Operator) -> bool {
+		match self {
+			Self::Binary {
+				o: inner,
+				..
+			} => inner.binding_power() < o.binding_power(),
+			Self::Unary {
+				..
+			} => false,
+		}
+	}
+
+	/// Checks whether this is a binary expression which binds as tightly as the given operator.
+	fn binds_as(&self, o: &Operator) -> bool {
+		match self {
+			Self::Binary {
+				o: inner,
+				..
+			} => inner.binding_power() == o.binding_power(),
+			Self::Unary {
+				..
+			} => false,
+		}
+	}
+
 	pub(crate) fn writeable(&self) -> bool {
 		match self {
 			Self::Unary {
@@ -202,12 +228,76 @@ impl fmt::Display for Expression {
 			Self::Unary {
 				o,
 				v,
-			} => write!(f, "{o}{v}"),
+			} => {
+				write!(f, "{o}")?;
+				// Binary operands bind weaker than any unary operator.
+				match v {
+					Value::Expression(x) if matches!(**x, Self::Binary { .. }) => {
+						write!(f, "({v})")
+					}
+					_ => write!(f, "{v}"),
+				}
+			}
 			Self::Binary {
 				l,
 				o,
 				r,
-			} => write!(f, "{l} {o} {r}"),
+			} => {
+				// Expressions are left associative, and relations can not be chained, so the
+				// operands are wrapped in parens where they would otherwise parse differently.
+				match l {
+					Value::Expression(x)
+						if x.binds_weaker_than(o)
+							|| (o.is_relation() && x.operator().is_relation()) =>
+					{
+						write!(f, "({l})")?
+					}
+					_ => write!(f, "{l}")?,
+				}
+				write!(f, " {o} ")?;
+				match r {
+					Value::Expression(x) if x.binds_weaker_than(o) || x.binds_as(o) => {
+						write!(f, "({r})")
+					}
+					_ => write!(f, "{r}"),
+				}
+			}
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sql::Number;
+
+	fn binary(l: impl Into<Value>, o: Operator, r: impl Into<Value>) -> Value {
+		Value::Expression(Box::new(Expression::new(l.into(), o, r.into())))
+	}
+
+	#[test]
+	fn display_respects_precedence() {
+		let add = binary(Number::Int(1), Operator::Add, Number::Int(2));
+		let mul = binary(add.clone(), Operator::Mul, Number::Int(3));
+		assert_eq!(mul.to_string(), "(1 + 2) * 3");
+		let mul = binary(Number::Int(3), Operator::Mul, add.clone());
+		assert_eq!(mul.to_string(), "3 * (1 + 2)");
+		let sub = binary(Number::Int(3), Operator::Sub, add.clone());
+		assert_eq!(sub.to_string(), "3 - (1 + 2)");
+		let sub = binary(add.clone(), Operator::Sub, Number::Int(3));
+		assert_eq!(sub.to_string(), "1 + 2 - 3");
+		let neg = Value::Expression(Box::new(Expression::Unary {
+			o: Operator::Neg,
+			v: add,
+		}));
+		assert_eq!(neg.to_string(), "-(1 + 2)");
+	}
+
+	#[test]
+	fn display_does_not_chain_relations() {
+		let eq = binary(Number::Int(1), Operator::Equal, Number::Int(2));
+		let eq = binary(eq, Operator::Equal, Value::Bool(true));
+		assert_eq!(eq.to_string(), "(1 = 2) = true");
+		crate::syn::value(&eq.to_string()).unwrap();
+	}
+}
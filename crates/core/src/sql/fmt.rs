@@ -310,4 +310,19 @@ mod tests {
 		assert_eq!(format!("{}", array), "[1, 2, 3]");
 		assert_eq!(format!("{:#}", array), "[\n\t1,\n\t2,\n\t3\n]");
 	}
+
+	#[test]
+	fn pretty_round_trip() {
+		for source in [
+			"SELECT * FROM person WHERE age > 18 AND (name = 'a' OR name = 'b');",
+			"RETURN (1 + 2) * 3 - -(4 / 2);",
+			"RETURN 1 - (2 - 3) ?? !(true && false);",
+			"UPDATE person SET friends += [1, 2], data = { a: (1 < 2) = true };",
+			"IF $a { RETURN [1, 2] } ELSE { RETURN { b: 2 ** (1 + 1) } };",
+		] {
+			let formatted = parse(source).unwrap().to_sql_pretty();
+			let reformatted = parse(&formatted).unwrap().to_sql_pretty();
+			assert_eq!(formatted, reformatted, "{source}");
+		}
+	}
 }
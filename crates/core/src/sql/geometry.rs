@@ -1,5 +1,6 @@
 #![allow(clippy::derived_hash_with_manual_eq)]
 
+use crate::err::Error;
 use crate::sql::array::Array;
 use crate::sql::fmt::Fmt;
 use crate::sql::value::Value;
@@ -9,6 +10,7 @@ use geo::{Coord, LineString, LinesIter, Point, Polygon};
 use geo_types::{MultiLineString, MultiPoint, MultiPolygon};
 use revision::revisioned;
 use serde::{Deserialize, Serialize};
+use serde_json::Value as Json;
 use std::cmp::Ordering;
 use std::collections::BTreeMap;
 use std::iter::once;
@@ -178,6 +180,80 @@ impl Geometry {
 		obj.into()
 	}
 
+	/// Get the GeoJSON representation of this geometry
+	pub fn to_geojson(&self) -> Json {
+		Value::Geometry(self.clone()).into_json()
+	}
+
+	/// Parses a geometry from its GeoJSON representation
+	///
+	/// Positions may carry an altitude as a third element, which is dropped because
+	/// geometries only hold two dimensions.
+	pub fn from_geojson(v: &Json) -> Result<Geometry, Error> {
+		let invalid = |message: &str| Error::InvalidGeoJson(message.to_owned());
+		let Some(obj) = v.as_object() else {
+			return Err(invalid("expected an object"));
+		};
+		let Some(typ) = obj.get("type").and_then(Json::as_str) else {
+			return Err(invalid("expected a `type` field containing a string"));
+		};
+		if typ == "GeometryCollection" {
+			let Some(geometries) = obj.get("geometries").and_then(Json::as_array) else {
+				return Err(invalid("expected a `geometries` field containing an array"));
+			};
+			return geometries
+				.iter()
+				.map(Self::from_geojson)
+				.collect::<Result<_, _>>()
+				.map(Self::Collection);
+		}
+		let Some(coordinates) = obj.get("coordinates") else {
+			return Err(invalid("expected a `coordinates` field"));
+		};
+		let geometry = match typ {
+			"Point" => Self::json_to_point(coordinates).map(Self::Point),
+			"LineString" => Self::json_to_line(coordinates).map(Self::Line),
+			"Polygon" => Self::json_to_polygon(coordinates).map(Self::Polygon),
+			"MultiPoint" => Self::json_to_multi(coordinates, Self::json_to_point)
+				.map(|v| Self::MultiPoint(v.into())),
+			"MultiLineString" => Self::json_to_multi(coordinates, Self::json_to_line)
+				.map(|v| Self::MultiLine(MultiLineString::new(v))),
+			"MultiPolygon" => Self::json_to_multi(coordinates, Self::json_to_polygon)
+				.map(|v| Self::MultiPolygon(v.into())),
+			typ => return Err(Error::InvalidGeoJson(format!("unknown geometry type `{typ}`"))),
+		};
+		geometry.ok_or_else(|| Error::InvalidGeoJson(format!("invalid coordinates for a {typ}")))
+	}
+
+	/// Converts a GeoJSON array of coordinates with the given converter
+	fn json_to_multi<T>(v: &Json, f: fn(&Json) -> Option<T>) -> Option<Vec<T>> {
+		v.as_array()?.iter().map(f).collect()
+	}
+
+	/// Converts GeoJSON coordinates to a Polygon
+	fn json_to_polygon(v: &Json) -> Option<Polygon<f64>> {
+		let mut rings = Self::json_to_multi(v, Self::json_to_line)?.into_iter();
+		let exterior = rings.next()?;
+		Some(Polygon::new(exterior, rings.collect()))
+	}
+
+	/// Converts GeoJSON coordinates to a LineString
+	fn json_to_line(v: &Json) -> Option<LineString<f64>> {
+		Self::json_to_multi(v, Self::json_to_point).map(LineString::from)
+	}
+
+	/// Converts a GeoJSON position to a Point, dropping any altitude
+	fn json_to_point(v: &Json) -> Option<Point<f64>> {
+		match v.as_array()?.as_slice() {
+			[x, y] => Some(Point::new(x.as_f64()?, y.as_f64()?)),
+			[x, y, z] => {
+				z.as_f64()?;
+				Some(Point::new(x.as_f64()?, y.as_f64()?))
+			}
+			_ => None,
+		}
+	}
+
 	/// Converts a surreal value to a MultiPolygon if the array matches to a MultiPolygon.
 	pub(crate) fn array_to_multipolygon(v: &Value) -> Option<MultiPolygon<f64>> {
 		let mut res = Vec::new();
@@ -354,12 +430,20 @@ impl PartialOrd for Geometry {
 	}
 }
 
+/// Converts a `(longitude, latitude)` pair into a point
 impl From<(f64, f64)> for Geometry {
 	fn from(v: (f64, f64)) -> Self {
 		Self::Point(v.into())
 	}
 }
 
+/// Converts a list of `(longitude, latitude)` pairs into a line
+impl From<Vec<(f64, f64)>> for Geometry {
+	fn from(v: Vec<(f64, f64)>) -> Self {
+		Self::Line(LineString::from(v))
+	}
+}
+
 impl From<[f64; 2]> for Geometry {
 	fn from(v: [f64; 2]) -> Self {
 		Self::Point(v.into())
@@ -723,3 +807,75 @@ impl hash::Hash for Geometry {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use geo::{line_string, point, polygon};
+	use serde_json::json;
+
+	#[test]
+	fn geojson_round_trip() {
+		let point = point! { x: 1.5, y: -2.0 };
+		let line = line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)];
+		let polygon =
+			polygon![(x: 0.0, y: 0.0), (x: 4.0, y: 0.0), (x: 4.0, y: 4.0), (x: 0.0, y: 0.0)];
+		let holed = Polygon::new(
+			line_string![(x: 0.0, y: 0.0), (x: 9.0, y: 0.0), (x: 9.0, y: 9.0), (x: 0.0, y: 0.0)],
+			vec![
+				line_string![(x: 1.0, y: 1.0), (x: 2.0, y: 1.0), (x: 2.0, y: 2.0), (x: 1.0, y: 1.0)],
+			],
+		);
+		let geometries = [
+			Geometry::Point(point),
+			Geometry::Line(line.clone()),
+			Geometry::Polygon(polygon.clone()),
+			Geometry::Polygon(holed.clone()),
+			Geometry::MultiPoint(vec![point, point! { x: 3.0, y: 4.0 }].into()),
+			Geometry::MultiLine(MultiLineString::new(vec![line.clone(), line.clone()])),
+			Geometry::MultiPolygon(vec![polygon, holed].into()),
+			Geometry::Collection(vec![Geometry::Point(point), Geometry::Line(line)]),
+			Geometry::Collection(vec![]),
+		];
+		for geometry in geometries {
+			let geojson = geometry.to_geojson();
+			assert_eq!(geojson["type"], geometry.as_type());
+			assert_eq!(Geometry::from_geojson(&geojson).unwrap(), geometry, "{geojson}");
+		}
+	}
+
+	#[test]
+	fn from_geojson() {
+		let geojson = json!({ "type": "Point", "coordinates": [10, 20.5] });
+		assert_eq!(Geometry::from_geojson(&geojson).unwrap(), Geometry::Point((10.0, 20.5).into()));
+	}
+
+	#[test]
+	fn from_geojson_drops_altitude() {
+		let geojson = json!({ "type": "Point", "coordinates": [10, 20.5, 100] });
+		assert_eq!(Geometry::from_geojson(&geojson).unwrap(), Geometry::Point((10.0, 20.5).into()));
+		let geojson = json!({ "type": "LineString", "coordinates": [[1, 2, 3], [4, 5]] });
+		let line = LineString::from(vec![(1.0, 2.0), (4.0, 5.0)]);
+		assert_eq!(Geometry::from_geojson(&geojson).unwrap(), Geometry::Line(line));
+	}
+
+	#[test]
+	fn from_geojson_malformed() {
+		for geojson in [
+			json!([1, 2]),
+			json!({ "coordinates": [1, 2] }),
+			json!({ "type": "Circle", "coordinates": [1, 2] }),
+			json!({ "type": "Point" }),
+			json!({ "type": "Point", "coordinates": [1, 2, 3, 4] }),
+			json!({ "type": "Point", "coordinates": [1, 2, "3"] }),
+			json!({ "type": "Point", "coordinates": [1, "2"] }),
+			json!({ "type": "LineString", "coordinates": [1, 2] }),
+			json!({ "type": "Polygon", "coordinates": [] }),
+			json!({ "type": "MultiPoint", "coordinates": [[1, 2], [3]] }),
+			json!({ "type": "GeometryCollection", "geometries": [{ "type": "Point" }] }),
+		] {
+			let error = Geometry::from_geojson(&geojson).unwrap_err();
+			assert!(matches!(error, Error::InvalidGeoJson(_)), "{geojson}: {error}");
+		}
+	}
+}
@@ -1,14 +1,22 @@
 use super::escape::escape_key;
 use super::{Duration, Number, Strand};
+use crate::err::Error;
 use crate::sql::statements::info::InfoStructure;
 use crate::sql::{
 	fmt::{is_pretty, pretty_indent, Fmt, Pretty},
-	Table, Value,
+	Array, Bytes, Closure, Datetime, Geometry, Ident, Object, Range, Table, Thing, Uuid, Value,
 };
+use crate::syn;
+use chrono::DateTime;
+use geo::{LineString, MultiLineString, MultiPoint, MultiPolygon, Polygon};
 use revision::revisioned;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::collections::BTreeMap;
 use std::fmt::{self, Display, Formatter, Write};
+use std::ops::Bound;
+use std::str::FromStr;
 
 #[revisioned(revision = 1)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
@@ -189,6 +197,285 @@ impl Kind {
 	}
 }
 
+impl Kind {
+	/// Combine this kind with another kind, returning a normalised union
+	///
+	/// Nested unions are flattened and duplicate kinds are removed, so
+	/// `union(T, T)` collapses to `T`. If either kind is `any` then the
+	/// result is `any`, and if either kind is optional then the result
+	/// is an optional union of the remaining kinds.
+	pub fn union(self, other: Kind) -> Kind {
+		let mut optional = false;
+		let mut kinds: Vec<Kind> = Vec::new();
+		for kind in [self, other] {
+			if !kind.flatten_into(&mut kinds, &mut optional) {
+				return Kind::Any;
+			}
+		}
+		let kind = match kinds.len() {
+			1 => kinds.remove(0),
+			_ => Kind::Either(kinds),
+		};
+		match optional {
+			true => Kind::Option(Box::new(kind)),
+			false => kind,
+		}
+	}
+
+	/// Flatten this kind into a list of unique non-union kinds
+	///
+	/// Returns false if the kind contains `any`, as it absorbs every other kind.
+	fn flatten_into(self, kinds: &mut Vec<Kind>, optional: &mut bool) -> bool {
+		match self {
+			Kind::Any => false,
+			Kind::Option(x) => {
+				*optional = true;
+				x.flatten_into(kinds, optional)
+			}
+			Kind::Either(x) => x.into_iter().all(|k| k.flatten_into(kinds, optional)),
+			k => {
+				if !kinds.contains(&k) {
+					kinds.push(k);
+				}
+				true
+			}
+		}
+	}
+
+	/// Find the common subtype of this kind and another kind
+	///
+	/// Returns `None` if there is no value which could satisfy both kinds.
+	pub fn intersect(self, other: Kind) -> Option<Kind> {
+		match (self, other) {
+			(Kind::Any, k) | (k, Kind::Any) => Some(k),
+			(a, b) if a == b => Some(a),
+			(Kind::Option(a), Kind::Option(b)) => {
+				a.intersect(*b).map(|k| Kind::Option(Box::new(k)))
+			}
+			(Kind::Option(a), b) | (b, Kind::Option(a)) => a.intersect(b),
+			(Kind::Either(a), b) => {
+				a.into_iter().filter_map(|k| k.intersect(b.clone())).reduce(Kind::union)
+			}
+			(a, Kind::Either(b)) => {
+				b.into_iter().filter_map(|k| a.clone().intersect(k)).reduce(Kind::union)
+			}
+			(Kind::Number, k @ (Kind::Int | Kind::Float | Kind::Decimal))
+			| (k @ (Kind::Int | Kind::Float | Kind::Decimal), Kind::Number) => Some(k),
+			(Kind::Record(a), Kind::Record(b)) => match (a.is_empty(), b.is_empty()) {
+				(true, _) => Some(Kind::Record(b)),
+				(_, true) => Some(Kind::Record(a)),
+				_ => {
+					let v: Vec<Table> = a.into_iter().filter(|t| b.contains(t)).collect();
+					(!v.is_empty()).then_some(Kind::Record(v))
+				}
+			},
+			(Kind::Geometry(a), Kind::Geometry(b)) => match (a.is_empty(), b.is_empty()) {
+				(true, _) => Some(Kind::Geometry(b)),
+				(_, true) => Some(Kind::Geometry(a)),
+				_ => {
+					let v: Vec<String> = a.into_iter().filter(|t| b.contains(t)).collect();
+					(!v.is_empty()).then_some(Kind::Geometry(v))
+				}
+			},
+			(Kind::Array(a, x), Kind::Array(b, y)) => {
+				a.intersect(*b).map(|k| Kind::Array(Box::new(k), min_len(x, y)))
+			}
+			(Kind::Set(a, x), Kind::Set(b, y)) => {
+				a.intersect(*b).map(|k| Kind::Set(Box::new(k), min_len(x, y)))
+			}
+			(Kind::Literal(l), k) | (k, Kind::Literal(l)) => {
+				let base = l.to_kind();
+				match base.clone().intersect(k) {
+					Some(v) if v == base => Some(Kind::Literal(l)),
+					_ => None,
+				}
+			}
+			_ => None,
+		}
+	}
+
+	/// Returns true if this kind accepts `NONE`
+	///
+	/// This is the case for `option<T>`, for `any`, and for unions which
+	/// contain either of these.
+	pub fn is_optional(&self) -> bool {
+		match self {
+			Kind::Any | Kind::Option(_) => true,
+			Kind::Either(x) => x.iter().any(Kind::is_optional),
+			_ => false,
+		}
+	}
+
+	/// Returns true if this kind accepts `NULL`
+	///
+	/// This is the case for `null`, for `any`, and for optional kinds or
+	/// unions which contain either of these.
+	pub fn is_nullable(&self) -> bool {
+		match self {
+			Kind::Any | Kind::Null => true,
+			Kind::Option(x) => x.is_nullable(),
+			Kind::Either(x) => x.iter().any(Kind::is_nullable),
+			_ => false,
+		}
+	}
+
+	/// Strip one layer of optionality from this kind
+	///
+	/// For `option<T>` this returns `T`, and for a union of `null` and a
+	/// single other kind, such as `string | null`, this returns the other
+	/// kind. Any other kind is returned as is.
+	pub fn inner_non_optional(&self) -> &Kind {
+		match self {
+			Kind::Option(x) => x,
+			Kind::Either(x) => match x.as_slice() {
+				[Kind::Null, k] | [k, Kind::Null] => k,
+				_ => self,
+			},
+			k => k,
+		}
+	}
+
+	/// Return the canonical form of this kind
+	///
+	/// Unions are flattened, sorted and deduplicated, with any optionality
+	/// hoisted to a single outer `option<T>`, and the same is done for the
+	/// tables of a record and the types of a geometry. Nested kinds, such as
+	/// the fields of a literal object, are normalised in turn.
+	///
+	/// The `PartialEq` implementation compares kinds structurally, so
+	/// `string | int` and `int | string` are not equal. Compare normalised
+	/// kinds, or use [`Kind::is_equivalent`], to compare them semantically.
+	pub fn normalized(&self) -> Kind {
+		match self {
+			Kind::Option(_) | Kind::Either(_) => {
+				let mut optional = false;
+				let mut kinds: Vec<Kind> = Vec::new();
+				if !self.clone().flatten_into(&mut kinds, &mut optional) {
+					return Kind::Any;
+				}
+				let mut kinds: Vec<Kind> = kinds.iter().map(Kind::normalized).collect();
+				kinds.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+				kinds.dedup();
+				let kind = match kinds.len() {
+					1 => kinds.remove(0),
+					_ => Kind::Either(kinds),
+				};
+				match optional {
+					true => Kind::Option(Box::new(kind)),
+					false => kind,
+				}
+			}
+			Kind::Record(t) => {
+				let mut t = t.clone();
+				t.sort();
+				t.dedup();
+				Kind::Record(t)
+			}
+			Kind::Geometry(t) => {
+				let mut t = t.clone();
+				t.sort();
+				t.dedup();
+				Kind::Geometry(t)
+			}
+			Kind::Set(k, l) => Kind::Set(Box::new(k.normalized()), *l),
+			Kind::Array(k, l) => Kind::Array(Box::new(k.normalized()), *l),
+			Kind::Function(args, ret) => Kind::Function(
+				args.as_ref().map(|a| a.iter().map(Kind::normalized).collect()),
+				ret.as_ref().map(|r| Box::new(r.normalized())),
+			),
+			Kind::Literal(l) => Kind::Literal(l.normalized()),
+			k => k.clone(),
+		}
+	}
+
+	/// Returns true if both kinds accept the same values
+	///
+	/// This compares the [normalised](Kind::normalized) form of both kinds.
+	pub fn is_equivalent(&self, other: &Kind) -> bool {
+		self.normalized() == other.normalized()
+	}
+
+	/// Generate a representative example value which satisfies this kind
+	///
+	/// Scalars produce a fixed placeholder such as `"string"` or `0`, unions
+	/// produce an example of their first kind, and arrays and sets contain a
+	/// single example element unless limited to a length of zero. Geometries
+	/// are of the first requested type, and functions are closures which
+	/// return an example of their return kind. As no value is more
+	/// representative than another, `any` produces `NONE`.
+	pub fn example(&self) -> Value {
+		match self {
+			Kind::Any => Value::None,
+			Kind::Null => Value::Null,
+			Kind::Bool => Value::Bool(false),
+			Kind::Bytes => Value::Bytes(Bytes::default()),
+			Kind::Datetime => Value::Datetime(Datetime(DateTime::UNIX_EPOCH)),
+			Kind::Decimal => Value::Number(Number::Decimal(Decimal::ZERO)),
+			Kind::Duration => Value::Duration(Duration::default()),
+			Kind::Float => Value::Number(Number::Float(0.0)),
+			Kind::Int | Kind::Number => Value::Number(Number::Int(0)),
+			Kind::Object => Value::Object(Object::default()),
+			Kind::Point => Value::Geometry(Geometry::from((0.0, 0.0))),
+			Kind::Geometry(types) => Value::Geometry(example_geometry(types)),
+			Kind::String => Value::from("string"),
+			Kind::Uuid => Value::Uuid(Uuid(uuid::Uuid::nil())),
+			Kind::Record(tables) => {
+				let tb = tables.first().map(|t| t.0.as_str()).unwrap_or("record");
+				Value::Thing(Thing::from((tb, "id")))
+			}
+			Kind::Option(k) => k.example(),
+			Kind::Either(kinds) => kinds.first().map(Kind::example).unwrap_or_default(),
+			Kind::Set(k, len) | Kind::Array(k, len) => match len {
+				Some(0) => Value::Array(Array::new()),
+				_ => Value::Array(Array::from(vec![k.example()])),
+			},
+			Kind::Function(args, returns) => Value::Closure(Box::new(Closure {
+				args: args
+					.iter()
+					.flatten()
+					.enumerate()
+					.map(|(i, kind)| (Ident::from(format!("arg{i}")), kind.clone()))
+					.collect(),
+				returns: returns.as_deref().cloned(),
+				body: returns.as_deref().map(Kind::example).unwrap_or_default(),
+			})),
+			Kind::Range => Value::Range(Box::new(Range {
+				beg: Bound::Included(Value::from(0)),
+				end: Bound::Excluded(Value::from(1)),
+			})),
+			Kind::Literal(l) => l.example(),
+		}
+	}
+}
+
+fn example_geometry(types: &[String]) -> Geometry {
+	let line = || LineString::from(vec![(0.0, 0.0), (1.0, 1.0)]);
+	let polygon = || {
+		let exterior = LineString::from(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 0.0)]);
+		Polygon::new(exterior, Vec::new())
+	};
+	match types.first().map(String::as_str) {
+		Some("line") => Geometry::Line(line()),
+		Some("polygon") => Geometry::Polygon(polygon()),
+		Some("multipoint") => Geometry::MultiPoint(MultiPoint::from(vec![(0.0, 0.0)])),
+		Some("multiline") => Geometry::MultiLine(MultiLineString::new(vec![line()])),
+		Some("multipolygon") => Geometry::MultiPolygon(MultiPolygon::new(vec![polygon()])),
+		Some("collection") => Geometry::Collection(vec![Geometry::from((0.0, 0.0))]),
+		_ => Geometry::from((0.0, 0.0)),
+	}
+}
+
+fn example_object(fields: &BTreeMap<String, Kind>) -> Object {
+	fields.iter().map(|(k, v)| (k.clone(), v.example())).collect()
+}
+
+fn min_len(a: Option<u64>, b: Option<u64>) -> Option<u64> {
+	match (a, b) {
+		(Some(a), Some(b)) => Some(a.min(b)),
+		(a, b) => a.or(b),
+	}
+}
+
 impl From<&Kind> for Box<Kind> {
 	#[inline]
 	fn from(v: &Kind) -> Self {
@@ -196,6 +483,13 @@ impl From<&Kind> for Box<Kind> {
 	}
 }
 
+impl FromStr for Kind {
+	type Err = Error;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		syn::kind(s)
+	}
+}
+
 impl Display for Kind {
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
 		match self {
@@ -279,6 +573,38 @@ impl Literal {
 		}
 	}
 
+	/// Generate an example value which satisfies this literal
+	pub fn example(&self) -> Value {
+		match self {
+			Self::String(v) => Value::Strand(v.clone()),
+			Self::Number(v) => Value::Number(*v),
+			Self::Duration(v) => Value::Duration(*v),
+			Self::Array(a) => Value::Array(a.iter().map(Kind::example).collect()),
+			Self::Object(o) => Value::Object(example_object(o)),
+			Self::DiscriminatedObject(_, discriminants) => {
+				discriminants.first().map(|o| Value::Object(example_object(o))).unwrap_or_default()
+			}
+		}
+	}
+
+	/// Return the canonical form of this literal, normalising any nested kinds
+	pub fn normalized(&self) -> Literal {
+		let object = |o: &BTreeMap<String, Kind>| -> BTreeMap<String, Kind> {
+			o.iter().map(|(k, v)| (k.clone(), v.normalized())).collect()
+		};
+		match self {
+			Self::Array(a) => Self::Array(a.iter().map(Kind::normalized).collect()),
+			Self::Object(o) => Self::Object(object(o)),
+			Self::DiscriminatedObject(key, discriminants) => {
+				let mut discriminants: Vec<_> = discriminants.iter().map(object).collect();
+				discriminants.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+				discriminants.dedup();
+				Self::DiscriminatedObject(key.clone(), discriminants)
+			}
+			l => l.clone(),
+		}
+	}
+
 	pub fn validate_value(&self, value: &Value) -> bool {
 		match self {
 			Self::String(v) => match value {
@@ -443,3 +769,235 @@ impl Display for Literal {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn from_str_round_trip() {
+		let kinds = [
+			"any",
+			"string | int | bool",
+			"option<int>",
+			"option<string | int>",
+			"array<record<user>>",
+			"array<set<float, 3>, 10>",
+			"record<user | post>",
+			"geometry<point | polygon>",
+			"{ a: int, b: option<array<string>> }",
+			"'a' | 'b' | 1",
+			"[int, { name: string }]",
+		];
+		for kind in kinds {
+			let parsed: Kind = kind.parse().unwrap();
+			assert_eq!(parsed.to_string(), kind);
+			assert_eq!(parsed.to_string().parse::<Kind>().unwrap(), parsed);
+		}
+		assert_eq!(
+			"array<record<user>>".parse::<Kind>().unwrap(),
+			Kind::Array(Box::new(Kind::Record(vec![Table::from("user")])), None)
+		);
+	}
+
+	#[test]
+	fn from_str_invalid() {
+		for kind in ["", "array<", "option<int", "int>", "strnig", "int int"] {
+			assert!(kind.parse::<Kind>().is_err(), "{kind}");
+		}
+	}
+
+	#[test]
+	fn union_flattens() {
+		let a = Kind::Either(vec![Kind::String, Kind::Int]);
+		let b = Kind::Either(vec![Kind::Bool, Kind::Either(vec![Kind::Float])]);
+		assert_eq!(
+			a.union(b),
+			Kind::Either(vec![Kind::String, Kind::Int, Kind::Bool, Kind::Float])
+		);
+	}
+
+	#[test]
+	fn union_deduplicates() {
+		assert_eq!(Kind::String.union(Kind::String), Kind::String);
+		let a = Kind::Either(vec![Kind::String, Kind::Int]);
+		let b = Kind::Either(vec![Kind::Int, Kind::String]);
+		assert_eq!(a.union(b), Kind::Either(vec![Kind::String, Kind::Int]));
+	}
+
+	#[test]
+	fn union_absorbs_any() {
+		assert_eq!(Kind::String.union(Kind::Any), Kind::Any);
+		let a = Kind::Either(vec![Kind::String, Kind::Int]);
+		assert_eq!(a.union(Kind::Option(Box::new(Kind::Any))), Kind::Any);
+	}
+
+	#[test]
+	fn union_optional() {
+		let a = Kind::Option(Box::new(Kind::String));
+		assert_eq!(
+			a.union(Kind::Int),
+			Kind::Option(Box::new(Kind::Either(vec![Kind::String, Kind::Int])))
+		);
+	}
+
+	#[test]
+	fn intersect_subtypes() {
+		assert_eq!(Kind::Any.intersect(Kind::String), Some(Kind::String));
+		assert_eq!(Kind::Number.intersect(Kind::Int), Some(Kind::Int));
+		let a = Kind::Either(vec![Kind::String, Kind::Int, Kind::Bool]);
+		let b = Kind::Either(vec![Kind::Bool, Kind::Number]);
+		assert_eq!(a.intersect(b), Some(Kind::Either(vec![Kind::Int, Kind::Bool])));
+		let a = Kind::Array(Box::new(Kind::Number), Some(10));
+		let b = Kind::Array(Box::new(Kind::Float), None);
+		assert_eq!(a.intersect(b), Some(Kind::Array(Box::new(Kind::Float), Some(10))));
+	}
+
+	#[test]
+	fn intersect_disjoint() {
+		assert_eq!(Kind::String.intersect(Kind::Int), None);
+		let a = Kind::Either(vec![Kind::String, Kind::Bool]);
+		let b = Kind::Either(vec![Kind::Int, Kind::Datetime]);
+		assert_eq!(a.intersect(b), None);
+		let a = Kind::Record(vec![Table::from("person")]);
+		let b = Kind::Record(vec![Table::from("company")]);
+		assert_eq!(a.intersect(b), None);
+	}
+
+	#[test]
+	fn normalized_unions() {
+		let a: Kind = "string | int".parse().unwrap();
+		let b: Kind = "int | string".parse().unwrap();
+		assert_ne!(a, b);
+		assert!(a.is_equivalent(&b));
+		assert_eq!(a.normalized(), b.normalized());
+		let a = Kind::Either(vec![Kind::Int, Kind::Either(vec![Kind::String, Kind::Int])]);
+		assert_eq!(a.normalized(), b.normalized());
+		let a = Kind::Either(vec![Kind::Option(Box::new(Kind::String)), Kind::Int]);
+		let b: Kind = "option<int | string>".parse().unwrap();
+		assert!(a.is_equivalent(&b));
+		assert_eq!(Kind::Either(vec![Kind::String]).normalized(), Kind::String);
+		assert_eq!(Kind::Either(vec![Kind::String, Kind::Any]).normalized(), Kind::Any);
+		assert!(!a.is_equivalent(&Kind::Either(vec![Kind::Int, Kind::String])));
+	}
+
+	#[test]
+	fn normalized_nested() {
+		let a: Kind = "{ a: int | string, b: array<record<post | user>> }".parse().unwrap();
+		let b: Kind = "{ b: array<record<user | post>>, a: string | int }".parse().unwrap();
+		assert!(a.is_equivalent(&b));
+		let a: Kind = "set<geometry<polygon | point>> | [bool | int]".parse().unwrap();
+		let b: Kind = "[int | bool] | set<geometry<point | polygon>>".parse().unwrap();
+		assert!(a.is_equivalent(&b));
+		let a: Kind = "{ kind: 'a', v: int | string } | { kind: 'b' }".parse().unwrap();
+		let b: Kind = "{ kind: 'b' } | { kind: 'a', v: string | int }".parse().unwrap();
+		assert!(a.is_equivalent(&b));
+		let b: Kind = "{ kind: 'b' } | { kind: 'a', v: string }".parse().unwrap();
+		assert!(!a.is_equivalent(&b));
+	}
+
+	#[test]
+	fn optional_and_nullable() {
+		let option: Kind = "option<string>".parse().unwrap();
+		assert!(option.is_optional());
+		assert!(!option.is_nullable());
+		assert_eq!(option.inner_non_optional(), &Kind::String);
+		let null: Kind = "string | null".parse().unwrap();
+		assert!(!null.is_optional());
+		assert!(null.is_nullable());
+		assert_eq!(null.inner_non_optional(), &Kind::String);
+		let plain: Kind = "string".parse().unwrap();
+		assert!(!plain.is_optional());
+		assert!(!plain.is_nullable());
+		assert_eq!(plain.inner_non_optional(), &Kind::String);
+	}
+
+	#[test]
+	fn inner_non_optional_strips_one_layer() {
+		let kind: Kind = "option<string | null>".parse().unwrap();
+		assert!(kind.is_optional());
+		assert!(kind.is_nullable());
+		assert_eq!(kind.inner_non_optional(), &Kind::Either(vec![Kind::String, Kind::Null]));
+		let kind: Kind = "string | int | null".parse().unwrap();
+		assert_eq!(kind.inner_non_optional(), &kind);
+		assert!(Kind::Any.is_optional() && Kind::Any.is_nullable());
+	}
+
+	#[test]
+	fn example_scalars() {
+		assert_eq!(Kind::String.example(), Value::from("string"));
+		assert_eq!(Kind::Int.example(), Value::from(0));
+		assert_eq!(Kind::Any.example(), Value::None);
+		assert_eq!(Kind::Option(Box::new(Kind::Bool)).example(), Value::Bool(false));
+		assert_eq!(Kind::Either(vec![Kind::Int, Kind::String]).example(), Value::from(0));
+	}
+
+	#[test]
+	fn example_geometries() {
+		for name in [
+			"feature",
+			"point",
+			"line",
+			"polygon",
+			"multipoint",
+			"multiline",
+			"multipolygon",
+			"collection",
+		] {
+			let kind = Kind::Geometry(vec![name.to_owned()]);
+			let val = kind.example();
+			assert!(val.is_geometry_type(&[name.to_owned()]), "{name}: {val}");
+			assert!(val.coerce_to(&kind).is_ok(), "{name}");
+		}
+	}
+
+	#[test]
+	fn example_function() {
+		let val = Kind::Function(Some(vec![Kind::Int]), Some(Box::new(Kind::String))).example();
+		let Value::Closure(closure) = &val else {
+			panic!("expected a closure, found {val}");
+		};
+		assert_eq!(closure.param_kinds().cloned().collect::<Vec<_>>(), [Kind::Int]);
+		assert_eq!(closure.returns, Some(Kind::String));
+		assert_eq!(closure.body, Value::from("string"));
+		assert!(val.coerce_to(&Kind::Function(None, None)).is_ok());
+	}
+
+	#[test]
+	fn example_nested_object() {
+		let address = BTreeMap::from([
+			("city".to_owned(), Kind::String),
+			("zip".to_owned(), Kind::Option(Box::new(Kind::Int))),
+		]);
+		let kind = Kind::Literal(Literal::Object(BTreeMap::from([
+			("name".to_owned(), Kind::String),
+			("address".to_owned(), Kind::Literal(Literal::Object(address))),
+			("owner".to_owned(), Kind::Record(vec![Table::from("user")])),
+		])));
+		let val = kind.example();
+		assert_eq!(
+			val.to_string(),
+			"{ address: { city: 'string', zip: 0 }, name: 'string', owner: user:id }"
+		);
+		assert!(val.coerce_to(&kind).is_ok());
+	}
+
+	#[test]
+	fn example_array() {
+		let kind = Kind::Array(
+			Box::new(Kind::Literal(Literal::Object(BTreeMap::from([(
+				"tags".to_owned(),
+				Kind::Set(Box::new(Kind::String), None),
+			)])))),
+			Some(10),
+		);
+		let val = kind.example();
+		assert_eq!(val.to_string(), "[{ tags: ['string'] }]");
+		assert!(val.coerce_to(&kind).is_ok());
+		let empty = Kind::Array(Box::new(Kind::Int), Some(0));
+		assert_eq!(empty.example(), Value::Array(Array::new()));
+		let tuple = Kind::Literal(Literal::Array(vec![Kind::Int, Kind::Bool]));
+		assert_eq!(tuple.example().to_string(), "[0, false]");
+	}
+}
@@ -150,6 +150,7 @@ pub use self::timeout::Timeout;
 pub use self::tokenizer::Tokenizer;
 pub use self::uuid::Uuid;
 pub use self::value::serde::from_value;
+pub use self::value::serde::from_value_with_path;
 pub use self::value::serde::to_value;
 pub use self::value::Value;
 pub use self::value::Values;
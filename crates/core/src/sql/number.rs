@@ -377,6 +377,55 @@ impl Number {
 		}
 	}
 
+	/// Converts the number to a decimal, returning `None` if it can not be represented as one
+	///
+	/// Integers and decimals convert exactly. Floats are rounded to the 16 or so significant
+	/// digits a float can hold, which drops the error of the binary representation, so both
+	/// `0.1f` and `0.1f + 0.2f` become the decimal you would expect (`0.1dec` and `0.3dec`).
+	/// Floats which are not finite or are outside the range of a decimal return `None`.
+	pub fn try_to_decimal(&self) -> Option<Decimal> {
+		match self {
+			Number::Int(v) => Some(Decimal::from(*v)),
+			Number::Float(v) => Decimal::from_f64(*v),
+			Number::Decimal(v) => Some(*v),
+		}
+	}
+
+	/// Converts the number to an `i32`, clamping values outside its range
+	///
+	/// Floats and decimals are rounded to the nearest integer, with halfway cases
+	/// rounded away from zero. `NaN` converts to zero.
+	pub fn to_i32_saturating(&self) -> i32 {
+		self.to_i128_rounded().clamp(i32::MIN as i128, i32::MAX as i128) as i32
+	}
+
+	/// Converts the number to a `u32`, clamping values outside its range
+	///
+	/// Rounds in the same way as [`Number::to_i32_saturating`].
+	pub fn to_u32_saturating(&self) -> u32 {
+		self.to_i128_rounded().clamp(0, u32::MAX as i128) as u32
+	}
+
+	/// Converts the number to a `u8`, clamping values outside its range
+	///
+	/// Rounds in the same way as [`Number::to_i32_saturating`].
+	pub fn to_u8_saturating(&self) -> u8 {
+		self.to_i128_rounded().clamp(0, u8::MAX as i128) as u8
+	}
+
+	/// Rounds the number to an `i128`, which holds every integer and decimal
+	fn to_i128_rounded(self) -> i128 {
+		match self {
+			Number::Int(v) => v as i128,
+			// Casting a float saturates, and converts NaN to zero
+			Number::Float(v) => v.round() as i128,
+			Number::Decimal(v) => v
+				.round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+				.to_i128()
+				.unwrap_or_default(),
+		}
+	}
+
 	// -----------------------------------
 	//
 	// -----------------------------------
@@ -562,6 +611,66 @@ impl Number {
 			(v, p) => v.as_float().powf(p.as_float()).into(),
 		}
 	}
+
+	// -----------------------------------
+	// Integer arithmetic
+	// -----------------------------------
+
+	/// Returns the value as an `i64` if it is a whole number which fits in one
+	fn to_exact_int(self) -> Option<i64> {
+		match self {
+			Number::Int(v) => Some(v),
+			// The upper bound is exclusive as `i64::MAX` can not be represented as a float
+			Number::Float(v)
+				if v.fract() == 0.0
+					&& (-9223372036854775808.0..9223372036854775808.0).contains(&v) =>
+			{
+				Some(v as i64)
+			}
+			Number::Float(_) => None,
+			Number::Decimal(v) if v.is_integer() => v.to_i64(),
+			Number::Decimal(_) => None,
+		}
+	}
+
+	/// The greatest common divisor of two whole numbers
+	///
+	/// The result is never negative, and is zero only if both numbers are zero.
+	/// Returns `None` if either number is not a whole number, or if the result
+	/// does not fit in an integer.
+	pub fn gcd(&self, other: &Number) -> Option<Number> {
+		let (mut a, mut b) =
+			(self.to_exact_int()?.unsigned_abs(), other.to_exact_int()?.unsigned_abs());
+		while b != 0 {
+			(a, b) = (b, a % b);
+		}
+		i64::try_from(a).ok().map(Number::Int)
+	}
+
+	/// The least common multiple of two whole numbers
+	///
+	/// The result is never negative, and is zero if either number is zero.
+	/// Returns `None` if either number is not a whole number, or if the result
+	/// does not fit in an integer.
+	pub fn lcm(&self, other: &Number) -> Option<Number> {
+		let a = self.to_exact_int()?;
+		let b = other.to_exact_int()?;
+		if a == 0 || b == 0 {
+			return Some(Number::Int(0));
+		}
+		let gcd = self.gcd(other)?.to_int();
+		(a / gcd).checked_mul(b)?.checked_abs().map(Number::Int)
+	}
+
+	/// Whether a whole number is even, or `None` if it is not a whole number
+	pub fn is_even(&self) -> Option<bool> {
+		self.to_exact_int().map(|v| v % 2 == 0)
+	}
+
+	/// Whether a whole number is odd, or `None` if it is not a whole number
+	pub fn is_odd(&self) -> Option<bool> {
+		self.is_even().map(|v| !v)
+	}
 }
 
 impl Eq for Number {}
@@ -1069,6 +1178,39 @@ mod tests {
 
 	use super::Number;
 	use super::TryFloatDiv;
+
+	#[test]
+	fn try_to_decimal_round_trip() {
+		for v in [
+			"0.1",
+			"-12345.6789",
+			"79228162514264337593543950335",
+			"0.0000000000000000000000000001",
+		] {
+			let dec = Decimal::from_str_exact(v).unwrap();
+			let number = Number::from(dec);
+			assert_eq!(number.try_to_decimal(), Some(dec));
+		}
+		assert_eq!(Number::Int(i64::MAX).try_to_decimal(), Some(Decimal::from(i64::MAX)));
+		assert_eq!(Number::Int(i64::MIN).try_to_decimal(), Some(Decimal::from(i64::MIN)));
+	}
+
+	#[test]
+	fn try_to_decimal_float() {
+		for (v, expected) in [
+			(0.1, "0.1"),
+			(2.675, "2.675"),
+			(1.0 / 3.0, "0.3333333333333333"),
+			(0.1 + 0.2, "0.3"),
+			(1e-20, "0.00000000000000000001"),
+		] {
+			let expected = Decimal::from_str_exact(expected).unwrap();
+			assert_eq!(Number::Float(v).try_to_decimal(), Some(expected), "{v}");
+		}
+		assert_eq!(Number::Float(f64::NAN).try_to_decimal(), None);
+		assert_eq!(Number::Float(f64::INFINITY).try_to_decimal(), None);
+		assert_eq!(Number::Float(1e30).try_to_decimal(), None);
+	}
 	#[test]
 	fn test_try_float_div() {
 		let (sum_one, count_one) = (Number::Int(5), Number::Int(2));
@@ -1189,4 +1331,88 @@ mod tests {
 			assert_consistent(a, b, c);
 		}
 	}
+
+	#[test]
+	fn gcd_and_lcm() {
+		let gcd = |a: Number, b: Number| a.gcd(&b);
+		let lcm = |a: Number, b: Number| a.lcm(&b);
+		assert_eq!(gcd(Number::Int(12), Number::Int(18)), Some(Number::Int(6)));
+		assert_eq!(lcm(Number::Int(4), Number::Int(6)), Some(Number::Int(12)));
+		// Negative inputs give a positive result
+		assert_eq!(gcd(Number::Int(-12), Number::Int(18)), Some(Number::Int(6)));
+		assert_eq!(gcd(Number::Int(-12), Number::Int(-18)), Some(Number::Int(6)));
+		assert_eq!(lcm(Number::Int(-4), Number::Int(6)), Some(Number::Int(12)));
+		// Zero
+		assert_eq!(gcd(Number::Int(0), Number::Int(-5)), Some(Number::Int(5)));
+		assert_eq!(gcd(Number::Int(0), Number::Int(0)), Some(Number::Int(0)));
+		assert_eq!(lcm(Number::Int(0), Number::Int(5)), Some(Number::Int(0)));
+		// Whole floats and decimals
+		assert_eq!(
+			gcd(Number::Float(12.0), Number::Decimal(Decimal::from(8))),
+			Some(Number::Int(4))
+		);
+		// Non-integers
+		assert_eq!(gcd(Number::Float(1.5), Number::Int(3)), None);
+		assert_eq!(lcm(Number::Int(3), Number::Decimal(Decimal::new(25, 1))), None);
+		assert_eq!(gcd(Number::NAN, Number::Int(3)), None);
+		// Results which don't fit in an integer
+		assert_eq!(gcd(Number::Int(i64::MIN), Number::Int(0)), None);
+		assert_eq!(lcm(Number::Int(i64::MAX), Number::Int(2)), None);
+	}
+
+	#[test]
+	fn parity() {
+		assert_eq!(Number::Int(4).is_even(), Some(true));
+		assert_eq!(Number::Int(-3).is_even(), Some(false));
+		assert_eq!(Number::Int(-3).is_odd(), Some(true));
+		assert_eq!(Number::Int(0).is_odd(), Some(false));
+		assert_eq!(Number::Float(6.0).is_even(), Some(true));
+		assert_eq!(Number::Decimal(Decimal::from(7)).is_odd(), Some(true));
+		assert_eq!(Number::Float(2.5).is_even(), None);
+		assert_eq!(Number::Decimal(Decimal::new(25, 1)).is_odd(), None);
+		assert_eq!(Number::Float(f64::INFINITY).is_even(), None);
+	}
+
+	#[test]
+	fn exact_int_float_bounds() {
+		// 2^63 saturates to `i64::MAX` when cast, but is not representable as an `i64`
+		assert_eq!(Number::Float(9.223372036854775808e18).to_exact_int(), None);
+		assert_eq!(Number::Float(-9.223372036854775808e18).to_exact_int(), Some(i64::MIN));
+		assert_eq!(Number::Float(-9.223372036854777856e18).to_exact_int(), None);
+		assert_eq!(
+			Number::Float(9.223372036854774784e18).to_exact_int(),
+			Some(9223372036854774784)
+		);
+		assert_eq!(Number::Float(9.223372036854775808e18).is_even(), None);
+	}
+
+	#[test]
+	fn saturating_overflow() {
+		assert_eq!(Number::Int(i64::MAX).to_i32_saturating(), i32::MAX);
+		assert_eq!(Number::Int(i64::MIN).to_i32_saturating(), i32::MIN);
+		assert_eq!(Number::Int(-1).to_u32_saturating(), 0);
+		assert_eq!(Number::Int(1 << 40).to_u32_saturating(), u32::MAX);
+		assert_eq!(Number::Int(-300).to_u8_saturating(), 0);
+		assert_eq!(Number::Int(300).to_u8_saturating(), u8::MAX);
+		assert_eq!(Number::Int(200).to_u8_saturating(), 200);
+		assert_eq!(Number::Float(1e20).to_i32_saturating(), i32::MAX);
+		assert_eq!(Number::Float(f64::NEG_INFINITY).to_i32_saturating(), i32::MIN);
+		assert_eq!(Number::Float(f64::INFINITY).to_u8_saturating(), u8::MAX);
+		assert_eq!(Number::Decimal(Decimal::MAX).to_u32_saturating(), u32::MAX);
+		assert_eq!(Number::Decimal(Decimal::MIN).to_i32_saturating(), i32::MIN);
+		assert_eq!(Number::NAN.to_i32_saturating(), 0);
+	}
+
+	#[test]
+	fn saturating_rounding() {
+		assert_eq!(Number::Float(1.4).to_i32_saturating(), 1);
+		assert_eq!(Number::Float(1.5).to_i32_saturating(), 2);
+		assert_eq!(Number::Float(2.5).to_i32_saturating(), 3);
+		assert_eq!(Number::Float(-1.5).to_i32_saturating(), -2);
+		assert_eq!(Number::Float(-0.4).to_u32_saturating(), 0);
+		assert_eq!(Number::Float(254.6).to_u8_saturating(), 255);
+		assert_eq!(Number::Decimal(Decimal::new(25, 1)).to_i32_saturating(), 3);
+		assert_eq!(Number::Decimal(Decimal::new(-25, 1)).to_i32_saturating(), -3);
+		assert_eq!(Number::Decimal(Decimal::new(1234, 3)).to_u32_saturating(), 1);
+	}
 }
@@ -19,6 +19,11 @@ use std::ops::DerefMut;
 pub(crate) const TOKEN: &str = "$surrealdb::private::sql::Object";
 
 /// Invariant: Keys never contain NUL bytes.
+///
+/// Keys are always kept in ascending order, so iterating, formatting as
+/// SurrealQL, and serializing an object all visit its entries in sorted
+/// key order, regardless of how the object was constructed. This ordering
+/// is stable and can be relied upon for deterministic output.
 #[revisioned(revision = 1)]
 #[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[serde(rename = "$surrealdb::private::sql::Object")]
@@ -130,6 +135,12 @@ impl DerefMut for Object {
 	}
 }
 
+impl FromIterator<(String, Value)> for Object {
+	fn from_iter<I: IntoIterator<Item = (String, Value)>>(iter: I) -> Self {
+		Self(BTreeMap::from_iter(iter))
+	}
+}
+
 impl IntoIterator for Object {
 	type Item = (String, Value);
 	type IntoIter = std::collections::btree_map::IntoIter<String, Value>;
@@ -139,6 +150,11 @@ impl IntoIterator for Object {
 }
 
 impl Object {
+	/// Iterate over the entries of this object in ascending key order
+	pub fn sorted_entries(&self) -> impl Iterator<Item = (&String, &Value)> {
+		self.0.iter()
+	}
+
 	/// Fetch the record id if there is one
 	pub fn rid(&self) -> Option<Thing> {
 		match self.get("id") {
@@ -328,3 +344,33 @@ mod no_nul_bytes_in_keys {
 		deserializer.deserialize_map(NoNulBytesInKeysVisitor)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn sorted_entries_from_unsorted() {
+		let obj: Object = vec![
+			(String::from("c"), Value::from(3)),
+			(String::from("a"), Value::from(1)),
+			(String::from("b"), Value::from(2)),
+		]
+		.into_iter()
+		.collect();
+		let keys: Vec<&str> = obj.sorted_entries().map(|(k, _)| k.as_str()).collect();
+		assert_eq!(keys, vec!["a", "b", "c"]);
+		assert_eq!(obj.to_string(), "{ a: 1, b: 2, c: 3 }");
+	}
+
+	#[test]
+	fn sorted_entries_from_hashmap() {
+		let map: HashMap<String, Value> =
+			(0..50).map(|i| (format!("k{i:02}"), Value::from(i))).collect();
+		let obj = Object::from(map);
+		let keys: Vec<&String> = obj.sorted_entries().map(|(k, _)| k).collect();
+		let mut sorted = keys.clone();
+		sorted.sort();
+		assert_eq!(keys, sorted);
+	}
+}
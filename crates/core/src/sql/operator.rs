@@ -89,6 +89,76 @@ impl Operator {
 			_ => 5,
 		}
 	}
+
+	/// Returns how tightly this operator binds its operands, matching the parser.
+	///
+	/// Operators with a higher binding power are evaluated before those with a lower one.
+	pub(crate) fn binding_power(&self) -> u8 {
+		match self {
+			Self::Inc | Self::Dec | Self::Ext => 0,
+			Self::Or => 1,
+			Self::And => 2,
+			Self::Equal
+			| Self::Exact
+			| Self::NotEqual
+			| Self::AllEqual
+			| Self::AnyEqual
+			| Self::Like
+			| Self::NotLike
+			| Self::AllLike
+			| Self::AnyLike
+			| Self::Matches(_) => 3,
+			Self::LessThan
+			| Self::LessThanOrEqual
+			| Self::MoreThan
+			| Self::MoreThanOrEqual
+			| Self::Contain
+			| Self::NotContain
+			| Self::ContainAll
+			| Self::ContainAny
+			| Self::ContainNone
+			| Self::Inside
+			| Self::NotInside
+			| Self::AllInside
+			| Self::AnyInside
+			| Self::NoneInside
+			| Self::Outside
+			| Self::Intersects
+			| Self::Knn(..)
+			| Self::Ann(..) => 4,
+			Self::Add | Self::Sub => 5,
+			Self::Mul | Self::Div | Self::Rem => 6,
+			Self::Pow => 7,
+			Self::Tco | Self::Nco => 8,
+			Self::Neg | Self::Not => 9,
+		}
+	}
+
+	/// Returns whether this operator is a relation, which can not be chained without parens.
+	pub(crate) fn is_relation(&self) -> bool {
+		matches!(
+			self,
+			Self::Equal
+				| Self::NotEqual
+				| Self::AllEqual
+				| Self::AnyEqual
+				| Self::NotLike
+				| Self::AllLike
+				| Self::AnyLike
+				| Self::Like | Self::Contain
+				| Self::NotContain
+				| Self::NotInside
+				| Self::ContainAll
+				| Self::ContainNone
+				| Self::AllInside
+				| Self::AnyInside
+				| Self::NoneInside
+				| Self::Outside
+				| Self::Intersects
+				| Self::Inside
+				| Self::Knn(_, _)
+		)
+	}
 }
 
 impl fmt::Display for Operator {
@@ -119,6 +119,14 @@ impl From<Vec<Statement>> for Query {
 	}
 }
 
+impl Query {
+	/// Formats the query as canonical SurrealQL, with statements and clauses split over
+	/// multiple lines and nested values indented.
+	pub fn to_sql_pretty(&self) -> String {
+		format!("{self:#}")
+	}
+}
+
 impl Deref for Query {
 	type Target = Vec<Statement>;
 	fn deref(&self) -> &Self::Target {
@@ -1,5 +1,7 @@
 use crate::cnf::REGEX_CACHE_SIZE;
+use crate::err::Error;
 use quick_cache::sync::{Cache, GuardResult};
+use regex::RegexBuilder;
 use revision::revisioned;
 use serde::{
 	de::{self, Visitor},
@@ -25,6 +27,31 @@ impl Regex {
 	pub fn regex(&self) -> &regex::Regex {
 		&self.0
 	}
+
+	/// Compiles an untrusted pattern within a memory budget
+	///
+	/// `size_limit` bounds the size of the compiled program and `dfa_size_limit` bounds the
+	/// cache used by the lazy DFA while matching, both in bytes. Patterns which would exceed
+	/// the compile budget are rejected with [`Error::InvalidRegex`]. A DFA cache which fills
+	/// up makes matching fall back to a slower engine rather than failing.
+	///
+	/// The regex engine never backtracks, so matching always takes time linear in the size
+	/// of the input. Together with the limits this bounds the cost of untrusted patterns.
+	/// Unlike parsed regexes, regexes compiled with limits are not cached.
+	pub fn compile_with_limits(
+		pattern: &str,
+		size_limit: usize,
+		dfa_size_limit: usize,
+	) -> Result<Self, Error> {
+		if pattern.contains('\0') {
+			return Err(Error::InvalidRegex("regex contained NUL byte".to_owned()));
+		}
+		let regex = RegexBuilder::new(pattern)
+			.size_limit(size_limit)
+			.dfa_size_limit(dfa_size_limit)
+			.build()?;
+		Ok(Self(regex))
+	}
 }
 
 fn regex_new(str: &str) -> Result<regex::Regex, regex::Error> {
@@ -148,3 +175,30 @@ impl<'de> Deserialize<'de> for Regex {
 		deserializer.deserialize_newtype_struct(TOKEN, RegexNewtypeVisitor)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn compile_with_limits() {
+		let regex = Regex::compile_with_limits("^[a-z]+@[a-z]+$", 1 << 16, 1 << 16).unwrap();
+		assert!(regex.regex().is_match("tobie@surrealdb"));
+		assert!(!regex.regex().is_match("tobie"));
+	}
+
+	#[test]
+	fn compile_with_limits_rejects_large_patterns() {
+		// Counted repetitions are expanded when compiled, so this grows to thousands of states
+		let pattern = r"\w{50}";
+		let Err(Error::InvalidRegex(error)) = Regex::compile_with_limits(pattern, 1 << 16, 1 << 16)
+		else {
+			panic!("expected the pattern to exceed the size limit");
+		};
+		assert!(error.contains("size limit"), "{error}");
+		// The same pattern compiles without the tight limit
+		assert!(Regex::compile_with_limits(pattern, 10 << 20, 1 << 16).is_ok());
+		assert!(Regex::compile_with_limits("a\0", 1 << 16, 1 << 16).is_err());
+	}
+}
@@ -0,0 +1,247 @@
+use crate::sql::id::Id;
+use crate::sql::number::Number;
+use crate::sql::value::Value;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+// Each value is prefixed with a tag so values of different types never share an encoding
+const NONE: u8 = 0;
+const NULL: u8 = 1;
+const BOOL: u8 = 2;
+const INT: u8 = 3;
+const FLOAT: u8 = 4;
+const DECIMAL: u8 = 5;
+const STRAND: u8 = 6;
+const DURATION: u8 = 7;
+const DATETIME: u8 = 8;
+const UUID: u8 = 9;
+const ARRAY: u8 = 10;
+const OBJECT: u8 = 11;
+const GEOMETRY: u8 = 12;
+const BYTES: u8 = 13;
+const THING: u8 = 14;
+const OTHER: u8 = 15;
+
+impl Value {
+	/// Returns a deterministic byte representation of this value, suitable for hashing
+	///
+	/// Values which are equal produce the same bytes. To achieve this the encoding is
+	/// normalized as follows:
+	///
+	/// - Object keys are written in sorted order.
+	/// - Numbers are written by value rather than by type, so `1`, `1.0f` and `1dec` are
+	///   encoded the same, as are `0.5f` and `0.5dec`. Trailing zeros of decimals are
+	///   dropped and `-0.0f` is encoded as `0`.
+	/// - All NaN floats are encoded the same.
+	/// - Geometries and un-computed values, such as params and expressions, are written as
+	///   their SurrealQL representation.
+	///
+	/// The encoding is not compatible with any wire format and may change between releases,
+	/// so it should not be persisted.
+	pub fn canonical_bytes(&self) -> Vec<u8> {
+		let mut bytes = Vec::new();
+		self.write_canonical(&mut bytes);
+		bytes
+	}
+
+	/// Returns a hash of the [canonical bytes](Value::canonical_bytes) of this value
+	///
+	/// Values which are equal have the same hash, while different values usually don't.
+	/// The hash is stable within a process but may change between releases.
+	pub fn content_hash(&self) -> u64 {
+		let mut hasher = DefaultHasher::new();
+		self.canonical_bytes().hash(&mut hasher);
+		hasher.finish()
+	}
+
+	fn write_canonical(&self, out: &mut Vec<u8>) {
+		match self {
+			Value::None => out.push(NONE),
+			Value::Null => out.push(NULL),
+			Value::Bool(v) => {
+				out.push(BOOL);
+				out.push(*v as u8);
+			}
+			Value::Number(v) => write_number(v, out),
+			Value::Strand(v) => {
+				out.push(STRAND);
+				write_str(&v.0, out);
+			}
+			Value::Duration(v) => {
+				out.push(DURATION);
+				out.extend(v.0.as_secs().to_le_bytes());
+				out.extend(v.0.subsec_nanos().to_le_bytes());
+			}
+			Value::Datetime(v) => {
+				out.push(DATETIME);
+				out.extend(v.0.timestamp().to_le_bytes());
+				out.extend(v.0.timestamp_subsec_nanos().to_le_bytes());
+			}
+			Value::Uuid(v) => {
+				out.push(UUID);
+				out.extend(v.0.as_bytes());
+			}
+			Value::Array(v) => {
+				out.push(ARRAY);
+				write_len(v.len(), out);
+				for v in v.iter() {
+					v.write_canonical(out);
+				}
+			}
+			Value::Object(v) => {
+				out.push(OBJECT);
+				write_len(v.len(), out);
+				// Objects are backed by a sorted map, so the keys are already in order
+				for (k, v) in v.iter() {
+					write_str(k, out);
+					v.write_canonical(out);
+				}
+			}
+			Value::Geometry(v) => {
+				out.push(GEOMETRY);
+				write_str(&v.to_string(), out);
+			}
+			Value::Bytes(v) => {
+				out.push(BYTES);
+				write_len(v.0.len(), out);
+				out.extend(&v.0);
+			}
+			Value::Thing(v) => {
+				out.push(THING);
+				write_str(&v.tb, out);
+				match &v.id {
+					Id::Number(v) => write_number(&Number::Int(*v), out),
+					Id::String(v) => {
+						out.push(STRAND);
+						write_str(v, out);
+					}
+					Id::Uuid(v) => Value::Uuid(*v).write_canonical(out),
+					Id::Array(v) => Value::Array(v.clone()).write_canonical(out),
+					Id::Object(v) => Value::Object(v.clone()).write_canonical(out),
+					v => {
+						out.push(OTHER);
+						write_str(&v.to_string(), out);
+					}
+				}
+			}
+			v => {
+				out.push(OTHER);
+				write_str(&v.to_string(), out);
+			}
+		}
+	}
+}
+
+/// Writes a number by value, so numbers which compare equal are written the same
+fn write_number(v: &Number, out: &mut Vec<u8>) {
+	match v {
+		Number::Int(v) => write_int(*v as i128, out),
+		Number::Float(v) => {
+			// Floats of this size fit in an i128 without losing the integer part
+			if v.is_finite() && v.fract() == 0.0 && v.abs() < 2f64.powi(126) {
+				write_int(*v as i128, out)
+			} else if v.is_nan() {
+				write_float(f64::NAN, out)
+			} else {
+				write_float(*v, out)
+			}
+		}
+		Number::Decimal(v) => {
+			let v = v.normalize();
+			if v.fract().is_zero() {
+				// The integer part of a decimal always fits in an i128
+				if let Some(v) = v.to_i128() {
+					return write_int(v, out);
+				}
+			}
+			// Decimals which a float represents exactly are written as that float
+			match v.to_f64() {
+				Some(f) if Decimal::from_f64_retain(f) == Some(v) => write_float(f, out),
+				_ => {
+					out.push(DECIMAL);
+					out.extend(v.serialize());
+				}
+			}
+		}
+	}
+}
+
+fn write_int(v: i128, out: &mut Vec<u8>) {
+	out.push(INT);
+	out.extend(v.to_le_bytes());
+}
+
+fn write_float(v: f64, out: &mut Vec<u8>) {
+	out.push(FLOAT);
+	out.extend(v.to_bits().to_le_bytes());
+}
+
+fn write_len(len: usize, out: &mut Vec<u8>) {
+	out.extend((len as u64).to_le_bytes());
+}
+
+fn write_str(v: &str, out: &mut Vec<u8>) {
+	write_len(v.len(), out);
+	out.extend(v.as_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::syn::Parse;
+
+	#[test]
+	fn canonical_equal_values() {
+		for (a, b) in [
+			("{ a: 1, b: 'text', c: [true, NONE] }", "{ c: [true, NONE], b: 'text', a: 1 }"),
+			("1", "1.0f"),
+			("1", "1dec"),
+			("1.50dec", "1.5dec"),
+			("0.5f", "0.5dec"),
+			("-0.0f", "0"),
+			("1e20f", "100000000000000000000dec"),
+			("person:1", "person:1"),
+			("d'2024-01-01T00:00:00Z'", "d'2024-01-01T00:00:00.000Z'"),
+			("1h", "60m"),
+		] {
+			let (a, b) = (Value::parse(a), Value::parse(b));
+			assert_eq!(a, b);
+			assert_eq!(a.canonical_bytes(), b.canonical_bytes(), "{a} and {b}");
+			assert_eq!(a.content_hash(), b.content_hash(), "{a} and {b}");
+		}
+	}
+
+	#[test]
+	fn canonical_different_values() {
+		let values = [
+			"NONE",
+			"NULL",
+			"false",
+			"0",
+			"1",
+			"0.1f",
+			"0.1dec",
+			"'1'",
+			"[]",
+			"{}",
+			"[1, 2]",
+			"[2, 1]",
+			"{ a: 1 }",
+			"{ a: '1' }",
+			"{ b: 1 }",
+			"person:1",
+			"person:⟨1⟩",
+			"1s",
+			"$param",
+		]
+		.map(Value::parse);
+		for (i, a) in values.iter().enumerate() {
+			for b in &values[i + 1..] {
+				assert_ne!(a.canonical_bytes(), b.canonical_bytes(), "{a} and {b}");
+				assert_ne!(a.content_hash(), b.content_hash(), "{a} and {b}");
+			}
+		}
+	}
+}
@@ -0,0 +1,90 @@
+use crate::sql::value::Value;
+
+impl Value {
+	/// Check if this value is equal to another value, ignoring the order of array items
+	///
+	/// Objects are compared key by key, and arrays are compared as multisets,
+	/// so every item must appear the same number of times in both arrays.
+	/// Nested arrays are also compared without regard to their order.
+	pub fn deep_eq_ignore_order(&self, other: &Value) -> bool {
+		match (self, other) {
+			(Value::Object(a), Value::Object(b)) => {
+				a.len() == b.len()
+					&& a.iter().all(|(k, v)| b.get(k).is_some_and(|w| v.deep_eq_ignore_order(w)))
+			}
+			(Value::Array(a), Value::Array(b)) => {
+				if a.len() != b.len() {
+					return false;
+				}
+				// Match every item against a distinct unmatched item
+				let mut matched = vec![false; b.len()];
+				a.iter().all(|v| {
+					match (0..b.len()).find(|&i| !matched[i] && v.deep_eq_ignore_order(&b[i])) {
+						Some(i) => {
+							matched[i] = true;
+							true
+						}
+						None => false,
+					}
+				})
+			}
+			(a, b) => a == b,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::syn::Parse;
+
+	#[test]
+	fn deep_eq_ignore_order_reordered() {
+		let a = Value::parse("[1, 2, 3, 'test']");
+		let b = Value::parse("['test', 3, 1, 2]");
+		assert!(a.deep_eq_ignore_order(&b));
+	}
+
+	#[test]
+	fn deep_eq_ignore_order_counts() {
+		let a = Value::parse("[1, 1, 2]");
+		let b = Value::parse("[1, 2, 2]");
+		assert!(!a.deep_eq_ignore_order(&b));
+		let a = Value::parse("[1, 2]");
+		let b = Value::parse("[1, 2, 2]");
+		assert!(!a.deep_eq_ignore_order(&b));
+	}
+
+	#[test]
+	fn deep_eq_ignore_order_nested() {
+		let a = Value::parse(
+			"[
+				{ name: 'Tobie', tags: ['code', 'databases'], address: { city: 'London' } },
+				{ name: 'Jaime', tags: ['design', 'operations'] },
+			]",
+		);
+		let b = Value::parse(
+			"[
+				{ name: 'Jaime', tags: ['operations', 'design'] },
+				{ address: { city: 'London' }, tags: ['databases', 'code'], name: 'Tobie' },
+			]",
+		);
+		assert!(a.deep_eq_ignore_order(&b));
+		let c = Value::parse(
+			"[
+				{ name: 'Jaime', tags: ['operations', 'design'] },
+				{ address: { city: 'Paris' }, tags: ['databases', 'code'], name: 'Tobie' },
+			]",
+		);
+		assert!(!a.deep_eq_ignore_order(&c));
+	}
+
+	#[test]
+	fn deep_eq_ignore_order_objects() {
+		let a = Value::parse("{ test: true, other: [1, 2] }");
+		let b = Value::parse("{ test: true }");
+		assert!(!a.deep_eq_ignore_order(&b));
+		assert!(!b.deep_eq_ignore_order(&a));
+	}
+}
@@ -0,0 +1,89 @@
+use crate::sql::value::Value;
+use std::ops::Bound;
+
+impl Value {
+	/// Count the total number of values in this value tree, including this value itself
+	///
+	/// This descends into the elements of arrays, the values of objects,
+	/// the bounds of ranges, and the bodies of closures.
+	pub fn count_nodes(&self) -> usize {
+		let mut count = 1;
+		self.each_child(|v| count += v.count_nodes());
+		count
+	}
+
+	/// Get the maximum nesting depth of this value tree
+	///
+	/// A value without any children has a depth of zero, so `1` has
+	/// a depth of zero, while `[]` and `[1]` both have a depth of one.
+	pub fn depth(&self) -> usize {
+		match self {
+			Value::Array(_) | Value::Object(_) | Value::Range(_) | Value::Closure(_) => {
+				let mut depth = 0;
+				self.each_child(|v| depth = depth.max(v.depth()));
+				depth + 1
+			}
+			_ => 0,
+		}
+	}
+
+	fn each_child<'a>(&'a self, mut f: impl FnMut(&'a Value)) {
+		match self {
+			Value::Array(v) => v.iter().for_each(f),
+			Value::Object(v) => v.values().for_each(f),
+			Value::Range(v) => {
+				for bound in [&v.beg, &v.end] {
+					if let Bound::Included(v) | Bound::Excluded(v) = bound {
+						f(v);
+					}
+				}
+			}
+			Value::Closure(v) => f(&v.body),
+			_ => (),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::syn::Parse;
+
+	#[test]
+	fn metrics_scalar() {
+		let val = Value::parse("'test'");
+		assert_eq!(val.count_nodes(), 1);
+		assert_eq!(val.depth(), 0);
+	}
+
+	#[test]
+	fn metrics_flat() {
+		let val = Value::parse("[1, 2, 3]");
+		assert_eq!(val.count_nodes(), 4);
+		assert_eq!(val.depth(), 1);
+		let val = Value::parse("{}");
+		assert_eq!(val.count_nodes(), 1);
+		assert_eq!(val.depth(), 1);
+	}
+
+	#[test]
+	fn metrics_nested() {
+		let val = Value::parse("{ a: [1, { b: [[2]] }], c: 3 }");
+		assert_eq!(val.count_nodes(), 8);
+		assert_eq!(val.depth(), 5);
+		let val = Value::parse(&format!("{}1{}", "[".repeat(50), "]".repeat(50)));
+		assert_eq!(val.count_nodes(), 51);
+		assert_eq!(val.depth(), 50);
+	}
+
+	#[test]
+	fn metrics_range_and_closure() {
+		let val = Value::parse("[1..=[2, 3]]");
+		assert_eq!(val.count_nodes(), 6);
+		assert_eq!(val.depth(), 3);
+		let val = Value::parse("|$a: int| [$a]");
+		assert_eq!(val.count_nodes(), 3);
+		assert_eq!(val.depth(), 2);
+	}
+}
@@ -6,6 +6,7 @@ pub(super) mod serde;
 mod value;
 
 mod all;
+mod canonical;
 mod changed;
 mod clear;
 mod compare;
@@ -15,6 +16,7 @@ mod def;
 mod del;
 mod diff;
 mod each;
+mod equal;
 mod every;
 mod extend;
 mod fetch;
@@ -28,10 +30,15 @@ mod increment;
 mod into_json;
 mod last;
 mod merge;
+mod metrics;
 mod patch;
 mod pick;
+mod project;
 mod put;
+mod redact;
 mod replace;
 mod rid;
 mod set;
+mod sort;
+mod validate;
 mod walk;
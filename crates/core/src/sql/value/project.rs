@@ -0,0 +1,64 @@
+use crate::sql::object::Object;
+use crate::sql::part::Part;
+use crate::sql::value::Value;
+
+impl Value {
+	/// Project an object down to only the specified fields
+	///
+	/// Each key can be a dotted path into nested objects, such as `address.city`.
+	/// Keys which do not exist are omitted from the output, and values which are
+	/// not objects are returned unchanged.
+	pub fn project(&self, keys: &[&str]) -> Value {
+		match self {
+			Value::Object(_) => {
+				let mut out = Value::Object(Object::default());
+				for key in keys {
+					let path: Vec<Part> =
+						key.split('.').map(|k| Part::from(k.to_owned())).collect();
+					match self.pick(&path) {
+						Value::None => (),
+						v => out.put(&path, v),
+					}
+				}
+				out
+			}
+			v => v.clone(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::syn::Parse;
+
+	#[test]
+	fn project_top_level() {
+		let val = Value::parse("{ id: 1, name: 'Tobie', password: 'secret' }");
+		let res = Value::parse("{ id: 1, name: 'Tobie' }");
+		assert_eq!(res, val.project(&["id", "name"]));
+	}
+
+	#[test]
+	fn project_nested() {
+		let val = Value::parse(
+			"{ name: 'Tobie', address: { city: 'London', street: 'Oxford Street' }, age: 36 }",
+		);
+		let res = Value::parse("{ name: 'Tobie', address: { city: 'London' } }");
+		assert_eq!(res, val.project(&["name", "address.city"]));
+	}
+
+	#[test]
+	fn project_missing() {
+		let val = Value::parse("{ name: 'Tobie', address: { city: 'London' } }");
+		let res = Value::parse("{ name: 'Tobie' }");
+		assert_eq!(res, val.project(&["name", "email", "address.country"]));
+	}
+
+	#[test]
+	fn project_non_object() {
+		let val = Value::parse("[{ name: 'Tobie' }]");
+		assert_eq!(val, val.project(&["name"]));
+	}
+}
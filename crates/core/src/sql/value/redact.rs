@@ -0,0 +1,77 @@
+use crate::sql::value::Value;
+
+impl Value {
+	/// Replace the value of every object key matching the predicate with `"[REDACTED]"`
+	///
+	/// This descends through nested objects and arrays, leaving the structure
+	/// of the value intact, so that it can be safely written to logs.
+	pub fn redact<F: Fn(&str) -> bool>(&mut self, should_redact: F) {
+		self.redact_with(&should_redact)
+	}
+
+	fn redact_with<F: Fn(&str) -> bool>(&mut self, should_redact: &F) {
+		match self {
+			Value::Object(v) => {
+				for (key, val) in v.iter_mut() {
+					if should_redact(key) {
+						*val = Value::from("[REDACTED]");
+					} else {
+						val.redact_with(should_redact);
+					}
+				}
+			}
+			Value::Array(v) => {
+				for val in v.iter_mut() {
+					val.redact_with(should_redact);
+				}
+			}
+			_ => (),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::syn::Parse;
+
+	#[test]
+	fn redact_top_level() {
+		let mut val = Value::parse("{ name: 'Tobie', password: 'secret' }");
+		let res = Value::parse("{ name: 'Tobie', password: '[REDACTED]' }");
+		val.redact(|k| k == "password");
+		assert_eq!(res, val);
+	}
+
+	#[test]
+	fn redact_nested() {
+		let mut val = Value::parse(
+			"{ user: { name: 'Tobie', auth: { password: 'secret', token: { iss: 'surreal' } } } }",
+		);
+		let res = Value::parse(
+			"{ user: { name: 'Tobie', auth: { password: '[REDACTED]', token: '[REDACTED]' } } }",
+		);
+		val.redact(|k| k == "password" || k == "token");
+		assert_eq!(res, val);
+	}
+
+	#[test]
+	fn redact_arrays_of_objects() {
+		let mut val = Value::parse(
+			"{ sessions: [{ id: 1, token: 'abc' }, [{ token: 'def', data: { password: 'ghi' } }]] }",
+		);
+		let res = Value::parse(
+			"{ sessions: [{ id: 1, token: '[REDACTED]' }, [{ token: '[REDACTED]', data: { password: '[REDACTED]' } }]] }",
+		);
+		val.redact(|k| k == "password" || k == "token");
+		assert_eq!(res, val);
+	}
+
+	#[test]
+	fn redact_non_object() {
+		let mut val = Value::parse("'password'");
+		val.redact(|_| true);
+		assert_eq!(Value::parse("'password'"), val);
+	}
+}
@@ -81,3 +81,20 @@ where
 	let deserializer = Deserializer::new(content).coerce_numbers();
 	T::deserialize(deserializer).map_err(Into::into)
 }
+
+/// Deserializes a value `T` from `SurrealDB` [`Value`], tracking the path to the field which failed
+///
+/// On failure the path is returned as a list of segments, where object keys
+/// are returned as-is and array indexes are returned as `[index]`. The path and
+/// the error are boxed, as the error is large.
+pub fn from_value_with_path<T>(value: Value) -> Result<T, Box<(Vec<String>, Error)>>
+where
+	T: DeserializeOwned,
+{
+	let content = value.into_content().map_err(|e| Box::new((Vec::new(), e)))?;
+	let deserializer = Deserializer::new(content).coerce_numbers();
+	serde_path_to_error::deserialize(deserializer).map_err(|e| {
+		let path = e.path().iter().map(ToString::to_string).collect();
+		Box::new((path, e.into_inner().into()))
+	})
+}
@@ -2,4 +2,5 @@ mod de;
 mod ser;
 
 pub use de::from_value;
+pub use de::from_value_with_path;
 pub use ser::to_value;
@@ -0,0 +1,128 @@
+use crate::sql::value::Value;
+
+impl Value {
+	/// Recursively sort every array by the natural value ordering
+	///
+	/// Every array is sorted, including arrays of objects and nested arrays.
+	/// Nested arrays and objects are sorted first, then compared as a whole.
+	/// Values of differing types are ordered by their kind, in the same
+	/// order as the variants of [`Value`], so the output is deterministic.
+	/// Use [`Value::sort_arrays_by_key`] to sort arrays of objects by a field.
+	pub fn sort_arrays(&mut self) {
+		match self {
+			Value::Array(v) => {
+				v.iter_mut().for_each(Value::sort_arrays);
+				v.sort_unstable();
+			}
+			Value::Object(v) => v.values_mut().for_each(Value::sort_arrays),
+			_ => (),
+		}
+	}
+
+	/// Recursively sort every array of objects by the value of the specified field
+	///
+	/// Array items which are not objects, or objects which do not contain the
+	/// field, are sorted as if the field was `NONE`. Ties are broken by the
+	/// natural value ordering so that the output is fully deterministic.
+	pub fn sort_arrays_by_key(&mut self, key: &str) {
+		match self {
+			Value::Array(v) => {
+				v.iter_mut().for_each(|v| v.sort_arrays_by_key(key));
+				v.sort_by(|a, b| {
+					let x = match a {
+						Value::Object(o) => o.get(key),
+						_ => None,
+					};
+					let y = match b {
+						Value::Object(o) => o.get(key),
+						_ => None,
+					};
+					x.unwrap_or(&Value::None).cmp(y.unwrap_or(&Value::None)).then_with(|| a.cmp(b))
+				});
+			}
+			Value::Object(v) => v.values_mut().for_each(|v| v.sort_arrays_by_key(key)),
+			_ => (),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::syn::Parse;
+
+	#[test]
+	fn sort_arrays_scalars() {
+		let mut val = Value::parse("[3, 1, 2]");
+		let res = Value::parse("[1, 2, 3]");
+		val.sort_arrays();
+		assert_eq!(res, val);
+	}
+
+	#[test]
+	fn sort_arrays_mixed() {
+		let mut val = Value::parse("['b', 2, true, NULL, 'a', 1]");
+		let res = Value::parse("[NULL, true, 1, 2, 'a', 'b']");
+		val.sort_arrays();
+		assert_eq!(res, val);
+	}
+
+	#[test]
+	fn sort_arrays_nested() {
+		let mut val = Value::parse("{ test: { tags: ['c', 'a', 'b'] }, other: [[2, 1], [0]] }");
+		let res = Value::parse("{ test: { tags: ['a', 'b', 'c'] }, other: [[0], [1, 2]] }");
+		val.sort_arrays();
+		assert_eq!(res, val);
+	}
+
+	#[test]
+	fn sort_arrays_objects() {
+		let mut val = Value::parse("[{ b: [2, 1] }, { a: 2 }, { a: 1 }]");
+		let res = Value::parse("[{ a: 1 }, { a: 2 }, { b: [1, 2] }]");
+		val.sort_arrays();
+		assert_eq!(res, val);
+	}
+
+	#[test]
+	fn sort_arrays_by_key_objects() {
+		let mut val = Value::parse("[{ age: 36, name: 'Tobie' }, { age: 24, name: 'Jaime' }]");
+		let res = Value::parse("[{ age: 24, name: 'Jaime' }, { age: 36, name: 'Tobie' }]");
+		val.sort_arrays_by_key("age");
+		assert_eq!(res, val);
+	}
+
+	#[test]
+	fn sort_arrays_by_key_missing() {
+		let mut val = Value::parse("[{ age: 36 }, 'test', { name: 'Jaime' }, { age: 24 }]");
+		let res = Value::parse("['test', { name: 'Jaime' }, { age: 24 }, { age: 36 }]");
+		val.sort_arrays_by_key("age");
+		assert_eq!(res, val);
+	}
+
+	#[test]
+	fn sort_arrays_by_key_nested() {
+		let mut val = Value::parse(
+			"{
+				test: {
+					something: [
+						{ id: 2, items: [{ id: 'b' }, { id: 'a' }] },
+						{ id: 1, items: [{ id: 'd' }, { id: 'c' }] },
+					],
+				},
+			}",
+		);
+		let res = Value::parse(
+			"{
+				test: {
+					something: [
+						{ id: 1, items: [{ id: 'c' }, { id: 'd' }] },
+						{ id: 2, items: [{ id: 'a' }, { id: 'b' }] },
+					],
+				},
+			}",
+		);
+		val.sort_arrays_by_key("id");
+		assert_eq!(res, val);
+	}
+}
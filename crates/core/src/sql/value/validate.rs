@@ -0,0 +1,125 @@
+use crate::err::Error;
+use crate::sql::escape::escape_key;
+use crate::sql::kind::{Kind, Literal};
+use crate::sql::value::Value;
+
+impl Value {
+	/// Check whether this value would be accepted by a field of the specified `Kind`
+	pub fn matches_kind(&self, kind: &Kind) -> bool {
+		self.validate_against(kind).is_ok()
+	}
+
+	/// Check whether this value would be accepted by a field of the specified `Kind`,
+	/// returning the path to the first nested value which does not conform
+	///
+	/// Values are checked with the same coercion rules as a field type check, so a
+	/// value which would be coerced into the kind is accepted. The path starts at
+	/// `$value`, such as `$value.address.city` or `$value.tags[1]`.
+	pub fn validate_against(&self, kind: &Kind) -> Result<(), Error> {
+		self.validate_at(kind, "$value")
+	}
+
+	fn validate_at(&self, kind: &Kind, path: &str) -> Result<(), Error> {
+		let check = || Error::KindCheck {
+			value: self.to_string(),
+			path: path.to_owned(),
+			check: kind.to_string(),
+		};
+		match (kind, self) {
+			(Kind::Any, _) => Ok(()),
+			(Kind::Option(_), Value::None) => Ok(()),
+			(Kind::Option(k), _) => self.validate_at(k, path),
+			(Kind::Either(kinds), _) => match kinds.iter().any(|k| self.matches_kind(k)) {
+				true => Ok(()),
+				false => Err(check()),
+			},
+			(Kind::Array(k, len) | Kind::Set(k, len), Value::Array(v)) => {
+				if len.is_some_and(|len| v.len() as u64 > len) {
+					return Err(check());
+				}
+				for (i, v) in v.iter().enumerate() {
+					v.validate_at(k, &format!("{path}[{i}]"))?;
+				}
+				Ok(())
+			}
+			(Kind::Literal(Literal::Array(kinds)), Value::Array(v)) => {
+				if kinds.len() != v.len() {
+					return Err(check());
+				}
+				for (i, (k, v)) in kinds.iter().zip(v.iter()).enumerate() {
+					v.validate_at(k, &format!("{path}[{i}]"))?;
+				}
+				Ok(())
+			}
+			(Kind::Literal(Literal::Object(fields)), Value::Object(v)) => {
+				for (key, k) in fields.iter() {
+					let value = v.get(key).unwrap_or(&Value::None);
+					value.validate_at(k, &format!("{path}.{}", escape_key(key)))?;
+				}
+				// Literal objects do not accept any other fields
+				if let Some((key, value)) = v.iter().find(|(key, _)| !fields.contains_key(*key)) {
+					return Err(Error::KindCheck {
+						value: value.to_string(),
+						path: format!("{path}.{}", escape_key(key)),
+						check: "none".to_owned(),
+					});
+				}
+				Ok(())
+			}
+			// Everything else is accepted if it could be coerced
+			_ => match self.clone().coerce_to(kind) {
+				Ok(_) => Ok(()),
+				Err(Error::CoerceTo {
+					..
+				}) => Err(check()),
+				Err(e) => Err(e),
+			},
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::syn;
+
+	#[test]
+	fn matching_object() {
+		let check = syn::kind(
+			"{ name: string, age: option<int>, tags: array<string>, role: 'admin' | 'user' }",
+		)
+		.unwrap();
+		let val = syn::value("{ name: 'Tobie', tags: ['rust'], role: 'admin' }").unwrap();
+		assert!(val.matches_kind(&check));
+		val.validate_against(&check).unwrap();
+		let val = syn::value("{ name: 'Tobie', age: 30, tags: [], role: 'user' }").unwrap();
+		assert!(val.matches_kind(&check));
+		assert!(Value::None.matches_kind(&syn::kind("option<string>").unwrap()));
+		assert!(!Value::None.matches_kind(&syn::kind("string").unwrap()));
+	}
+
+	#[test]
+	fn wrong_nested_field() {
+		let check = syn::kind("{ name: string, address: { city: string, zip: int } }").unwrap();
+		let val = syn::value("{ name: 'Tobie', address: { city: 'London', zip: 'SW1' } }").unwrap();
+		assert!(!val.matches_kind(&check));
+		let err = val.validate_against(&check).unwrap_err();
+		assert_eq!(err.to_string(), "Found 'SW1' for `$value.address.zip`, but expected a int");
+		let val = syn::value("{ name: 'Tobie', address: { city: 'London', zip: 1, extra: true } }")
+			.unwrap();
+		let err = val.validate_against(&check).unwrap_err();
+		assert_eq!(err.to_string(), "Found true for `$value.address.extra`, but expected a none");
+	}
+
+	#[test]
+	fn wrong_array_element() {
+		let check = syn::kind("array<int | { id: string }>").unwrap();
+		let val = syn::value("[1, { id: 'a' }, { id: 2 }]").unwrap();
+		let err = val.validate_against(&check).unwrap_err();
+		assert_eq!(
+			err.to_string(),
+			"Found { id: 2 } for `$value[2]`, but expected a int | { id: string }"
+		);
+	}
+}
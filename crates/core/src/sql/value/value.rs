@@ -1074,6 +1074,14 @@ impl Value {
 		matches!(self, Value::Closure(_))
 	}
 
+	/// Returns the Closure if this Value is a Closure
+	pub fn as_closure(&self) -> Option<&Closure> {
+		match self {
+			Value::Closure(v) => Some(v),
+			_ => None,
+		}
+	}
+
 	/// Check if this Value is a Thing, and belongs to a certain table
 	pub fn is_record_of_table(&self, table: String) -> bool {
 		match self {
@@ -1090,6 +1098,14 @@ impl Value {
 		matches!(self, Value::Geometry(_))
 	}
 
+	/// Returns the Geometry if this Value is a Geometry
+	pub fn as_geometry(&self) -> Option<&Geometry> {
+		match self {
+			Value::Geometry(v) => Some(v),
+			_ => None,
+		}
+	}
+
 	/// Check if this Value is an int Number
 	pub fn is_int(&self) -> bool {
 		matches!(self, Value::Number(Number::Int(_)))
@@ -3263,4 +3279,22 @@ mod tests {
 		let value = Value::from(vector);
 		assert!(matches!(value, Value::Array(Array(_))));
 	}
+
+	#[test]
+	fn geometry_from_coordinates() {
+		let point = Value::from(Geometry::from((-0.118, 51.509)));
+		match point.as_geometry() {
+			Some(Geometry::Point(p)) => assert_eq!(p.x_y(), (-0.118, 51.509)),
+			v => panic!("expected a point, found {v:?}"),
+		}
+		let line = Value::from(Geometry::from(vec![(0.0, 0.0), (1.0, 2.0)]));
+		match line.as_geometry() {
+			Some(Geometry::Line(l)) => {
+				let coords: Vec<(f64, f64)> = l.points().map(|p| p.x_y()).collect();
+				assert_eq!(coords, vec![(0.0, 0.0), (1.0, 2.0)]);
+			}
+			v => panic!("expected a line, found {v:?}"),
+		}
+		assert!(Value::from("point").as_geometry().is_none());
+	}
 }
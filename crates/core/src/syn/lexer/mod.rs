@@ -281,3 +281,30 @@ impl Iterator for Lexer<'_> {
 		Some(token)
 	}
 }
+
+/// Lexes the whole source, returning every token along with every error encountered.
+///
+/// Unlike the parser this does not stop at the first invalid token. Invalid tokens are kept in
+/// the returned tokens with [`TokenKind::Invalid`] so that their span is preserved, and the
+/// error for each is collected in order. Whitespace tokens are kept as well, so whether two tokens
+/// are joined can be determined from the tokens between them.
+///
+/// As mentioned on [`Lexer`] some syntax can only be lexed correctly with knowledge of previous
+/// tokens, so this is mostly intended for tooling like syntax highlighting.
+///
+/// # Panic
+/// This function will panic if the source is longer then u32::MAX.
+pub fn tokenize_all(source: &str) -> (Vec<Token>, Vec<SyntaxError>) {
+	let mut lexer = Lexer::new(source.as_bytes());
+	let mut tokens = Vec::new();
+	let mut errors = Vec::new();
+	while let Some(token) = lexer.next() {
+		if let TokenKind::Invalid = token.kind {
+			if let Some(error) = lexer.error.take() {
+				errors.push(error);
+			}
+		}
+		tokens.push(token);
+	}
+	(tokens, errors)
+}
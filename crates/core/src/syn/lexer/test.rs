@@ -129,3 +129,27 @@ fn ident_angle_with_escape_char() {
 		]
 	}
 }
+
+#[test]
+fn tokenize_all_collects_errors() {
+	let source = "a & b ^ c";
+	let (tokens, errors) = crate::syn::lexer::tokenize_all(source);
+	let kinds: Vec<_> = tokens.iter().map(|t| t.kind).collect();
+	assert_eq!(
+		kinds,
+		[
+			TokenKind::Identifier,
+			t!(" "),
+			TokenKind::Invalid,
+			t!(" "),
+			TokenKind::Identifier,
+			t!(" "),
+			TokenKind::Invalid,
+			t!(" "),
+			TokenKind::Identifier,
+		]
+	);
+	assert_eq!(errors.len(), 2);
+	assert_eq!(&source[tokens[2].span.offset as usize..][..tokens[2].span.len as usize], "&");
+	assert_eq!(&source[tokens[6].span.offset as usize..][..tokens[6].span.len as usize], "^");
+}
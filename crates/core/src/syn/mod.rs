@@ -1,9 +1,9 @@
 //! Module containing the implementation of the surrealql tokens, lexer, and parser.
 
 use crate::{
-	cnf::{MAX_OBJECT_PARSING_DEPTH, MAX_QUERY_PARSING_DEPTH},
+	cnf::{MAX_EXPRESSION_PARSING_DEPTH, MAX_OBJECT_PARSING_DEPTH, MAX_QUERY_PARSING_DEPTH},
 	err::Error,
-	sql::{Block, Datetime, Duration, Idiom, Query, Range, Subquery, Thing, Value},
+	sql::{Block, Datetime, Duration, Idiom, Kind, Query, Range, Subquery, Thing, Value},
 };
 
 pub mod error;
@@ -51,7 +51,8 @@ pub fn parse(input: &str) -> Result<Query, Error> {
 
 	let mut parser = Parser::new(input.as_bytes())
 		.with_object_recursion_limit(*MAX_OBJECT_PARSING_DEPTH as usize)
-		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize);
+		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize)
+		.with_expression_recursion_limit(*MAX_EXPRESSION_PARSING_DEPTH as usize);
 	let mut stack = Stack::new();
 	stack
 		.enter(|stk| parser.parse_query(stk))
@@ -71,7 +72,8 @@ pub fn value(input: &str) -> Result<Value, Error> {
 
 	let mut parser = Parser::new(input.as_bytes())
 		.with_object_recursion_limit(*MAX_OBJECT_PARSING_DEPTH as usize)
-		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize);
+		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize)
+		.with_expression_recursion_limit(*MAX_EXPRESSION_PARSING_DEPTH as usize);
 	let mut stack = Stack::new();
 	stack
 		.enter(|stk| parser.parse_value_field(stk))
@@ -81,6 +83,62 @@ pub fn value(input: &str) -> Result<Value, Error> {
 		.map_err(Error::InvalidQuery)
 }
 
+/// Limits on the size and shape of a value parsed with [`value_with_limits`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseLimits {
+	/// The maximum number of values, including the value itself and every value within it, such
+	/// as array elements, object values, function arguments, and the operands of expressions.
+	pub max_nodes: usize,
+	/// The maximum depth to which arrays and objects may be nested.
+	pub max_depth: usize,
+	/// The maximum length in bytes of any string, identifier, object key, record id, regex, or
+	/// script body.
+	pub max_string_length: usize,
+}
+
+impl Default for ParseLimits {
+	fn default() -> Self {
+		Self {
+			max_nodes: 10_000,
+			max_depth: *MAX_OBJECT_PARSING_DEPTH as usize,
+			max_string_length: 1024 * 1024,
+		}
+	}
+}
+
+/// Parses a SurrealQL [`Value`], rejecting values which exceed the given [`ParseLimits`].
+///
+/// This is intended for values received from untrusted clients. The limits are enforced while
+/// parsing, so parsing stops as soon as one of them is exceeded. Input which exceeds the
+/// recursion limits of the parser itself is rejected in the same way as by [`value`]. Any
+/// other exceeded limit results in [`Error::ParseLimitExceeded`].
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn value_with_limits(input: &str, limits: ParseLimits) -> Result<Value, Error> {
+	trace!(target: TARGET, "Parsing SurrealQL value with limits");
+
+	if input.len() > u32::MAX as usize {
+		return Err(Error::QueryTooLarge);
+	}
+
+	let mut parser = Parser::new(input.as_bytes())
+		.with_object_recursion_limit(*MAX_OBJECT_PARSING_DEPTH as usize)
+		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize)
+		.with_expression_recursion_limit(*MAX_EXPRESSION_PARSING_DEPTH as usize)
+		.with_value_limits(limits);
+	let mut stack = Stack::new();
+	stack
+		.enter(|stk| parser.parse_value_field(stk))
+		.finish()
+		.and_then(|e| parser.assert_finished().map(|_| e))
+		.map_err(|e| match parser.exceeded_value_limit() {
+			Some((limit, max)) => Error::ParseLimitExceeded {
+				limit,
+				max,
+			},
+			None => Error::InvalidQuery(e.render_on(input)),
+		})
+}
+
 /// Parses JSON into an inert SurrealQL [`Value`]
 #[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
 pub fn json(input: &str) -> Result<Value, Error> {
@@ -92,7 +150,8 @@ pub fn json(input: &str) -> Result<Value, Error> {
 
 	let mut parser = Parser::new(input.as_bytes())
 		.with_object_recursion_limit(*MAX_OBJECT_PARSING_DEPTH as usize)
-		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize);
+		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize)
+		.with_expression_recursion_limit(*MAX_EXPRESSION_PARSING_DEPTH as usize);
 	let mut stack = Stack::new();
 	stack
 		.enter(|stk| parser.parse_json(stk))
@@ -113,7 +172,8 @@ pub fn subquery(input: &str) -> Result<Subquery, Error> {
 
 	let mut parser = Parser::new(input.as_bytes())
 		.with_object_recursion_limit(*MAX_OBJECT_PARSING_DEPTH as usize)
-		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize);
+		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize)
+		.with_expression_recursion_limit(*MAX_EXPRESSION_PARSING_DEPTH as usize);
 	let mut stack = Stack::new();
 	stack
 		.enter(|stk| parser.parse_full_subquery(stk))
@@ -134,7 +194,8 @@ pub fn idiom(input: &str) -> Result<Idiom, Error> {
 
 	let mut parser = Parser::new(input.as_bytes())
 		.with_object_recursion_limit(*MAX_OBJECT_PARSING_DEPTH as usize)
-		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize);
+		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize)
+		.with_expression_recursion_limit(*MAX_EXPRESSION_PARSING_DEPTH as usize);
 	parser.table_as_field = true;
 	let mut stack = Stack::new();
 	stack
@@ -145,6 +206,28 @@ pub fn idiom(input: &str) -> Result<Idiom, Error> {
 		.map_err(Error::InvalidQuery)
 }
 
+/// Parses a SurrealQL [`Kind`], such as `option<array<record<user>>>`
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn kind(input: &str) -> Result<Kind, Error> {
+	trace!(target: TARGET, "Parsing SurrealQL kind");
+
+	if input.len() > u32::MAX as usize {
+		return Err(Error::QueryTooLarge);
+	}
+
+	let mut parser = Parser::new(input.as_bytes())
+		.with_object_recursion_limit(*MAX_OBJECT_PARSING_DEPTH as usize)
+		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize)
+		.with_expression_recursion_limit(*MAX_EXPRESSION_PARSING_DEPTH as usize);
+	let mut stack = Stack::new();
+	stack
+		.enter(|stk| parser.parse_inner_kind(stk))
+		.finish()
+		.and_then(|e| parser.assert_finished().map(|_| e))
+		.map_err(|e| e.render_on(input))
+		.map_err(Error::InvalidQuery)
+}
+
 /// Parse a datetime without enclosing delimiters from a string.
 #[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
 pub fn datetime(input: &str) -> Result<Datetime, Error> {
@@ -209,7 +292,8 @@ pub fn thing(input: &str) -> Result<Thing, Error> {
 
 	let mut parser = Parser::new(input.as_bytes())
 		.with_object_recursion_limit(*MAX_OBJECT_PARSING_DEPTH as usize)
-		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize);
+		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize)
+		.with_expression_recursion_limit(*MAX_EXPRESSION_PARSING_DEPTH as usize);
 	let mut stack = Stack::new();
 	stack
 		.enter(|stk| parser.parse_thing(stk))
@@ -230,7 +314,8 @@ pub fn thing_with_range(input: &str) -> Result<Thing, Error> {
 
 	let mut parser = Parser::new(input.as_bytes())
 		.with_object_recursion_limit(*MAX_OBJECT_PARSING_DEPTH as usize)
-		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize);
+		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize)
+		.with_expression_recursion_limit(*MAX_EXPRESSION_PARSING_DEPTH as usize);
 	let mut stack = Stack::new();
 	stack
 		.enter(|stk| parser.parse_thing_with_range(stk))
@@ -251,7 +336,8 @@ pub fn block(input: &str) -> Result<Block, Error> {
 
 	let mut parser = Parser::new(input.as_bytes())
 		.with_object_recursion_limit(*MAX_OBJECT_PARSING_DEPTH as usize)
-		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize);
+		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize)
+		.with_expression_recursion_limit(*MAX_EXPRESSION_PARSING_DEPTH as usize);
 	let mut stack = Stack::new();
 	let token = parser.peek();
 	match token.kind {
@@ -283,7 +369,8 @@ pub fn value_legacy_strand(input: &str) -> Result<Value, Error> {
 
 	let mut parser = Parser::new(input.as_bytes())
 		.with_object_recursion_limit(*MAX_OBJECT_PARSING_DEPTH as usize)
-		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize);
+		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize)
+		.with_expression_recursion_limit(*MAX_EXPRESSION_PARSING_DEPTH as usize);
 	let mut stack = Stack::new();
 	parser.allow_legacy_strand(true);
 	stack
@@ -305,7 +392,8 @@ pub fn json_legacy_strand(input: &str) -> Result<Value, Error> {
 
 	let mut parser = Parser::new(input.as_bytes())
 		.with_object_recursion_limit(*MAX_OBJECT_PARSING_DEPTH as usize)
-		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize);
+		.with_query_recursion_limit(*MAX_QUERY_PARSING_DEPTH as usize)
+		.with_expression_recursion_limit(*MAX_EXPRESSION_PARSING_DEPTH as usize);
 	let mut stack = Stack::new();
 	parser.allow_legacy_strand(true);
 	stack
@@ -23,6 +23,7 @@ impl TokenValue for Ident {
 			TokenKind::Identifier => {
 				parser.pop_peek();
 				let str = parser.lexer.string.take().unwrap();
+				parser.check_string_length(&str)?;
 				Ok(Ident(str))
 			}
 			x if Parser::kind_is_keyword_like(x) => {
@@ -67,6 +68,7 @@ impl TokenValue for Param {
 			TokenKind::Parameter => {
 				parser.pop_peek();
 				let param = parser.lexer.string.take().unwrap();
+				parser.check_string_length(&param)?;
 				Ok(Param(Ident(param)))
 			}
 			_ => unexpected!(parser, peek, "a parameter"),
@@ -107,15 +109,17 @@ impl TokenValue for Datetime {
 impl TokenValue for Strand {
 	fn from_token(parser: &mut Parser<'_>) -> ParseResult<Self> {
 		let token = parser.peek();
-		match token.kind {
-			TokenKind::Glued(token::Glued::Strand) => Ok(pop_glued!(parser, Strand)),
+		let strand = match token.kind {
+			TokenKind::Glued(token::Glued::Strand) => pop_glued!(parser, Strand),
 			t!("\"") | t!("'") => {
 				parser.pop_peek();
 				let v = parser.lexer.lex_compound(token, compound::strand)?.value;
-				Ok(Strand(v))
+				Strand(v)
 			}
 			_ => unexpected!(parser, token, "a strand"),
-		}
+		};
+		parser.check_string_length(&strand.0)?;
+		Ok(strand)
 	}
 }
 
@@ -141,6 +145,7 @@ impl TokenValue for Regex {
 			t!("/") => {
 				parser.pop_peek();
 				let v = parser.lexer.lex_compound(peek, compound::regex)?.value;
+				parser.check_string_length(v.as_str())?;
 				Ok(Regex(v))
 			}
 			_ => unexpected!(parser, peek, "a regex"),
@@ -156,7 +161,7 @@ impl Parser<'_> {
 
 	pub(crate) fn parse_flexible_ident(&mut self) -> ParseResult<Ident> {
 		let token = self.next();
-		match token.kind {
+		let ident = match token.kind {
 			TokenKind::Digits => {
 				let peek = self.peek_whitespace();
 				let span = match peek.kind {
@@ -170,19 +175,19 @@ impl Parser<'_> {
 					}
 					_ => token.span,
 				};
-				Ok(Ident(self.lexer.span_str(span).to_owned()))
+				Ident(self.lexer.span_str(span).to_owned())
 			}
 			TokenKind::Identifier => {
 				let str = self.lexer.string.take().unwrap();
-				Ok(Ident(str))
-			}
-			x if Self::kind_is_keyword_like(x) => {
-				Ok(Ident(self.lexer.span_str(token.span).to_owned()))
+				Ident(str)
 			}
+			x if Self::kind_is_keyword_like(x) => Ident(self.lexer.span_str(token.span).to_owned()),
 			_ => {
 				unexpected!(self, token, "an identifier");
 			}
-		}
+		};
+		self.check_string_length(&ident.0)?;
+		Ok(ident)
 	}
 }
 
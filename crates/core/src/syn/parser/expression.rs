@@ -10,7 +10,7 @@ use crate::sql::{value::TryNeg, Cast, Expression, Number, Operator, Value};
 use crate::syn::error::bail;
 use crate::syn::token::{self, Token};
 use crate::syn::{
-	parser::{mac::expected, ParseResult, Parser},
+	parser::{enter_expression_recursion, mac::expected, ParseResult, Parser},
 	token::{t, TokenKind},
 };
 
@@ -292,37 +292,11 @@ impl Parser<'_> {
 
 	fn expression_is_relation(value: &Value) -> bool {
 		if let Value::Expression(x) = value {
-			return Self::operator_is_relation(x.operator());
+			return x.operator().is_relation();
 		}
 		false
 	}
 
-	fn operator_is_relation(operator: &Operator) -> bool {
-		matches!(
-			operator,
-			Operator::Equal
-				| Operator::NotEqual
-				| Operator::AllEqual
-				| Operator::AnyEqual
-				| Operator::NotLike
-				| Operator::AllLike
-				| Operator::AnyLike
-				| Operator::Like
-				| Operator::Contain
-				| Operator::NotContain
-				| Operator::NotInside
-				| Operator::ContainAll
-				| Operator::ContainNone
-				| Operator::AllInside
-				| Operator::AnyInside
-				| Operator::NoneInside
-				| Operator::Outside
-				| Operator::Intersects
-				| Operator::Inside
-				| Operator::Knn(_, _)
-		)
-	}
-
 	async fn parse_infix_op(
 		&mut self,
 		ctx: &mut Stk,
@@ -407,7 +381,7 @@ impl Parser<'_> {
 		let before = self.recent_span();
 		let rhs = ctx.run(|ctx| self.pratt_parse_expr(ctx, min_bp)).await?;
 
-		if Self::operator_is_relation(&operator) && Self::expression_is_relation(&lhs) {
+		if operator.is_relation() && Self::expression_is_relation(&lhs) {
 			let span = before.covers(self.recent_span());
 			// 1 >= 2 >= 3 has no defined associativity and is often a mistake.
 			bail!("Chaining relational operators have no defined associativity.",
@@ -518,32 +492,34 @@ impl Parser<'_> {
 		ctx: &mut Stk,
 		min_bp: BindingPower,
 	) -> ParseResult<Value> {
-		let peek = self.peek();
-		let mut lhs = if let Some(bp) = self.prefix_binding_power(peek.kind) {
-			self.parse_prefix_op(ctx, bp).await?
-		} else {
-			self.parse_idiom_expression(ctx).await?
-		};
+		enter_expression_recursion!(this = self => {
+			let peek = this.peek();
+			let mut lhs = if let Some(bp) = this.prefix_binding_power(peek.kind) {
+				this.parse_prefix_op(ctx, bp).await?
+			} else {
+				this.parse_idiom_expression(ctx).await?
+			};
 
-		loop {
-			let token = self.peek();
-			let Some(bp) = self.infix_binding_power(token.kind) else {
-				// explain that assignment operators can't be used in normal expressions.
-				if let t!("+=") | t!("*=") | t!("-=") | t!("+?=") = token.kind {
-					unexpected!(self,token,"an operator",
-						=> "assignment operators are only allowed in SET and DUPLICATE KEY UPDATE clauses")
+			loop {
+				let token = this.peek();
+				let Some(bp) = this.infix_binding_power(token.kind) else {
+					// explain that assignment operators can't be used in normal expressions.
+					if let t!("+=") | t!("*=") | t!("-=") | t!("+?=") = token.kind {
+						unexpected!(this,token,"an operator",
+							=> "assignment operators are only allowed in SET and DUPLICATE KEY UPDATE clauses")
+					}
+					break;
+				};
+
+				if bp <= min_bp {
+					break;
 				}
-				break;
-			};
 
-			if bp <= min_bp {
-				break;
+				lhs = this.parse_infix_op(ctx, bp, lhs).await?;
 			}
 
-			lhs = self.parse_infix_op(ctx, bp, lhs).await?;
-		}
-
-		Ok(lhs)
+			Ok(lhs)
+		})
 	}
 }
 
@@ -88,6 +88,7 @@ macro_rules! enter_object_recursion {
 			return Err($crate::syn::parser::SyntaxError::new("Exceeded query recursion depth limit")
 				.with_span($this.last_span(), $crate::syn::error::MessageKind::Error))
 		}
+		$this.check_value_depth()?;
 		struct Dropper<'a, 'b>(&'a mut $crate::syn::parser::Parser<'b>);
 		impl Drop for Dropper<'_, '_> {
 			fn drop(&mut self) {
@@ -151,6 +152,41 @@ macro_rules! enter_query_recursion {
 	}};
 }
 
+macro_rules! enter_expression_recursion {
+	($name:ident = $this:expr => { $($t:tt)* }) => {{
+		if $this.expression_recursion == 0 {
+			return Err($crate::syn::parser::SyntaxError::new("Exceeded expression recursion depth limit")
+				.with_span($this.last_span(), $crate::syn::error::MessageKind::Error))
+		}
+		struct Dropper<'a, 'b>(&'a mut $crate::syn::parser::Parser<'b>);
+		impl Drop for Dropper<'_, '_> {
+			fn drop(&mut self) {
+				self.0.expression_recursion += 1;
+			}
+		}
+		impl<'a> ::std::ops::Deref for Dropper<'_,'a>{
+			type Target = $crate::syn::parser::Parser<'a>;
+
+			fn deref(&self) -> &Self::Target{
+				self.0
+			}
+		}
+
+		impl<'a> ::std::ops::DerefMut for Dropper<'_,'a>{
+			fn deref_mut(&mut self) -> &mut Self::Target{
+				self.0
+			}
+		}
+
+		$this.expression_recursion -= 1;
+        #[allow(unused_mut)]
+		let mut $name = Dropper($this);
+		{
+			$($t)*
+		}
+	}};
+}
+
 // This macro is used to parse an option in the format `+option`.
 macro_rules! parse_option {
 	($parser: ident, $what: expr, $( $string: expr => $result: expr, )+ _ => $fallback: expr) => {
@@ -186,6 +222,7 @@ macro_rules! parse_option {
 	};
 }
 
+pub(crate) use enter_expression_recursion;
 pub(crate) use enter_object_recursion;
 pub(crate) use enter_query_recursion;
 pub(crate) use expected;
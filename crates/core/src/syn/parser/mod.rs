@@ -56,9 +56,10 @@ use self::token_buffer::TokenBuffer;
 use crate::{
 	sql::{self, Datetime, Duration, Strand, Uuid},
 	syn::{
-		error::{bail, SyntaxError},
+		error::{bail, MessageKind, SyntaxError},
 		lexer::{compound::NumberKind, Lexer},
 		token::{t, Span, Token, TokenKind},
+		ParseLimits,
 	},
 };
 use reblessive::Stk;
@@ -79,7 +80,9 @@ mod thing;
 mod token;
 mod token_buffer;
 
-pub(crate) use mac::{enter_object_recursion, enter_query_recursion, unexpected};
+pub(crate) use mac::{
+	enter_expression_recursion, enter_object_recursion, enter_query_recursion, unexpected,
+};
 
 #[cfg(test)]
 pub mod test;
@@ -117,6 +120,16 @@ pub enum GluedValue {
 	None,
 }
 
+/// The value limits a parser was configured with and how much of them has been used so far.
+struct ValueBudget {
+	limits: ParseLimits,
+	/// The object recursion limit when the value limits were set, from which the nesting depth
+	/// of arrays and objects is derived.
+	object_recursion: usize,
+	nodes: usize,
+	exceeded: Option<(&'static str, usize)>,
+}
+
 /// The SurrealQL parser.
 pub struct Parser<'a> {
 	lexer: Lexer<'a>,
@@ -128,6 +141,8 @@ pub struct Parser<'a> {
 	flexible_record_id: bool,
 	object_recursion: usize,
 	query_recursion: usize,
+	expression_recursion: usize,
+	value_budget: Option<ValueBudget>,
 }
 
 impl<'a> Parser<'a> {
@@ -143,6 +158,8 @@ impl<'a> Parser<'a> {
 			flexible_record_id: true,
 			object_recursion: 100,
 			query_recursion: 20,
+			expression_recursion: 1000,
+			value_budget: None,
 		}
 	}
 
@@ -161,6 +178,82 @@ impl<'a> Parser<'a> {
 		self
 	}
 
+	/// Disallow expressions from being nested deeper than the given limit.
+	/// Every nested expression counts, including the operands of operators and casts,
+	/// as well as expressions within parentheses, objects and arrays.
+	pub fn with_expression_recursion_limit(mut self, limit: usize) -> Self {
+		self.expression_recursion = limit;
+		self
+	}
+
+	/// Limit the number of values, the nesting depth of arrays and objects, and the length of
+	/// strings which the parser will produce.
+	///
+	/// Parsing fails as soon as one of the limits is exceeded, after which
+	/// [`Parser::exceeded_value_limit`] returns the limit in question. Should be called after
+	/// [`Parser::with_object_recursion_limit`].
+	pub fn with_value_limits(mut self, limits: ParseLimits) -> Self {
+		self.value_budget = Some(ValueBudget {
+			limits,
+			object_recursion: self.object_recursion,
+			nodes: 0,
+			exceeded: None,
+		});
+		self
+	}
+
+	/// Returns the name and the maximum of the value limit which caused parsing to fail, if any.
+	pub fn exceeded_value_limit(&self) -> Option<(&'static str, usize)> {
+		self.value_budget.as_ref().and_then(|x| x.exceeded)
+	}
+
+	fn exceed_value_limit(&mut self, limit: &'static str, max: usize) -> SyntaxError {
+		if let Some(budget) = self.value_budget.as_mut() {
+			budget.exceeded.get_or_insert((limit, max));
+		}
+		SyntaxError::new(format_args!("Exceeded the maximum {limit} of {max}"))
+			.with_span(self.last_span(), MessageKind::Error)
+	}
+
+	/// Counts a value which is about to be parsed against the value limits.
+	pub(crate) fn count_value(&mut self) -> ParseResult<()> {
+		let Some(budget) = self.value_budget.as_mut() else {
+			return Ok(());
+		};
+		budget.nodes += 1;
+		if budget.nodes > budget.limits.max_nodes {
+			let max = budget.limits.max_nodes;
+			return Err(self.exceed_value_limit("number of values", max));
+		}
+		Ok(())
+	}
+
+	/// Checks the depth of an array or object which is about to be parsed against the value
+	/// limits.
+	pub(crate) fn check_value_depth(&mut self) -> ParseResult<()> {
+		let Some(budget) = self.value_budget.as_ref() else {
+			return Ok(());
+		};
+		let depth = budget.object_recursion.saturating_sub(self.object_recursion);
+		if depth >= budget.limits.max_depth {
+			let max = budget.limits.max_depth;
+			return Err(self.exceed_value_limit("nesting depth", max));
+		}
+		Ok(())
+	}
+
+	/// Checks the length of a parsed string, identifier or record id against the value limits.
+	pub(crate) fn check_string_length(&mut self, s: &str) -> ParseResult<()> {
+		let Some(budget) = self.value_budget.as_ref() else {
+			return Ok(());
+		};
+		if s.len() > budget.limits.max_string_length {
+			let max = budget.limits.max_string_length;
+			return Err(self.exceed_value_limit("string length", max));
+		}
+		Ok(())
+	}
+
 	/// Parse strand like the old parser where a strand which looks like a UUID, Record-Id, Or a
 	/// DateTime will be parsed as a date-time.
 	pub fn with_allow_legacy_strand(mut self, value: bool) -> Self {
@@ -200,6 +293,8 @@ impl<'a> Parser<'a> {
 			table_as_field: false,
 			object_recursion: self.object_recursion,
 			query_recursion: self.query_recursion,
+			expression_recursion: self.expression_recursion,
+			value_budget: self.value_budget,
 		}
 	}
 
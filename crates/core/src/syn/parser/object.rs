@@ -609,6 +609,7 @@ impl Parser<'_> {
 			TokenKind::Identifier => {
 				self.pop_peek();
 				let str = self.lexer.string.take().unwrap();
+				self.check_string_length(&str)?;
 				Ok(str)
 			}
 			t!("\"") | t!("'") | TokenKind::Glued(Glued::Strand) => {
@@ -618,8 +619,9 @@ impl Parser<'_> {
 			TokenKind::Digits => {
 				self.pop_peek();
 				let span = self.lexer.lex_compound(token, compound::number)?.span;
-				let str = self.lexer.span_str(span);
-				Ok(str.to_string())
+				let str = self.lexer.span_str(span).to_string();
+				self.check_string_length(&str)?;
+				Ok(str)
 			}
 			TokenKind::Glued(Glued::Number) => {
 				self.pop_peek();
@@ -23,6 +23,7 @@ impl Parser<'_> {
 	///
 	/// What's are values which are more restricted in what expressions they can contain.
 	pub(super) async fn parse_what_primary(&mut self, ctx: &mut Stk) -> ParseResult<Value> {
+		self.count_value()?;
 		let token = self.peek();
 		match token.kind {
 			t!("r\"") => {
@@ -174,6 +175,7 @@ impl Parser<'_> {
 
 	/// Parse an expressions
 	pub(super) async fn parse_idiom_expression(&mut self, ctx: &mut Stk) -> ParseResult<Value> {
+		self.count_value()?;
 		let token = self.peek();
 		let value = match token.kind {
 			t!("@") => {
@@ -731,8 +733,9 @@ impl Parser<'_> {
 		// remove the starting `{` and ending `}`.
 		span.offset += 1;
 		span.len -= 2;
-		let body = self.lexer.span_str(span);
-		Ok(Function::Script(Script(body.to_string()), args))
+		let body = self.lexer.span_str(span).to_string();
+		self.check_string_length(&body)?;
+		Ok(Function::Script(Script(body), args))
 	}
 }
 
@@ -219,3 +219,49 @@ fn query_depth_if() {
 		.finish()
 		.expect_err("recursion limit of 5 didn't trigger on 6 deep query");
 }
+
+#[test]
+fn expression_depth_prefix() {
+	let mut stack = Stack::new();
+
+	let source = "RETURN !!!!true";
+	let mut parser = Parser::new(source.as_bytes()).with_expression_recursion_limit(5);
+	stack
+		.enter(|stk| parser.parse_query(stk))
+		.finish()
+		.expect("recursion limit of 5 couldn't parse 5 deep expression");
+
+	let source = "RETURN !!!!!true";
+	let mut parser = Parser::new(source.as_bytes()).with_expression_recursion_limit(5);
+	stack
+		.enter(|stk| parser.parse_query(stk))
+		.finish()
+		.expect_err("recursion limit of 5 didn't trigger on 6 deep expression");
+}
+
+#[test]
+fn expression_depth_cast() {
+	let mut stack = Stack::new();
+
+	let source = "RETURN <int><int><int><int>1";
+	let mut parser = Parser::new(source.as_bytes()).with_expression_recursion_limit(5);
+	stack
+		.enter(|stk| parser.parse_query(stk))
+		.finish()
+		.expect("recursion limit of 5 couldn't parse 5 deep expression");
+
+	let source = "RETURN <int><int><int><int><int>1";
+	let mut parser = Parser::new(source.as_bytes()).with_expression_recursion_limit(5);
+	stack
+		.enter(|stk| parser.parse_query(stk))
+		.finish()
+		.expect_err("recursion limit of 5 didn't trigger on 6 deep expression");
+}
+
+#[test]
+fn expression_depth_default() {
+	let source = format!("RETURN {}true", "!".repeat(100_000));
+	let error = crate::syn::parse(&source).expect_err("default limit didn't trigger");
+	assert!(error.to_string().contains("Exceeded expression recursion depth limit"), "{error}");
+	crate::syn::parse(&format!("RETURN {}true", "!".repeat(100))).unwrap();
+}
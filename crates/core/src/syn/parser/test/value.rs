@@ -74,7 +74,8 @@ fn parse_large_depth_object() {
 	}
 	let mut parser = Parser::new(text.as_bytes())
 		.with_query_recursion_limit(100000)
-		.with_object_recursion_limit(100000);
+		.with_object_recursion_limit(100000)
+		.with_expression_recursion_limit(100000);
 	let mut stack = Stack::new();
 	let query = stack.enter(|stk| parser.parse_query(stk)).finish().unwrap();
 	let Query(Statements(stmts)) = query;
@@ -106,7 +107,8 @@ fn parse_large_depth_record_id() {
 	}
 	let mut parser = Parser::new(text.as_bytes())
 		.with_query_recursion_limit(100000)
-		.with_object_recursion_limit(100000);
+		.with_object_recursion_limit(100000)
+		.with_expression_recursion_limit(100000);
 	let mut stack = Stack::new();
 	let query = stack.enter(|stk| parser.parse_query(stk)).finish().unwrap();
 	let Query(Statements(stmts)) = query;
@@ -227,7 +227,9 @@ impl Parser<'_> {
 				if let Ok(number) = digits_str.parse() {
 					Ok(Id::Number(number))
 				} else {
-					Ok(Id::String(digits_str.to_owned()))
+					let digits_str = digits_str.to_owned();
+					self.check_string_length(&digits_str)?;
+					Ok(Id::String(digits_str))
 				}
 			}
 			t!("-") => {
@@ -240,11 +242,15 @@ impl Parser<'_> {
 						Ordering::Less => Ok(Id::Number(-(number.value as i64))),
 						Ordering::Equal => Ok(Id::Number(i64::MIN)),
 						Ordering::Greater => {
-							Ok(Id::String(format!("-{}", self.lexer.span_str(number.span))))
+							let text = format!("-{}", self.lexer.span_str(number.span));
+							self.check_string_length(&text)?;
+							Ok(Id::String(text))
 						}
 					}
 				} else {
-					Ok(Id::String(format!("-{}", self.lexer.span_str(token.span))))
+					let text = format!("-{}", self.lexer.span_str(token.span));
+					self.check_string_length(&text)?;
+					Ok(Id::String(text))
 				}
 			}
 			TokenKind::Digits => {
@@ -262,7 +268,9 @@ impl Parser<'_> {
 				if let Ok(number) = digits_str.parse::<i64>() {
 					Ok(Id::Number(number))
 				} else {
-					Ok(Id::String(digits_str.to_owned()))
+					let digits_str = digits_str.to_owned();
+					self.check_string_length(&digits_str)?;
+					Ok(Id::String(digits_str))
 				}
 			}
 			TokenKind::Glued(Glued::Duration) if self.flexible_record_id => {
@@ -272,6 +280,7 @@ impl Parser<'_> {
 				}
 				// Should be valid utf-8 as it was already parsed by the lexer
 				let text = String::from_utf8(slice.to_vec()).unwrap();
+				self.check_string_length(&text)?;
 				Ok(Id::String(text))
 			}
 			TokenKind::Glued(_) => {
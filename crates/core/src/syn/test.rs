@@ -139,3 +139,186 @@ fn empty_subquery() {
 fn empty_json() {
 	super::json("").unwrap_err();
 }
+
+#[test]
+fn value_with_limits_within_budget() {
+	let limits = super::ParseLimits {
+		max_nodes: 7,
+		max_depth: 2,
+		max_string_length: 5,
+	};
+	super::value_with_limits("{ a: [1, 2], b: 'hello', c: r:['x'] }", limits).unwrap();
+}
+
+#[test]
+fn value_with_limits_nodes() {
+	let limits = super::ParseLimits {
+		max_nodes: 3,
+		..Default::default()
+	};
+	super::value_with_limits("[1, 2]", limits).unwrap();
+	let error = super::value_with_limits("[1, 2, 3]", limits).unwrap_err();
+	assert!(
+		matches!(
+			error,
+			Error::ParseLimitExceeded {
+				limit: "number of values",
+				max: 3
+			}
+		),
+		"{error}"
+	);
+}
+
+#[test]
+fn value_with_limits_depth() {
+	let limits = super::ParseLimits {
+		max_depth: 2,
+		..Default::default()
+	};
+	super::value_with_limits("[{ a: 1 }]", limits).unwrap();
+	let error = super::value_with_limits("[{ a: [1] }]", limits).unwrap_err();
+	assert!(
+		matches!(
+			error,
+			Error::ParseLimitExceeded {
+				limit: "nesting depth",
+				max: 2
+			}
+		),
+		"{error}"
+	);
+	let error = super::value_with_limits("r:[[[1]]]", limits).unwrap_err();
+	assert!(matches!(error, Error::ParseLimitExceeded { .. }), "{error}");
+}
+
+#[test]
+fn value_with_limits_string_length() {
+	let limits = super::ParseLimits {
+		max_string_length: 3,
+		..Default::default()
+	};
+	super::value_with_limits("{ abc: 'def' }", limits).unwrap();
+	for source in ["'abcd'", "{ abcd: 1 }", "r:abcd"] {
+		let error = super::value_with_limits(source, limits).unwrap_err();
+		assert!(
+			matches!(
+				error,
+				Error::ParseLimitExceeded {
+					limit: "string length",
+					max: 3
+				}
+			),
+			"{source}: {error}"
+		);
+	}
+}
+
+/// Asserts that `source` parses into the expected wrapper, and that the limit is still enforced
+/// for the values within it.
+fn assert_wrapped_limit(
+	source: &str,
+	is_wrapper: fn(&crate::sql::Value) -> bool,
+	limits: super::ParseLimits,
+	limit: &str,
+) {
+	assert!(is_wrapper(&super::value(source).unwrap()), "{source}");
+	let error = super::value_with_limits(source, limits).unwrap_err();
+	assert!(
+		matches!(error, Error::ParseLimitExceeded { limit: l, .. } if l == limit),
+		"{source}: {error}"
+	);
+}
+
+#[test]
+fn value_with_limits_wrapped_values() {
+	use crate::sql::Value;
+
+	let nodes = super::ParseLimits {
+		max_nodes: 5,
+		..Default::default()
+	};
+	let depth = super::ParseLimits {
+		max_depth: 2,
+		..Default::default()
+	};
+	let strings = super::ParseLimits {
+		max_string_length: 3,
+		..Default::default()
+	};
+	assert_wrapped_limit(
+		"<array> [[[1]]]",
+		|v| matches!(v, Value::Cast(_)),
+		depth,
+		"nesting depth",
+	);
+	assert_wrapped_limit(
+		"[1] + [[[1]]]",
+		|v| matches!(v, Value::Expression(_)),
+		depth,
+		"nesting depth",
+	);
+	assert_wrapped_limit(
+		"1 + 2 + 3 + 4 + 5 + 6",
+		|v| matches!(v, Value::Expression(_)),
+		nodes,
+		"number of values",
+	);
+	assert_wrapped_limit(
+		"(RETURN [[[1]]])",
+		|v| matches!(v, Value::Subquery(_)),
+		depth,
+		"nesting depth",
+	);
+	assert_wrapped_limit(
+		"1..(RETURN 'abcd')",
+		|v| matches!(v, Value::Range(_)),
+		strings,
+		"string length",
+	);
+	assert_wrapped_limit("r:[[[1]]]..", |v| matches!(v, Value::Thing(_)), depth, "nesting depth");
+	assert_wrapped_limit(
+		"array::len([[[1]]])",
+		|v| matches!(v, Value::Function(_)),
+		depth,
+		"nesting depth",
+	);
+	assert_wrapped_limit(
+		"function() { return 1; }",
+		|v| matches!(v, Value::Function(_)),
+		strings,
+		"string length",
+	);
+	assert_wrapped_limit(
+		"{ type: 'MultiPoint', coordinates: [[1, 2], [3, 4]] }",
+		|v| matches!(v, Value::Geometry(_)),
+		nodes,
+		"number of values",
+	);
+	assert_wrapped_limit(
+		"|$a| [[[1]]]",
+		|v| matches!(v, Value::Closure(_)),
+		depth,
+		"nesting depth",
+	);
+}
+
+#[test]
+fn value_with_limits_stops_parsing() {
+	let limits = super::ParseLimits {
+		max_nodes: 3,
+		..Default::default()
+	};
+	// The limit is reached before the parser gets to the syntax error at the end
+	let error = super::value_with_limits("[1, 2, 3, )", limits).unwrap_err();
+	assert!(
+		matches!(
+			error,
+			Error::ParseLimitExceeded {
+				limit: "number of values",
+				max: 3
+			}
+		),
+		"{error}"
+	);
+}
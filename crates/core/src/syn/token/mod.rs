@@ -7,6 +7,7 @@ pub(crate) use keyword::keyword_t;
 pub use keyword::Keyword;
 mod mac;
 use crate::sql::{language::Language, Algorithm};
+use crate::syn::error::Location;
 pub(crate) use mac::t;
 
 /// A location in the source passed to the lexer.
@@ -62,6 +63,33 @@ impl Span {
 		let end = self.offset as usize + self.len as usize;
 		other.offset as usize == end
 	}
+
+	/// Returns the 1 indexed line and column of the start of this span within the given source.
+	///
+	/// Columns are counted in chars. A span starting past the end of the source points to the
+	/// last char of the source, like the locations of parse errors do.
+	pub fn line_col(&self, source: &str) -> (u32, u32) {
+		let start = Location::range_of_span(source, *self).start;
+		(start.line as u32, start.column as u32)
+	}
+
+	/// Returns the text within the given source which this span covers.
+	///
+	/// The span is clamped to the source and to the nearest char boundaries within it.
+	pub fn snippet<'a>(&self, source: &'a str) -> &'a str {
+		let start = floor_char_boundary(source, self.offset as usize);
+		let end = floor_char_boundary(source, self.offset as usize + self.len as usize);
+		&source[start..end.max(start)]
+	}
+}
+
+/// Returns the largest char boundary in the source which is not past the given offset.
+fn floor_char_boundary(source: &str, offset: usize) -> usize {
+	let mut offset = offset.min(source.len());
+	while !source.is_char_boundary(offset) {
+		offset -= 1;
+	}
+	offset
 }
 
 #[repr(u8)]
@@ -509,3 +537,46 @@ pub struct CompoundToken<T> {
 
 /// A compound token which lexes a javascript function body.
 pub struct JavaScript;
+
+#[cfg(test)]
+mod test {
+	use super::Span;
+
+	#[test]
+	fn line_col() {
+		let source = "SELECT *\nFROM 🦀crab\r\nWHERE ⟨ä⟩ = 1";
+		let span = |offset: usize, len: usize| Span {
+			offset: offset as u32,
+			len: len as u32,
+		};
+		assert_eq!(span(0, 6).line_col(source), (1, 1));
+		assert_eq!(span(7, 1).line_col(source), (1, 8));
+		let crab = source.find("crab").unwrap();
+		assert_eq!(span(crab, 4).line_col(source), (2, 7));
+		let eq = source.find('=').unwrap();
+		assert_eq!(span(eq, 1).line_col(source), (3, 11));
+		assert_eq!(span(source.len() + 10, 0).line_col(source), (3, 13));
+	}
+
+	#[test]
+	fn snippet() {
+		let source = "SELECT *\nFROM 🦀crab";
+		let crab = source.find('🦀').unwrap();
+		let span = Span {
+			offset: crab as u32,
+			len: 8,
+		};
+		assert_eq!(span.snippet(source), "🦀crab");
+		// A span ending within a multi-byte char stops before it.
+		let span = Span {
+			offset: 7,
+			len: (crab - 7 + 2) as u32,
+		};
+		assert_eq!(span.snippet(source), "*\nFROM ");
+		let span = Span {
+			offset: 100,
+			len: 5,
+		};
+		assert_eq!(span.snippet(source), "");
+	}
+}
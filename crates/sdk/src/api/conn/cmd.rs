@@ -1,5 +1,9 @@
 use super::MlExportConfig;
-use crate::{opt::Resource, value::Notification, Result};
+use crate::{
+	opt::{Resource, SessionOverride},
+	value::Notification,
+	Result,
+};
 use bincode::Options;
 use channel::Sender;
 use revision::Revisioned;
@@ -69,6 +73,15 @@ pub(crate) enum Command {
 		query: Query,
 		variables: CoreObject,
 	},
+	QueryWithSession {
+		query: Query,
+		variables: CoreObject,
+		session: SessionOverride,
+	},
+	RawQuery {
+		query: Query,
+		variables: CoreObject,
+	},
 	ExportFile {
 		path: PathBuf,
 		config: Option<DbExportConfig>,
@@ -293,6 +306,10 @@ impl Command {
 			Command::Query {
 				query,
 				variables,
+			}
+			| Command::RawQuery {
+				query,
+				variables,
 			} => {
 				let params: Vec<CoreValue> = vec![query.into(), variables.into()];
 				RouterRequest {
@@ -346,6 +363,9 @@ impl Command {
 			},
 			Command::SubscribeLive {
 				..
+			}
+			| Command::QueryWithSession {
+				..
 			} => return None,
 			Command::Kill {
 				uuid,
@@ -13,7 +13,13 @@ use serde::de::DeserializeOwned;
 use std::collections::HashSet;
 use std::sync::atomic::AtomicI64;
 use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
 use surrealdb_core::sql::{from_value as from_core_value, Value as CoreValue};
+use tokio::sync::Semaphore;
+use tokio::sync::SemaphorePermit;
+use uuid::Uuid;
 
 mod cmd;
 pub(crate) use cmd::Command;
@@ -40,6 +46,10 @@ pub struct Router {
 	pub(crate) sender: Sender<Route>,
 	pub(crate) last_id: AtomicI64,
 	pub(crate) features: HashSet<ExtraFeatures>,
+	/// The live queries started through this router which have not been killed yet
+	pub(crate) live_queries: Mutex<HashSet<Uuid>>,
+	/// Limits the number of requests waiting for a response, if set
+	pub(crate) inflight: Option<Semaphore>,
 }
 
 impl Router {
@@ -52,6 +62,7 @@ impl Router {
 		command: Command,
 	) -> BoxFuture<'_, Result<Receiver<Result<DbResponse>>>> {
 		Box::pin(async move {
+			self.track_live_query(&command);
 			let id = self.next_id();
 			let (sender, receiver) = channel::bounded(1);
 			let route = Route {
@@ -66,6 +77,49 @@ impl Router {
 		})
 	}
 
+	/// Waits until another request can be sent without exceeding the in-flight limit
+	///
+	/// The request counts as in flight until the returned permit is dropped, which should only
+	/// happen once its response has been received.
+	async fn acquire_inflight(&self) -> Option<SemaphorePermit<'_>> {
+		// The semaphore is never closed, so acquiring a permit can not fail
+		self.inflight.as_ref()?.acquire().await.ok()
+	}
+
+	/// Keeps track of the live queries this router subscribed to
+	fn track_live_query(&self, command: &Command) {
+		let mut live_queries = self.lock_live_queries();
+		match command {
+			Command::SubscribeLive {
+				uuid,
+				..
+			} => {
+				live_queries.insert(*uuid);
+			}
+			Command::Kill {
+				uuid,
+			} => {
+				live_queries.remove(uuid);
+			}
+			_ => {}
+		}
+	}
+
+	/// Stops tracking a live query whose subscription ended without being killed
+	pub(crate) fn forget_live_query(&self, id: Uuid) {
+		self.lock_live_queries().remove(&id);
+	}
+
+	/// Returns the IDs of the live queries which are still active
+	pub(crate) fn live_queries(&self) -> Vec<Uuid> {
+		self.lock_live_queries().iter().copied().collect()
+	}
+
+	fn lock_live_queries(&self) -> MutexGuard<'_, HashSet<Uuid>> {
+		// The set is always left in a consistent state, so a poisoned lock can be reused
+		self.live_queries.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+
 	/// Receive responses for all methods except `query`
 	pub(crate) fn recv(
 		&self,
@@ -100,6 +154,7 @@ impl Router {
 		R: DeserializeOwned,
 	{
 		Box::pin(async move {
+			let _permit = self.acquire_inflight().await;
 			let rx = self.send(command).await?;
 			let value = self.recv(rx).await?;
 			from_core_value(value).map_err(Into::into)
@@ -112,6 +167,7 @@ impl Router {
 		R: DeserializeOwned,
 	{
 		Box::pin(async move {
+			let _permit = self.acquire_inflight().await;
 			let rx = self.send(command).await?;
 			match self.recv(rx).await? {
 				CoreValue::None | CoreValue::Null => Ok(None),
@@ -126,6 +182,7 @@ impl Router {
 		R: DeserializeOwned,
 	{
 		Box::pin(async move {
+			let _permit = self.acquire_inflight().await;
 			let rx = self.send(command).await?;
 			let value = match self.recv(rx).await? {
 				CoreValue::None | CoreValue::Null => return Ok(Vec::new()),
@@ -139,6 +196,7 @@ impl Router {
 	/// Execute methods that return nothing
 	pub(crate) fn execute_unit(&self, command: Command) -> BoxFuture<'_, Result<()>> {
 		Box::pin(async move {
+			let _permit = self.acquire_inflight().await;
 			let rx = self.send(command).await?;
 			match self.recv(rx).await? {
 				CoreValue::None | CoreValue::Null => Ok(()),
@@ -155,6 +213,7 @@ impl Router {
 	/// Execute methods that return a raw value
 	pub(crate) fn execute_value(&self, command: Command) -> BoxFuture<'_, Result<Value>> {
 		Box::pin(async move {
+			let _permit = self.acquire_inflight().await;
 			let rx = self.send(command).await?;
 			Ok(Value::from_inner(self.recv(rx).await?))
 		})
@@ -163,6 +222,7 @@ impl Router {
 	/// Execute the `query` method
 	pub(crate) fn execute_query(&self, command: Command) -> BoxFuture<'_, Result<Response>> {
 		Box::pin(async move {
+			let _permit = self.acquire_inflight().await;
 			let rx = self.send(command).await?;
 			self.recv_query(rx).await
 		})
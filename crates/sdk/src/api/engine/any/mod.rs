@@ -136,6 +136,7 @@ mod wasm;
 use crate::api::err::Error;
 use crate::api::opt::Config;
 use crate::api::opt::Endpoint;
+use crate::api::opt::EndpointKind;
 use crate::api::Connect;
 use crate::api::Result;
 use crate::api::Surreal;
@@ -162,24 +163,54 @@ fn split_url(url: &str) -> (&str, &str) {
 	}
 }
 
+/// Splits an address without a scheme, such as `localhost:8000`, into its host and port
+///
+/// Returns `None` if the address has a scheme, or if the part before the colon is the name of
+/// a supported engine, like the `rocksdb` in `rocksdb:path/to/db`. Query options, such as the
+/// `?strict=true` in `localhost:8000?strict=true`, are not part of the port.
+fn split_host_port(address: &str) -> Option<(&str, &str)> {
+	if address.contains("://") {
+		return None;
+	}
+	let address = address.split_once('?').map_or(address, |(address, _)| address);
+	let (host, port) = address.rsplit_once(':')?;
+	match EndpointKind::from(host) {
+		EndpointKind::Unsupported(_) => Some((host, port)),
+		_ => None,
+	}
+}
+
 impl IntoEndpoint for &str {
 	fn into_endpoint(self) -> Result<Endpoint> {
 		let (url, path) = match self {
 			"memory" | "mem://" => (Url::parse("mem://").unwrap(), "memory".to_owned()),
-			url if url.starts_with("ws") | url.starts_with("http") | url.starts_with("tikv") => {
+			url if url.split_once("://").is_some_and(|(scheme, _)| {
+				matches!(scheme, "ws" | "wss" | "http" | "https" | "tikv")
+			}) =>
+			{
 				(Url::parse(url).map_err(|_| Error::InvalidUrl(self.to_owned()))?, String::new())
 			}
-
-			_ => {
-				let (scheme, path) = split_url(self);
-				let protocol = format!("{scheme}://");
-				(
-					Url::parse(&protocol).map_err(|_| Error::InvalidUrl(self.to_owned()))?,
-					path_to_string(&protocol, path),
-				)
-			}
+			_ => match split_host_port(self) {
+				// Addresses without a scheme connect using WebSockets
+				Some((host, port)) => {
+					if host.is_empty() || port.parse::<u16>().is_err() {
+						return Err(Error::InvalidUrl(self.to_owned()).into());
+					}
+					let url = Url::parse(&format!("ws://{self}"))
+						.map_err(|_| Error::InvalidUrl(self.to_owned()))?;
+					(url, String::new())
+				}
+				None => {
+					let (scheme, path) = split_url(self);
+					let protocol = format!("{scheme}://");
+					(
+						Url::parse(&protocol).map_err(|_| Error::InvalidUrl(self.to_owned()))?,
+						path_to_string(&protocol, path),
+					)
+				}
+			},
 		};
-		let mut endpoint = Endpoint::new(url);
+		let mut endpoint = Endpoint::new(url).with_query_options()?;
 		endpoint.path = path;
 		Ok(endpoint)
 	}
@@ -202,9 +233,7 @@ where
 	T: Into<String>,
 {
 	fn into_endpoint(self) -> Result<Endpoint> {
-		let mut endpoint = IntoEndpoint::into_endpoint(self.0.into())?;
-		endpoint.config = self.1;
-		Ok(endpoint)
+		IntoEndpoint::into_endpoint(self.0.into())?.with_config(self.1)
 	}
 }
 
@@ -244,6 +273,19 @@ impl Surreal<Any> {
 
 /// Connects to a local, remote or embedded database
 ///
+/// Remote endpoints accept the `strict`, `capacity` and `tls_insecure` options
+/// in the query string of the URL, for example `ws://localhost:8000?strict=true`.
+/// Unknown options result in an error. This is a breaking change for URLs which
+/// carry other query parameters, as those used to be ignored.
+///
+/// `tls_insecure` disables the verification of server certificates, so a warning
+/// is logged whenever it is used. Only use it for local development or testing.
+///
+/// When a [`Config`] is passed along with the address, the options from the URL are
+/// applied on top of it. Options which contradict the `Config` result in an error.
+///
+/// An address without a scheme, such as `localhost:8000`, connects using WebSockets.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -254,6 +296,9 @@ impl Surreal<Any> {
 /// // Connect to a local endpoint
 /// let db = connect("ws://localhost:8000").await?;
 ///
+/// // Connect to a local endpoint using WebSockets by default
+/// let db = connect("localhost:8000").await?;
+///
 /// // Connect to a remote endpoint
 /// let db = connect("wss://cloud.surrealdb.com").await?;
 ///
@@ -307,6 +352,53 @@ mod tests {
 	use crate::opt::capabilities::Capabilities;
 	use crate::Value;
 
+	#[test]
+	fn endpoint_without_scheme() {
+		for (address, host) in [
+			("localhost:8000", "localhost"),
+			("127.0.0.1:8000", "127.0.0.1"),
+			("[::1]:8000", "[::1]"),
+			// Hosts may start with the name of a scheme
+			("ws1.internal:8000", "ws1.internal"),
+			("http-proxy:8000", "http-proxy"),
+			("tikv-pd:8000", "tikv-pd"),
+			("localhost:8000?strict=true", "localhost"),
+		] {
+			let endpoint = address.into_endpoint().unwrap();
+			assert_eq!(endpoint.url.scheme(), "ws", "{address}");
+			assert_eq!(endpoint.url.host_str(), Some(host), "{address}");
+			assert_eq!(endpoint.url.port(), Some(8000), "{address}");
+		}
+		for address in ["localhost:port", "localhost:65536", ":8000"] {
+			let error = address.into_endpoint().unwrap_err();
+			assert!(
+				matches!(error, crate::Error::Api(Error::InvalidUrl(ref url)) if url == address),
+				"{address}: {error:?}"
+			);
+		}
+	}
+
+	#[test]
+	fn endpoint_with_scheme() {
+		for (address, scheme) in [
+			("ws://localhost:8000", "ws"),
+			("wss://example.com", "wss"),
+			("http://localhost:8000", "http"),
+		] {
+			let endpoint = address.into_endpoint().unwrap();
+			assert_eq!(endpoint.url.scheme(), scheme, "{address}");
+		}
+		for address in ["memory", "mem://"] {
+			let endpoint = address.into_endpoint().unwrap();
+			assert_eq!(endpoint.url.scheme(), "mem", "{address}");
+			assert_eq!(endpoint.path, "memory", "{address}");
+		}
+		// Engines may be given as a scheme followed by a single colon
+		let endpoint = "rocksdb:8000".into_endpoint().unwrap();
+		assert_eq!(endpoint.url.scheme(), "rocksdb");
+		assert_eq!(endpoint.path, "rocksdb://8000");
+	}
+
 	#[tokio::test]
 	async fn local_engine_without_auth() {
 		// Instantiate an in-memory instance without root credentials
@@ -26,6 +26,7 @@ use std::sync::atomic::AtomicI64;
 use std::sync::Arc;
 use std::sync::OnceLock;
 use tokio::sync::watch;
+use tokio::sync::Semaphore;
 #[cfg(feature = "protocol-ws")]
 use tokio_tungstenite::tungstenite::protocol::WebSocketConfig;
 #[cfg(feature = "protocol-ws")]
@@ -38,6 +39,7 @@ impl Connection for Any {
 	#[allow(unused_variables, unreachable_code, unused_mut)] // these are all used depending on feature
 	fn connect(address: Endpoint, capacity: usize) -> BoxFuture<'static, Result<Surreal<Self>>> {
 		Box::pin(async move {
+			let inflight = address.max_inflight.map(Semaphore::new);
 			let (route_tx, route_rx) = match capacity {
 				0 => channel::unbounded(),
 				capacity => channel::bounded(capacity),
@@ -52,6 +54,7 @@ impl Connection for Any {
 					{
 						features.insert(ExtraFeatures::Backup);
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::SessionOverride);
 						tokio::spawn(engine::local::native::run_router(address, conn_tx, route_rx));
 						conn_rx.recv().await??
 					}
@@ -67,6 +70,7 @@ impl Connection for Any {
 					{
 						features.insert(ExtraFeatures::Backup);
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::SessionOverride);
 						tokio::spawn(engine::local::native::run_router(address, conn_tx, route_rx));
 						conn_rx.recv().await??
 					}
@@ -82,6 +86,7 @@ impl Connection for Any {
 					{
 						features.insert(ExtraFeatures::Backup);
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::SessionOverride);
 						tokio::spawn(engine::local::native::run_router(address, conn_tx, route_rx));
 						conn_rx.recv().await??
 					}
@@ -98,6 +103,7 @@ impl Connection for Any {
 					{
 						features.insert(ExtraFeatures::Backup);
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::SessionOverride);
 						tokio::spawn(engine::local::native::run_router(address, conn_tx, route_rx));
 						conn_rx.recv().await??
 					}
@@ -113,6 +119,7 @@ impl Connection for Any {
 					{
 						features.insert(ExtraFeatures::Backup);
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::SessionOverride);
 						tokio::spawn(engine::local::native::run_router(address, conn_tx, route_rx));
 						conn_rx.recv().await??
 					}
@@ -129,6 +136,7 @@ impl Connection for Any {
 					{
 						features.insert(ExtraFeatures::Backup);
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::SessionOverride);
 						tokio::spawn(engine::local::native::run_router(address, conn_tx, route_rx));
 						conn_rx.recv().await??
 					}
@@ -144,7 +152,8 @@ impl Connection for Any {
 					#[cfg(feature = "protocol-http")]
 					{
 						features.insert(ExtraFeatures::Backup);
-						let headers = http::default_headers();
+						features.insert(ExtraFeatures::RawResponses);
+						let headers = http::default_headers(address.headers);
 						#[allow(unused_mut)]
 						let mut builder = ClientBuilder::new().default_headers(headers);
 						#[cfg(any(feature = "native-tls", feature = "rustls"))]
@@ -175,6 +184,7 @@ impl Connection for Any {
 					#[cfg(feature = "protocol-ws")]
 					{
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::RawResponses);
 						let mut endpoint = address;
 						endpoint.url = endpoint.url.join(engine::remote::ws::PATH)?;
 						#[cfg(any(feature = "native-tls", feature = "rustls"))]
@@ -218,6 +228,8 @@ impl Connection for Any {
 					features,
 					sender: route_tx,
 					last_id: AtomicI64::new(0),
+					live_queries: Default::default(),
+					inflight,
 				})),
 				Arc::new(watch::channel(Some(WaitFor::Connection))),
 			))
@@ -17,6 +17,7 @@ use std::sync::atomic::AtomicI64;
 use std::sync::Arc;
 use std::sync::OnceLock;
 use tokio::sync::watch;
+use tokio::sync::Semaphore;
 use wasm_bindgen_futures::spawn_local;
 
 impl crate::api::Connection for Any {}
@@ -25,6 +26,7 @@ impl Connection for Any {
 	#[allow(unused_variables, unreachable_code, unused_mut)] // these are all used depending on feature
 	fn connect(address: Endpoint, capacity: usize) -> BoxFuture<'static, Result<Surreal<Self>>> {
 		Box::pin(async move {
+			let inflight = address.max_inflight.map(Semaphore::new);
 			let (route_tx, route_rx) = match capacity {
 				0 => channel::unbounded(),
 				capacity => channel::bounded(capacity),
@@ -38,6 +40,7 @@ impl Connection for Any {
 					#[cfg(feature = "kv-fdb")]
 					{
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::SessionOverride);
 						spawn_local(engine::local::wasm::run_router(address, conn_tx, route_rx));
 						conn_rx.recv().await??;
 					}
@@ -52,6 +55,7 @@ impl Connection for Any {
 					#[cfg(feature = "kv-indxdb")]
 					{
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::SessionOverride);
 						spawn_local(engine::local::wasm::run_router(address, conn_tx, route_rx));
 						conn_rx.recv().await??;
 					}
@@ -66,6 +70,7 @@ impl Connection for Any {
 					#[cfg(feature = "kv-mem")]
 					{
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::SessionOverride);
 						spawn_local(engine::local::wasm::run_router(address, conn_tx, route_rx));
 						conn_rx.recv().await??;
 					}
@@ -80,6 +85,7 @@ impl Connection for Any {
 					#[cfg(feature = "kv-rocksdb")]
 					{
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::SessionOverride);
 						spawn_local(engine::local::wasm::run_router(address, conn_tx, route_rx));
 						conn_rx.recv().await??;
 					}
@@ -95,6 +101,7 @@ impl Connection for Any {
 					#[cfg(feature = "kv-surrealkv")]
 					{
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::SessionOverride);
 						spawn_local(engine::local::wasm::run_router(address, conn_tx, route_rx));
 						conn_rx.recv().await??;
 					}
@@ -110,6 +117,7 @@ impl Connection for Any {
 					#[cfg(feature = "kv-surrealcs")]
 					{
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::SessionOverride);
 						spawn_local(engine::local::wasm::run_router(address, conn_tx, route_rx));
 						conn_rx.recv().await??;
 					}
@@ -125,6 +133,7 @@ impl Connection for Any {
 					#[cfg(feature = "kv-tikv")]
 					{
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::SessionOverride);
 						spawn_local(engine::local::wasm::run_router(address, conn_tx, route_rx));
 						conn_rx.recv().await??;
 					}
@@ -138,6 +147,7 @@ impl Connection for Any {
 				EndpointKind::Http | EndpointKind::Https => {
 					#[cfg(feature = "protocol-http")]
 					{
+						features.insert(ExtraFeatures::RawResponses);
 						spawn_local(engine::remote::http::wasm::run_router(
 							address, conn_tx, route_rx,
 						));
@@ -154,6 +164,7 @@ impl Connection for Any {
 					#[cfg(feature = "protocol-ws")]
 					{
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::RawResponses);
 						let mut endpoint = address;
 						endpoint.url = endpoint.url.join(engine::remote::ws::PATH)?;
 						spawn_local(engine::remote::ws::wasm::run_router(
@@ -177,6 +188,8 @@ impl Connection for Any {
 					features,
 					sender: route_tx,
 					last_id: AtomicI64::new(0),
+					live_queries: Default::default(),
+					inflight,
 				})),
 				Arc::new(watch::channel(Some(WaitFor::Connection))),
 			))
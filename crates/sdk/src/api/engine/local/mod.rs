@@ -777,6 +777,31 @@ async fn router(
 			let response = process(response);
 			Ok(DbResponse::Query(response))
 		}
+		Command::RawQuery {
+			..
+		} => Err(crate::api::Error::RawResponsesNotSupported.into()),
+		Command::QueryWithSession {
+			query,
+			mut variables,
+			session: session_override,
+		} => {
+			// Run against a copy, so the session of the connection is left untouched
+			let mut session = session.clone();
+			if let Some(ns) = session_override.namespace {
+				session.ns = Some(ns);
+			}
+			if let Some(db) = session_override.database {
+				session.db = Some(db);
+			}
+			if let Some(token) = session_override.token {
+				iam::verify::token(kvs, &mut session, &token.0).await?;
+			}
+			let mut vars = vars.clone();
+			vars.append(&mut variables.0);
+			let response = kvs.process(query, &session, Some(vars)).await?;
+			let response = process(response);
+			Ok(DbResponse::Query(response))
+		}
 
 		#[cfg(target_arch = "wasm32")]
 		Command::ExportFile {
@@ -28,6 +28,7 @@ use std::sync::Arc;
 use std::sync::OnceLock;
 use std::task::Poll;
 use tokio::sync::watch;
+use tokio::sync::Semaphore;
 use tokio_util::sync::CancellationToken;
 use wasm_bindgen_futures::spawn_local;
 
@@ -36,6 +37,7 @@ impl crate::api::Connection for Db {}
 impl Connection for Db {
 	fn connect(address: Endpoint, capacity: usize) -> BoxFuture<'static, Result<Surreal<Self>>> {
 		Box::pin(async move {
+			let inflight = address.max_inflight.map(Semaphore::new);
 			let (route_tx, route_rx) = match capacity {
 				0 => channel::unbounded(),
 				capacity => channel::bounded(capacity),
@@ -49,12 +51,15 @@ impl Connection for Db {
 
 			let mut features = HashSet::new();
 			features.insert(ExtraFeatures::LiveQueries);
+			features.insert(ExtraFeatures::SessionOverride);
 
 			Ok(Surreal::new_from_router_waiter(
 				Arc::new(OnceLock::with_value(Router {
 					features,
 					sender: route_tx,
 					last_id: AtomicI64::new(0),
+					live_queries: Default::default(),
+					inflight,
 				})),
 				Arc::new(watch::channel(Some(WaitFor::Connection))),
 			))
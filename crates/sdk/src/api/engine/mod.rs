@@ -36,9 +36,8 @@ use crate::Value;
 use super::opt::Resource;
 use super::opt::Table;
 
-// used in http and all local engines.
-#[allow(dead_code)]
-fn resource_to_values(r: Resource) -> CoreValues {
+// used in http and all local engines, and by the `return_none` methods.
+pub(crate) fn resource_to_values(r: Resource) -> CoreValues {
 	let mut res = CoreValues::default();
 	match r {
 		Resource::Table(x) => {
@@ -96,8 +96,10 @@ impl Surreal<Client> {
 	}
 }
 
-pub(crate) fn default_headers() -> HeaderMap {
-	let mut headers = HeaderMap::new();
+/// Adds the headers SurrealDB requires to the headers supplied by the user
+///
+/// The required headers replace any supplied headers with the same name.
+pub(crate) fn default_headers(mut headers: HeaderMap) -> HeaderMap {
 	headers.insert(ACCEPT, HeaderValue::from_static("application/surrealdb"));
 	headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/surrealdb"));
 	headers
@@ -257,19 +259,37 @@ pub(crate) async fn health(request: RequestBuilder) -> Result<()> {
 	Ok(())
 }
 
-async fn send_request(
+async fn send_raw_request(
 	req: RouterRequest,
 	base_url: &Url,
 	client: &reqwest::Client,
 	headers: &HeaderMap,
 	auth: &Option<Auth>,
-) -> Result<DbResponse> {
+) -> Result<Vec<u8>> {
 	let url = base_url.join(RPC_PATH).unwrap();
 	let http_req =
 		client.post(url).headers(headers.clone()).auth(auth).body(serialize(&req, false)?);
-	let response = http_req.send().await?.error_for_status()?;
+	let mut response = http_req.send().await?;
+	// Error responses which carry a response frame are handed back like any other frame
+	let carries_frame = response
+		.headers()
+		.get(CONTENT_TYPE)
+		.is_some_and(|content_type| content_type == "application/surrealdb");
+	if !carries_frame {
+		response = response.error_for_status()?;
+	}
 	let bytes = response.bytes().await?;
+	Ok(bytes.to_vec())
+}
 
+async fn send_request(
+	req: RouterRequest,
+	base_url: &Url,
+	client: &reqwest::Client,
+	headers: &HeaderMap,
+	auth: &Option<Auth>,
+) -> Result<DbResponse> {
+	let bytes = send_raw_request(req, base_url, client, headers, auth).await?;
 	let response: Response = deserialize(&bytes, false)?;
 	DbResponse::from_server_result(response.result)
 }
@@ -306,6 +326,21 @@ async fn router(
 			.expect("query should be valid request");
 			send_request(req, base_url, client, headers, auth).await
 		}
+		Command::RawQuery {
+			query,
+			mut variables,
+		} => {
+			variables.extend(vars.clone());
+			let req = Command::RawQuery {
+				query,
+				variables,
+			}
+			.into_router_request(None)
+			.expect("query should be valid request");
+			// The response frame is handed back as is, without decoding it
+			let bytes = send_raw_request(req, base_url, client, headers, auth).await?;
+			Ok(DbResponse::Other(CoreValue::Bytes(bytes.into())))
+		}
 		Command::Use {
 			namespace,
 			database,
@@ -533,6 +568,9 @@ async fn router(
 		Command::SubscribeLive {
 			..
 		} => Err(Error::LiveQueriesNotSupported.into()),
+		Command::QueryWithSession {
+			..
+		} => Err(Error::SessionOverrideNotSupported.into()),
 		cmd => {
 			let needs_flatten = cmd.needs_flatten();
 			let req = cmd.into_router_request(None).unwrap();
@@ -20,6 +20,7 @@ use std::sync::atomic::AtomicI64;
 use std::sync::Arc;
 use std::sync::OnceLock;
 use tokio::sync::watch;
+use tokio::sync::Semaphore;
 use url::Url;
 
 impl crate::api::Connection for Client {}
@@ -27,7 +28,8 @@ impl crate::api::Connection for Client {}
 impl Connection for Client {
 	fn connect(address: Endpoint, capacity: usize) -> BoxFuture<'static, Result<Surreal<Self>>> {
 		Box::pin(async move {
-			let headers = super::default_headers();
+			let inflight = address.max_inflight.map(Semaphore::new);
+			let headers = super::default_headers(address.headers);
 
 			#[allow(unused_mut)]
 			let mut builder = ClientBuilder::new().default_headers(headers);
@@ -57,12 +59,15 @@ impl Connection for Client {
 
 			let mut features = HashSet::new();
 			features.insert(ExtraFeatures::Backup);
+			features.insert(ExtraFeatures::RawResponses);
 
 			Ok(Surreal::new_from_router_waiter(
 				Arc::new(OnceLock::with_value(Router {
 					features,
 					sender: route_tx,
 					last_id: AtomicI64::new(0),
+					live_queries: Default::default(),
+					inflight,
 				})),
 				Arc::new(watch::channel(Some(WaitFor::Connection))),
 			))
@@ -82,3 +87,112 @@ pub(crate) async fn run_router(base_url: Url, client: reqwest::Client, route_rx:
 		let _ = route.response.send(result).await;
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use reqwest::header::HeaderValue;
+	use wiremock::matchers::{header, method, path};
+	use wiremock::{Mock, MockServer, ResponseTemplate};
+
+	#[test_log::test(tokio::test)]
+	async fn custom_headers_are_sent() {
+		let server = MockServer::start().await;
+		Mock::given(method("GET"))
+			.and(path("/health"))
+			.and(header("x-api-key", "secret"))
+			.and(header("accept", "application/surrealdb"))
+			.respond_with(ResponseTemplate::new(200))
+			.expect(1)
+			.mount(&server)
+			.await;
+		let mut endpoint = Endpoint::new(server.uri().parse().unwrap());
+		endpoint.headers.insert("x-api-key", HeaderValue::from_static("secret"));
+		// Headers required by SurrealDB win over custom ones
+		endpoint.headers.insert("accept", HeaderValue::from_static("text/plain"));
+		Client::connect(endpoint, 0).await.unwrap();
+		server.verify().await;
+	}
+
+	#[test_log::test(tokio::test)]
+	async fn raw_query_matches_typed_query() {
+		use crate::api::conn::DbResponse;
+		use crate::api::engine::remote::{deserialize, Response};
+		use revision::revisioned;
+		use serde::Serialize;
+		use surrealdb_core::dbs::Status;
+		use surrealdb_core::sql::serde::serialize;
+		use surrealdb_core::sql::Value as CoreValue;
+		use surrealdb_core::syn;
+
+		// Mirrors the frame the server sends back for the `query` method
+		#[revisioned(revision = 1)]
+		#[derive(Serialize)]
+		struct QueryResult {
+			time: String,
+			status: Status,
+			result: CoreValue,
+		}
+
+		#[revisioned(revision = 1)]
+		#[derive(Serialize)]
+		enum Data {
+			Other(CoreValue),
+			Query(Vec<QueryResult>),
+		}
+
+		#[revisioned(revision = 1)]
+		#[derive(Serialize)]
+		struct Failure {
+			code: i64,
+			message: String,
+		}
+
+		#[revisioned(revision = 1)]
+		#[derive(Serialize)]
+		struct Frame {
+			id: Option<CoreValue>,
+			result: std::result::Result<Data, Failure>,
+		}
+
+		let records = syn::value("[{ id: person:john, name: 'John' }]").unwrap();
+		let frame = serialize(&Frame {
+			id: None,
+			result: Ok(Data::Query(vec![QueryResult {
+				time: "1ms".to_owned(),
+				status: Status::Ok,
+				result: records.clone(),
+			}])),
+		})
+		.unwrap();
+
+		let server = MockServer::start().await;
+		Mock::given(method("GET"))
+			.and(path("/health"))
+			.respond_with(ResponseTemplate::new(200))
+			.mount(&server)
+			.await;
+		Mock::given(method("POST"))
+			.and(path("/rpc"))
+			.respond_with(ResponseTemplate::new(200).set_body_bytes(frame.clone()))
+			.mount(&server)
+			.await;
+		let db = Client::connect(Endpoint::new(server.uri().parse().unwrap()), 0).await.unwrap();
+
+		let sql = "SELECT * FROM type::table($table)";
+		let raw = db.query_raw_bytes(sql, ("table", "person")).await.unwrap();
+		assert_eq!(raw, frame);
+
+		let mut typed = db.query(sql).bind(("table", "person")).await.unwrap();
+		let typed: crate::Value = typed.take(0).unwrap();
+		let response: Response = deserialize(&raw, false).unwrap();
+		let DbResponse::Query(mut decoded) =
+			DbResponse::from_server_result(response.result).unwrap()
+		else {
+			panic!("expected a query response");
+		};
+		let decoded: crate::Value = decoded.take(0).unwrap();
+		assert_eq!(decoded, typed);
+		assert_eq!(typed.into_inner(), records);
+	}
+}
@@ -4,6 +4,7 @@ use crate::api::conn::Route;
 use crate::api::conn::Router;
 use crate::api::method::BoxFuture;
 use crate::api::opt::Endpoint;
+use crate::api::ExtraFeatures;
 use crate::api::OnceLockExt;
 use crate::api::Result;
 use crate::api::Surreal;
@@ -17,6 +18,7 @@ use std::sync::atomic::AtomicI64;
 use std::sync::Arc;
 use std::sync::OnceLock;
 use tokio::sync::watch;
+use tokio::sync::Semaphore;
 use url::Url;
 use wasm_bindgen_futures::spawn_local;
 
@@ -25,6 +27,7 @@ impl crate::api::Connection for Client {}
 impl Connection for Client {
 	fn connect(address: Endpoint, capacity: usize) -> BoxFuture<'static, Result<Surreal<Self>>> {
 		Box::pin(async move {
+			let inflight = address.max_inflight.map(Semaphore::new);
 			let (route_tx, route_rx) = match capacity {
 				0 => channel::unbounded(),
 				capacity => channel::bounded(capacity),
@@ -36,11 +39,16 @@ impl Connection for Client {
 
 			conn_rx.recv().await??;
 
+			let mut features = HashSet::new();
+			features.insert(ExtraFeatures::RawResponses);
+
 			Ok(Surreal::new_from_router_waiter(
 				Arc::new(OnceLock::with_value(Router {
-					features: HashSet::new(),
+					features,
 					sender: route_tx,
 					last_id: AtomicI64::new(0),
+					live_queries: Default::default(),
+					inflight,
 				})),
 				Arc::new(watch::channel(Some(WaitFor::Connection))),
 			))
@@ -48,8 +56,8 @@ impl Connection for Client {
 	}
 }
 
-async fn client(base_url: &Url) -> Result<reqwest::Client> {
-	let headers = super::default_headers();
+async fn client(base_url: &Url, headers: HeaderMap) -> Result<reqwest::Client> {
+	let headers = super::default_headers(headers);
 	let builder = ClientBuilder::new().default_headers(headers);
 	let client = builder.build()?;
 	let health = base_url.join("health")?;
@@ -64,7 +72,7 @@ pub(crate) async fn run_router(
 ) {
 	let base_url = address.url;
 
-	let client = match client(&base_url).await {
+	let client = match client(&base_url, address.headers).await {
 		Ok(client) => {
 			let _ = conn_tx.send(Ok(())).await;
 			client
@@ -76,6 +76,30 @@ mod tests {
 			assert_eq!(duration, parsed, "Duration {string} not parsed correctly");
 		}
 	}
+
+	#[test]
+	fn statement_error() {
+		let message = crate::error::Db::TxRetryable.to_string();
+		let error = super::statement_error(message.clone());
+		assert!(matches!(error, crate::Error::Db(crate::error::Db::TxRetryable)), "{error:?}");
+		let message = format!("The query was not executed due to a failed transaction. {message}");
+		let error = super::statement_error(message.clone());
+		assert!(
+			matches!(error, crate::Error::Api(crate::error::Api::Query(ref query)) if *query == message),
+			"{error:?}"
+		);
+	}
+}
+
+/// Converts the message of a statement which failed on a remote instance into an error
+///
+/// Remote instances only send the message of the error, so transaction conflicts are recognised
+/// by their message to be able to retry them.
+fn statement_error(message: String) -> crate::Error {
+	if message == crate::error::Db::TxRetryable.to_string() {
+		return crate::Error::Db(crate::error::Db::TxRetryable);
+	}
+	Error::Query(message).into()
 }
 
 #[revisioned(revision = 1)]
@@ -131,10 +155,8 @@ impl DbResponse {
 							map.insert(index, (stats, Ok(response.result)));
 						}
 						Status::Err => {
-							map.insert(
-								index,
-								(stats, Err(Error::Query(response.result.as_raw_string()).into())),
-							);
+							let error = statement_error(response.result.as_raw_string());
+							map.insert(index, (stats, Err(error)));
 						}
 						_ => unreachable!(),
 					}
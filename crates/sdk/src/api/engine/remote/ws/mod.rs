@@ -11,10 +11,14 @@ use crate::api::Connect;
 use crate::api::Result;
 use crate::api::Surreal;
 use crate::opt::IntoEndpoint;
+use crate::value::Action;
 use crate::value::Notification;
 use channel::Sender;
 use indexmap::IndexMap;
+use revision::revisioned;
+use serde::Deserialize;
 use std::collections::HashMap;
+use std::collections::VecDeque;
 use std::marker::PhantomData;
 use std::time::Duration;
 use surrealdb_core::sql::Value as CoreValue;
@@ -24,6 +28,10 @@ use uuid::Uuid;
 pub(crate) const PATH: &str = "rpc";
 const PING_INTERVAL: Duration = Duration::from_secs(5);
 const REVISION_HEADER: &str = "revision";
+/// The maximum number of notifications kept for live queries which are not registered yet
+const MAX_UNREGISTERED_NOTIFICATIONS: usize = 1_000;
+/// How long notifications are kept for a live query which is not registered yet
+const UNREGISTERED_NOTIFICATION_TIMEOUT: Duration = Duration::from_secs(30);
 
 enum RequestEffect {
 	/// Completing this request sets a variable to a give value.
@@ -37,6 +45,8 @@ enum RequestEffect {
 	},
 	/// Insert requests repsonses need to be flattened in an array.
 	Insert,
+	/// The response frame is returned to the caller without decoding it.
+	Raw,
 	/// No effect
 	None,
 }
@@ -64,10 +74,16 @@ struct RouterState<Sink, Stream> {
 	replay: IndexMap<ReplayMethod, Command>,
 	/// Pending live queries
 	live_queries: HashMap<Uuid, channel::Sender<Notification<CoreValue>>>,
+	/// Notifications which arrived before their live query was registered, oldest first.
+	unregistered_notifications: VecDeque<(Instant, Notification<CoreValue>)>,
+	/// Unregistered live queries whose held notifications had to be dropped, and when.
+	dropped_notifications: HashMap<Uuid, Instant>,
 	/// Send requests which are still awaiting an awnser.
 	pending_requests: HashMap<i64, PendingRequest>,
-	/// The last time a message was recieved from the server.
+	/// The last time a message was sent to or recieved from the server.
 	last_activity: Instant,
+	/// The last time a message was recieved from the server.
+	last_received: Instant,
 	/// The sink into which messages are send to surrealdb
 	sink: Sink,
 	/// The stream from which messages are recieved from surrealdb
@@ -80,12 +96,93 @@ impl<Sink, Stream> RouterState<Sink, Stream> {
 			vars: IndexMap::new(),
 			replay: IndexMap::new(),
 			live_queries: HashMap::new(),
+			unregistered_notifications: VecDeque::new(),
+			dropped_notifications: HashMap::new(),
 			pending_requests: HashMap::new(),
 			last_activity: Instant::now(),
+			last_received: Instant::now(),
 			sink,
 			stream,
 		}
 	}
+
+	/// Keeps a notification for a live query which is not registered yet
+	///
+	/// The server can send notifications before the client has learned the id of the live
+	/// query, so they are held until the live query is registered. When too many are held,
+	/// the oldest are dropped and the live query is sent a gap once it is registered.
+	fn buffer_notification(&mut self, notification: Notification<CoreValue>) {
+		self.expire_notifications();
+		if self.unregistered_notifications.len() == MAX_UNREGISTERED_NOTIFICATIONS {
+			if let Some((_, dropped)) = self.unregistered_notifications.pop_front() {
+				self.dropped_notifications.insert(dropped.query_id, Instant::now());
+			}
+		}
+		self.unregistered_notifications.push_back((Instant::now(), notification));
+	}
+
+	/// Drops the notifications of live queries which were not registered in time
+	///
+	/// The live query is still sent a gap if it is registered before the gap expires too.
+	fn expire_notifications(&mut self) {
+		while let Some((received, _)) = self.unregistered_notifications.front() {
+			if received.elapsed() < UNREGISTERED_NOTIFICATION_TIMEOUT {
+				break;
+			}
+			if let Some((_, expired)) = self.unregistered_notifications.pop_front() {
+				self.dropped_notifications.insert(expired.query_id, Instant::now());
+			}
+		}
+		self.dropped_notifications
+			.retain(|_, dropped| dropped.elapsed() < UNREGISTERED_NOTIFICATION_TIMEOUT);
+	}
+
+	/// Removes and returns the notifications held for a live query, oldest first
+	///
+	/// If any of them had to be dropped, a gap comes first.
+	fn take_notifications(&mut self, id: Uuid) -> VecDeque<Notification<CoreValue>> {
+		let (taken, kept): (VecDeque<_>, VecDeque<_>) =
+			std::mem::take(&mut self.unregistered_notifications)
+				.into_iter()
+				.partition(|(_, notification)| notification.query_id == id);
+		self.unregistered_notifications = kept;
+		let mut taken: VecDeque<_> =
+			taken.into_iter().map(|(_, notification)| notification).collect();
+		if self.dropped_notifications.remove(&id).is_some() {
+			taken.push_front(Notification {
+				query_id: id,
+				action: Action::Gap,
+				data: CoreValue::None,
+			});
+		}
+		taken
+	}
+
+	/// Takes the pending request a frame answers, if the caller asked for the frame as is
+	///
+	/// Only the ID of the frame is decoded, the rest of it is handed back untouched.
+	fn take_raw_request(&mut self, frame: &[u8]) -> Option<PendingRequest> {
+		#[revisioned(revision = 1)]
+		#[derive(Deserialize)]
+		struct ResponseId {
+			id: Option<CoreValue>,
+		}
+
+		let ResponseId {
+			id,
+		} = super::deserialize(frame, true).ok()?;
+		let id = id?.coerce_to_i64().ok()?;
+		match self.pending_requests.get(&id)?.effect {
+			RequestEffect::Raw => self.pending_requests.remove(&id),
+			_ => None,
+		}
+	}
+
+	/// Drops everything held for live queries which are not registered yet
+	fn clear_notifications(&mut self) {
+		self.unregistered_notifications.clear();
+		self.dropped_notifications.clear();
+	}
 }
 
 enum HandleResult {
@@ -9,6 +9,7 @@ use crate::api::engine::remote::Response;
 use crate::api::engine::remote::{deserialize, serialize};
 use crate::api::err::Error;
 use crate::api::method::BoxFuture;
+use crate::api::opt::ConnectionState;
 use crate::api::opt::Endpoint;
 #[cfg(any(feature = "native-tls", feature = "rustls"))]
 use crate::api::opt::Tls;
@@ -34,6 +35,7 @@ use std::sync::OnceLock;
 use surrealdb_core::sql::Value as CoreValue;
 use tokio::net::TcpStream;
 use tokio::sync::watch;
+use tokio::sync::Semaphore;
 use tokio::time;
 use tokio::time::MissedTickBehavior;
 use tokio_tungstenite::tungstenite::client::IntoClientRequest;
@@ -104,6 +106,7 @@ impl Connection for Client {
 	) -> BoxFuture<'static, Result<Surreal<Self>>> {
 		Box::pin(async move {
 			address.url = address.url.join(PATH)?;
+			let inflight = address.max_inflight.map(Semaphore::new);
 			#[cfg(any(feature = "native-tls", feature = "rustls"))]
 			let maybe_connector = address.config.tls_config.clone().map(Connector::from);
 			#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
@@ -127,12 +130,15 @@ impl Connection for Client {
 
 			let mut features = HashSet::new();
 			features.insert(ExtraFeatures::LiveQueries);
+			features.insert(ExtraFeatures::RawResponses);
 
 			Ok(Surreal::new_from_router_waiter(
 				Arc::new(OnceLock::with_value(Router {
 					features,
 					sender: route_tx,
 					last_id: AtomicI64::new(0),
+					live_queries: Default::default(),
+					inflight,
 				})),
 				Arc::new(watch::channel(Some(WaitFor::Connection))),
 			))
@@ -186,11 +192,22 @@ async fn router_handle_route(
 		} => {
 			effect = RequestEffect::Insert;
 		}
+		Command::RawQuery {
+			..
+		} => {
+			effect = RequestEffect::Raw;
+		}
 		Command::SubscribeLive {
 			ref uuid,
 			ref notification_sender,
 		} => {
 			state.live_queries.insert(*uuid, notification_sender.clone());
+			// Deliver the notifications which arrived before the live query was registered
+			for notification in state.take_notifications(*uuid) {
+				if notification_sender.send(notification).await.is_err() {
+					break;
+				}
+			}
 			if response.clone().send(Ok(DbResponse::Other(CoreValue::None))).await.is_err() {
 				trace!("Receiver dropped");
 			}
@@ -201,6 +218,10 @@ async fn router_handle_route(
 			ref uuid,
 		} => {
 			state.live_queries.remove(uuid);
+			state
+				.unregistered_notifications
+				.retain(|(_, notification)| notification.query_id != *uuid);
+			state.dropped_notifications.remove(uuid);
 		}
 		Command::Use {
 			..
@@ -259,8 +280,20 @@ async fn router_handle_route(
 	HandleResult::Ok
 }
 
-async fn router_handle_response(response: Message, state: &mut RouterState) -> HandleResult {
-	match Response::try_from(&response) {
+async fn router_handle_response(message: Message, state: &mut RouterState) -> HandleResult {
+	let message = match message {
+		Message::Binary(binary) => match state.take_raw_request(&binary) {
+			// Hand back the frame as is, errors included
+			Some(pending) => {
+				let bytes = CoreValue::Bytes(binary.into());
+				let _res = pending.response_channel.send(Ok(DbResponse::Other(bytes))).await;
+				return HandleResult::Ok;
+			}
+			None => Message::Binary(binary),
+		},
+		message => message,
+	};
+	match Response::try_from(&message) {
 		Ok(option) => {
 			// We are only interested in responses that are not empty
 			if let Some(response) = option {
@@ -279,7 +312,7 @@ async fn router_handle_response(response: Message, state: &mut RouterState) -> H
 								};
 								// We can only route responses with IDs
 								match pending.effect {
-									RequestEffect::None => {}
+									RequestEffect::None | RequestEffect::Raw => {}
 									RequestEffect::Insert => {
 										// For insert, we need to flatten single responses in an array
 										if let DbResponse::Other(CoreValue::Array(array)) = resp {
@@ -324,15 +357,14 @@ async fn router_handle_response(response: Message, state: &mut RouterState) -> H
 						match response.result {
 							Ok(Data::Live(notification)) => {
 								let live_query_id = notification.id;
+								let notification = Notification {
+									query_id: *notification.id,
+									action: Action::from_core(notification.action),
+									data: notification.result,
+								};
 								// Check if this live query is registered
 								if let Some(sender) = state.live_queries.get(&live_query_id) {
 									// Send the notification back to the caller or kill live query if the receiver is already dropped
-
-									let notification = Notification {
-										query_id: *notification.id,
-										action: Action::from_core(notification.action),
-										data: notification.result,
-									};
 									if sender.send(notification).await.is_err() {
 										state.live_queries.remove(&live_query_id);
 										let kill = {
@@ -349,6 +381,9 @@ async fn router_handle_response(response: Message, state: &mut RouterState) -> H
 											return HandleResult::Disconnected;
 										}
 									}
+								} else {
+									// The live query may not be registered yet
+									state.buffer_notification(notification);
 								}
 							}
 							Ok(..) => { /* Ignored responses like pings */ }
@@ -366,7 +401,7 @@ async fn router_handle_response(response: Message, state: &mut RouterState) -> H
 			}
 
 			// Let's try to find out the ID of the response that failed to deserialise
-			if let Message::Binary(binary) = response {
+			if let Message::Binary(binary) = message {
 				if let Ok(ErrorResponse {
 					id,
 				}) = deserialize(&binary, true)
@@ -395,6 +430,7 @@ async fn router_reconnect(
 	state: &mut RouterState,
 	endpoint: &Endpoint,
 ) {
+	endpoint.hooks.emit(ConnectionState::Disconnected);
 	loop {
 		trace!("Reconnecting...");
 		match connect(endpoint, Some(*config), maybe_connector.clone()).await {
@@ -432,6 +468,7 @@ async fn router_reconnect(
 					}
 				}
 				trace!("Reconnected successfully");
+				endpoint.hooks.emit(ConnectionState::Reconnected);
 				break;
 			}
 			Err(error) => {
@@ -450,17 +487,23 @@ pub(crate) async fn run_router(
 	socket: WebSocketStream<MaybeTlsStream<TcpStream>>,
 	route_rx: Receiver<Route>,
 ) {
-	let ping = {
-		let request = Command::Health.into_router_request(None).unwrap();
-		let value = serialize(&request, true).unwrap();
-		Message::Binary(value)
+	let keepalive = endpoint.keepalive;
+	let ping_interval = keepalive.unwrap_or(PING_INTERVAL);
+	let ping = match keepalive {
+		// Keep-alive pings are sent as WebSocket ping frames, which the server answers with pongs
+		Some(_) => Message::Ping(Vec::new()),
+		None => {
+			let request = Command::Health.into_router_request(None).unwrap();
+			let value = serialize(&request, true).unwrap();
+			Message::Binary(value)
+		}
 	};
 
 	let (socket_sink, socket_stream) = socket.split();
 	let mut state = RouterState::new(socket_sink, socket_stream);
 
 	'router: loop {
-		let mut interval = time::interval(PING_INTERVAL);
+		let mut interval = time::interval(ping_interval);
 		// don't bombard the server with pings if we miss some ticks
 		interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
 
@@ -470,7 +513,9 @@ pub(crate) async fn run_router(
 		// recreated with each next.
 
 		state.last_activity = Instant::now();
+		state.last_received = Instant::now();
 		state.live_queries.clear();
+		state.clear_notifications();
 		state.pending_requests.clear();
 
 		loop {
@@ -520,6 +565,7 @@ pub(crate) async fn run_router(
 					};
 
 					state.last_activity = Instant::now();
+					state.last_received = state.last_activity;
 					match result {
 						Ok(message) => {
 							match router_handle_response(message, &mut state).await {
@@ -557,8 +603,25 @@ pub(crate) async fn run_router(
 					}
 				}
 				_ = pinger.next() => {
-					// only ping if we haven't talked to the server recently
-					if state.last_activity.elapsed() >= PING_INTERVAL {
+					// Notifications held for live queries which never register would otherwise pile up
+					state.expire_notifications();
+					let elapsed = state.last_activity.elapsed();
+					// the server has not answered a keep-alive ping within the grace period,
+					// which sending requests does not extend
+					let silent = state.last_received.elapsed();
+					if keepalive.is_some_and(|interval| silent >= interval.saturating_mul(2)) {
+						trace!("Keep-alive timed out, the connection is dead");
+						router_reconnect(
+							&maybe_connector,
+							&config,
+							&mut state,
+							&endpoint,
+						)
+						.await;
+						continue 'router;
+					}
+					// only ping if we haven't talked to the server recently, unless keep-alive is enabled
+					if keepalive.is_some() || elapsed >= PING_INTERVAL {
 						trace!("Pinging the server");
 						if let Err(error) = state.sink.send(ping.clone()).await {
 							trace!("failed to ping the server; {error:?}");
@@ -615,15 +678,293 @@ impl Response {
 
 #[cfg(test)]
 mod tests {
-	use super::serialize;
+	use super::super::{
+		PendingRequest, RequestEffect, RouterState, MAX_UNREGISTERED_NOTIFICATIONS,
+	};
+	use super::{connect, deserialize, run_router, serialize};
+	use crate::api::conn::{Command, RequestData, Route};
+	use crate::api::opt::{ConnectionState, Endpoint};
 	use bincode::Options;
 	use flate2::write::GzEncoder;
 	use flate2::Compression;
+	use futures::{SinkExt, StreamExt};
 	use rand::{thread_rng, Rng};
+	use revision::revisioned;
+	use serde::Serialize;
 	use std::io::Write;
-	use std::time::SystemTime;
+	use std::sync::Arc;
+	use std::time::{Duration, SystemTime};
+	use surrealdb_core::dbs::{Action, Notification};
 	use surrealdb_core::rpc::format::cbor::Cbor;
-	use surrealdb_core::sql::{Array, Value};
+	use surrealdb_core::rpc::Data;
+	use surrealdb_core::sql::{Array, Part, Value};
+	use tokio::net::{TcpListener, TcpStream};
+	use tokio::time;
+	use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+	use tokio_tungstenite::tungstenite::http::header::SEC_WEBSOCKET_PROTOCOL;
+	use tokio_tungstenite::tungstenite::http::HeaderValue;
+	use tokio_tungstenite::tungstenite::protocol::WebSocketConfig;
+	use tokio_tungstenite::tungstenite::Message;
+	use tokio_tungstenite::WebSocketStream;
+
+	/// Accepts the next connection on the listener, completing the handshake like the server does
+	async fn accept_ws(listener: &TcpListener) -> WebSocketStream<TcpStream> {
+		let (stream, _) = listener.accept().await.unwrap();
+		let callback = |_: &Request, mut response: Response| {
+			response.headers_mut().insert(
+				SEC_WEBSOCKET_PROTOCOL,
+				HeaderValue::from_static(super::super::REVISION_HEADER),
+			);
+			Ok(response)
+		};
+		tokio_tungstenite::accept_hdr_async(stream, callback).await.unwrap()
+	}
+
+	#[test_log::test(tokio::test)]
+	async fn keepalive_sends_pings() {
+		let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+		let address = listener.local_addr().unwrap();
+		let server = tokio::spawn(async move {
+			let mut socket = accept_ws(&listener).await;
+			let mut pings = 0;
+			while pings < 3 {
+				match socket.next().await {
+					Some(Ok(Message::Ping(..))) => pings += 1,
+					Some(Ok(..)) => {}
+					message => panic!("unexpected message: {message:?}"),
+				}
+			}
+			pings
+		});
+		let mut endpoint = Endpoint::new(format!("ws://{address}").parse().unwrap());
+		endpoint.keepalive = Some(Duration::from_millis(50));
+		let socket = connect(&endpoint, None, None).await.unwrap();
+		let (_route_tx, route_rx) = channel::unbounded();
+		tokio::spawn(run_router(endpoint, None, 0, WebSocketConfig::default(), socket, route_rx));
+		let pings = time::timeout(Duration::from_secs(5), server).await.unwrap().unwrap();
+		assert_eq!(pings, 3);
+	}
+
+	#[test_log::test(tokio::test)]
+	async fn keepalive_detects_silent_servers_while_sending() {
+		let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+		let address = listener.local_addr().unwrap();
+		let server = tokio::spawn(async move {
+			// Never read from the socket, so pings are not answered
+			accept_ws(&listener).await
+		});
+		let (tx, rx) = channel::unbounded();
+		let mut endpoint = Endpoint::new(format!("ws://{address}").parse().unwrap());
+		endpoint.keepalive = Some(Duration::from_millis(50));
+		endpoint.hooks.on_disconnect = Some(Arc::new(move |event| {
+			let _ = tx.try_send(event);
+		}));
+		endpoint.hooks.start(&endpoint.url);
+		let socket = connect(&endpoint, None, None).await.unwrap();
+		let (route_tx, route_rx) = channel::unbounded();
+		tokio::spawn(run_router(endpoint, None, 0, WebSocketConfig::default(), socket, route_rx));
+		let _socket = server.await.unwrap();
+		// Sending requests must not count as hearing from the server
+		let sender = tokio::spawn(async move {
+			for id in 1.. {
+				let (response, _) = channel::bounded(1);
+				let route = Route {
+					request: RequestData {
+						id,
+						command: Command::Health,
+					},
+					response,
+				};
+				if route_tx.send(route).await.is_err() {
+					break;
+				}
+				time::sleep(Duration::from_millis(10)).await;
+			}
+		});
+		let event = time::timeout(Duration::from_secs(5), rx.recv()).await.unwrap().unwrap();
+		assert_eq!(event.state, ConnectionState::Disconnected);
+		sender.abort();
+	}
+
+	#[test_log::test(tokio::test)]
+	async fn hooks_report_dropped_connections() {
+		let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+		let address = listener.local_addr().unwrap();
+		let server = tokio::spawn(async move {
+			// Drop the first connection straight away to simulate a network failure
+			drop(accept_ws(&listener).await);
+			accept_ws(&listener).await
+		});
+		let (tx, rx) = channel::unbounded();
+		let on_connect = tx.clone();
+		let mut endpoint = Endpoint::new(format!("ws://{address}").parse().unwrap());
+		endpoint.hooks.on_connect = Some(Arc::new(move |event| {
+			let _ = on_connect.try_send(event);
+		}));
+		endpoint.hooks.on_disconnect = Some(Arc::new(move |event| {
+			let _ = tx.try_send(event);
+		}));
+		endpoint.hooks.start(&endpoint.url);
+		let socket = connect(&endpoint, None, None).await.unwrap();
+		let (_route_tx, route_rx) = channel::unbounded();
+		tokio::spawn(run_router(endpoint, None, 0, WebSocketConfig::default(), socket, route_rx));
+		let event = time::timeout(Duration::from_secs(5), rx.recv()).await.unwrap().unwrap();
+		assert_eq!(event.state, ConnectionState::Disconnected);
+		let event = time::timeout(Duration::from_secs(5), rx.recv()).await.unwrap().unwrap();
+		assert_eq!(event.state, ConnectionState::Reconnected);
+		assert_eq!(event.address, format!("ws://{address}/"));
+		let _socket = server.await.unwrap();
+	}
+
+	#[revisioned(revision = 1)]
+	#[derive(Serialize)]
+	struct ResponseFrame {
+		id: Option<Value>,
+		result: Result<Data, Value>,
+	}
+
+	fn notification(live_query_id: uuid::Uuid, data: i64) -> ResponseFrame {
+		ResponseFrame {
+			id: None,
+			result: Ok(Data::Live(Notification::new(
+				live_query_id.into(),
+				Action::Create,
+				Value::None,
+				Value::from(data),
+			))),
+		}
+	}
+
+	#[test_log::test(tokio::test)]
+	async fn notifications_before_registration_are_delivered() {
+		let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+		let address = listener.local_addr().unwrap();
+		let live_query_id = uuid::Uuid::new_v4();
+		let server = tokio::spawn(async move {
+			let mut socket = accept_ws(&listener).await;
+			// Notify the client before it has registered the live query
+			let frame = notification(live_query_id, 1);
+			socket.send(Message::Binary(serialize(&frame, true).unwrap())).await.unwrap();
+			// Answer the first request, which the client only sees after the notification
+			while let Some(message) = socket.next().await {
+				let Message::Binary(binary) = message.unwrap() else {
+					continue;
+				};
+				let request: Value = deserialize(&binary, true).unwrap();
+				if request.pick(&[Part::from("id")]) == Value::from(1) {
+					let frame = ResponseFrame {
+						id: Some(Value::from(1)),
+						result: Ok(Data::Other(Value::None)),
+					};
+					socket.send(Message::Binary(serialize(&frame, true).unwrap())).await.unwrap();
+					break;
+				}
+			}
+			socket
+		});
+		let endpoint = Endpoint::new(format!("ws://{address}").parse().unwrap());
+		let socket = connect(&endpoint, None, None).await.unwrap();
+		let (route_tx, route_rx) = channel::unbounded();
+		tokio::spawn(run_router(endpoint, None, 0, WebSocketConfig::default(), socket, route_rx));
+		let (response, health) = channel::bounded(1);
+		let route = Route {
+			request: RequestData {
+				id: 1,
+				command: Command::Health,
+			},
+			response,
+		};
+		route_tx.send(route).await.unwrap();
+		health.recv().await.unwrap().unwrap();
+		let _socket = server.await.unwrap();
+		let (notification_sender, notifications) = channel::unbounded();
+		let (response, registered) = channel::bounded(1);
+		let route = Route {
+			request: RequestData {
+				id: 2,
+				command: Command::SubscribeLive {
+					uuid: live_query_id,
+					notification_sender,
+				},
+			},
+			response,
+		};
+		route_tx.send(route).await.unwrap();
+		registered.recv().await.unwrap().unwrap();
+		let notification = notifications.try_recv().unwrap();
+		assert_eq!(notification.query_id, live_query_id);
+		assert_eq!(notification.data, Value::from(1));
+	}
+
+	#[test]
+	fn dropped_unregistered_notifications_are_reported_as_a_gap() {
+		let mut state = RouterState::new((), ());
+		let first = uuid::Uuid::new_v4();
+		let second = uuid::Uuid::new_v4();
+		let to_notification = |frame: ResponseFrame| match frame.result {
+			Ok(Data::Live(notification)) => crate::Notification {
+				query_id: *notification.id,
+				action: crate::Action::from_core(notification.action),
+				data: notification.result,
+			},
+			_ => unreachable!(),
+		};
+		state.buffer_notification(to_notification(notification(first, 0)));
+		for data in 0..MAX_UNREGISTERED_NOTIFICATIONS {
+			state.buffer_notification(to_notification(notification(second, data as i64)));
+		}
+		// The only notification for the first live query made room for the others
+		let taken = state.take_notifications(first);
+		assert_eq!(taken.len(), 1);
+		assert_eq!(taken[0].action, crate::Action::Gap);
+		let taken = state.take_notifications(second);
+		assert_eq!(taken.len(), MAX_UNREGISTERED_NOTIFICATIONS);
+		assert!(taken.iter().all(|notification| notification.action == crate::Action::Create));
+	}
+
+	#[test]
+	fn raw_requests_are_matched_by_the_frame_id_only() {
+		#[revisioned(revision = 1)]
+		#[derive(Serialize)]
+		struct FrameHeader {
+			id: Option<Value>,
+		}
+
+		let frame = |id: i64| {
+			let mut frame = serialize(
+				&FrameHeader {
+					id: Some(Value::from(id)),
+				},
+				true,
+			)
+			.unwrap();
+			// The rest of the frame is never decoded
+			frame.extend([0xff; 8]);
+			frame
+		};
+		let mut state = RouterState::new((), ());
+		let (response_channel, _raw) = channel::bounded(1);
+		state.pending_requests.insert(
+			1,
+			PendingRequest {
+				effect: RequestEffect::Raw,
+				response_channel,
+			},
+		);
+		let (response_channel, _decoded) = channel::bounded(1);
+		state.pending_requests.insert(
+			2,
+			PendingRequest {
+				effect: RequestEffect::None,
+				response_channel,
+			},
+		);
+		assert!(state.take_raw_request(&frame(1)).is_some());
+		assert!(state.take_raw_request(&frame(2)).is_none());
+		assert!(state.take_raw_request(&frame(3)).is_none());
+		assert!(!state.pending_requests.contains_key(&1));
+		assert!(state.pending_requests.contains_key(&2));
+	}
 
 	#[test_log::test]
 	fn large_vector_serialisation_bench() {
@@ -9,6 +9,7 @@ use crate::api::engine::remote::Response;
 use crate::api::engine::remote::{deserialize, serialize};
 use crate::api::err::Error;
 use crate::api::method::BoxFuture;
+use crate::api::opt::ConnectionState;
 use crate::api::opt::Endpoint;
 use crate::api::ExtraFeatures;
 use crate::api::OnceLockExt;
@@ -38,6 +39,7 @@ use std::sync::OnceLock;
 use std::time::Duration;
 use surrealdb_core::sql::Value as CoreValue;
 use tokio::sync::watch;
+use tokio::sync::Semaphore;
 use trice::Instant;
 use wasm_bindgen_futures::spawn_local;
 use wasmtimer::tokio as time;
@@ -59,6 +61,7 @@ impl Connection for Client {
 	) -> BoxFuture<'static, Result<Surreal<Self>>> {
 		Box::pin(async move {
 			address.url = address.url.join(PATH)?;
+			let inflight = address.max_inflight.map(Semaphore::new);
 
 			let (route_tx, route_rx) = match capacity {
 				0 => channel::unbounded(),
@@ -73,12 +76,15 @@ impl Connection for Client {
 
 			let mut features = HashSet::new();
 			features.insert(ExtraFeatures::LiveQueries);
+			features.insert(ExtraFeatures::RawResponses);
 
 			Ok(Surreal::new_from_router_waiter(
 				Arc::new(OnceLock::with_value(Router {
 					features,
 					sender: route_tx,
 					last_id: AtomicI64::new(0),
+					live_queries: Default::default(),
+					inflight,
 				})),
 				Arc::new(watch::channel(Some(WaitFor::Connection))),
 			))
@@ -133,11 +139,22 @@ async fn router_handle_request(
 		} => {
 			effect = RequestEffect::Insert;
 		}
+		Command::RawQuery {
+			..
+		} => {
+			effect = RequestEffect::Raw;
+		}
 		Command::SubscribeLive {
 			ref uuid,
 			ref notification_sender,
 		} => {
 			state.live_queries.insert(*uuid, notification_sender.clone());
+			// Deliver the notifications which arrived before the live query was registered
+			for notification in state.take_notifications(*uuid) {
+				if notification_sender.send(notification).await.is_err() {
+					break;
+				}
+			}
 			if response.send(Ok(DbResponse::Other(CoreValue::None))).await.is_err() {
 				trace!("Receiver dropped");
 			}
@@ -148,6 +165,10 @@ async fn router_handle_request(
 			ref uuid,
 		} => {
 			state.live_queries.remove(uuid);
+			state
+				.unregistered_notifications
+				.retain(|(_, notification)| notification.query_id != *uuid);
+			state.dropped_notifications.remove(uuid);
 		}
 		Command::Use {
 			..
@@ -207,11 +228,23 @@ async fn router_handle_request(
 }
 
 async fn router_handle_response(
-	response: Message,
+	message: Message,
 	state: &mut RouterState,
 	_endpoint: &Endpoint,
 ) -> HandleResult {
-	match Response::try_from(&response) {
+	let message = match message {
+		Message::Binary(binary) => match state.take_raw_request(&binary) {
+			// Hand back the frame as is, errors included
+			Some(pending) => {
+				let bytes = CoreValue::Bytes(binary.into());
+				let _res = pending.response_channel.send(Ok(DbResponse::Other(bytes))).await;
+				return HandleResult::Ok;
+			}
+			None => Message::Binary(binary),
+		},
+		message => message,
+	};
+	match Response::try_from(&message) {
 		Ok(option) => {
 			// We are only interested in responses that are not empty
 			if let Some(response) = option {
@@ -223,7 +256,7 @@ async fn router_handle_response(
 							// We can only route responses with IDs
 							if let Some(pending) = state.pending_requests.remove(&id) {
 								match pending.effect {
-									RequestEffect::None => {}
+									RequestEffect::None | RequestEffect::Raw => {}
 									RequestEffect::Insert => {
 										// For insert, we need to flatten single responses in an array
 										if let Ok(Data::Other(CoreValue::Array(value))) =
@@ -274,15 +307,14 @@ async fn router_handle_response(
 					None => match response.result {
 						Ok(Data::Live(notification)) => {
 							let live_query_id = notification.id;
+							let notification = Notification {
+								query_id: notification.id.0,
+								action: Action::from_core(notification.action),
+								data: notification.result,
+							};
 							// Check if this live query is registered
 							if let Some(sender) = state.live_queries.get(&live_query_id) {
 								// Send the notification back to the caller or kill live query if the receiver is already dropped
-								let notification = Notification {
-									query_id: notification.id.0,
-									action: Action::from_core(notification.action),
-									data: notification.result,
-								};
-
 								if sender.send(notification).await.is_err() {
 									state.live_queries.remove(&live_query_id);
 									let kill = {
@@ -300,6 +332,9 @@ async fn router_handle_response(
 										return HandleResult::Disconnected;
 									}
 								}
+							} else {
+								// The live query may not be registered yet
+								state.buffer_notification(notification);
 							}
 						}
 						Ok(..) => { /* Ignored responses like pings */ }
@@ -316,7 +351,7 @@ async fn router_handle_response(
 			}
 
 			// Let's try to find out the ID of the response that failed to deserialise
-			if let Message::Binary(binary) = response {
+			if let Message::Binary(binary) = message {
 				if let Ok(Response {
 					id,
 				}) = deserialize(&mut &binary[..], true)
@@ -345,6 +380,7 @@ async fn router_reconnect(
 	endpoint: &Endpoint,
 	capacity: usize,
 ) {
+	endpoint.hooks.emit(ConnectionState::Disconnected);
 	loop {
 		trace!("Reconnecting...");
 		let connect = WsMeta::connect(&endpoint.url, vec![super::REVISION_HEADER]).await;
@@ -392,6 +428,7 @@ async fn router_reconnect(
 					}
 				}
 				trace!("Reconnected successfully");
+				endpoint.hooks.emit(ConnectionState::Reconnected);
 				break;
 			}
 			Err(error) => {
@@ -441,19 +478,25 @@ pub(crate) async fn run_router(
 		Message::Binary(value)
 	};
 
+	// Browsers do not expose WebSocket ping frames, so keep-alive uses the ping method instead
+	let keepalive = endpoint.keepalive;
+	let ping_interval = keepalive.unwrap_or(PING_INTERVAL);
+
 	let (socket_sink, socket_stream) = socket.split();
 
 	let mut state = RouterState::new(socket_sink, socket_stream);
 
 	'router: loop {
-		let mut interval = time::interval(PING_INTERVAL);
+		let mut interval = time::interval(ping_interval);
 		// don't bombard the server with pings if we miss some ticks
 		interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
 
 		let mut pinger = IntervalStream::new(interval);
 
 		state.last_activity = Instant::now();
+		state.last_received = Instant::now();
 		state.live_queries.clear();
+		state.clear_notifications();
 		state.pending_requests.clear();
 
 		loop {
@@ -485,6 +528,7 @@ pub(crate) async fn run_router(
 					};
 
 					state.last_activity = Instant::now();
+					state.last_received = state.last_activity;
 					match router_handle_response(message, &mut state,&endpoint).await {
 						HandleResult::Ok => {},
 						HandleResult::Disconnected => {
@@ -514,7 +558,18 @@ pub(crate) async fn run_router(
 					}
 				}
 				_ = pinger.next().fuse() => {
-					if state.last_activity.elapsed() >= PING_INTERVAL {
+					// Notifications held for live queries which never register would otherwise pile up
+					state.expire_notifications();
+					let elapsed = state.last_activity.elapsed();
+					// the server has not answered a keep-alive ping within the grace period,
+					// which sending requests does not extend
+					let silent = state.last_received.elapsed();
+					if keepalive.is_some_and(|interval| silent >= interval.saturating_mul(2)) {
+						trace!("Keep-alive timed out, the connection is dead");
+						router_reconnect(&mut state, &mut events, &endpoint, capacity).await;
+						break;
+					}
+					if keepalive.is_some() || elapsed >= PING_INTERVAL {
 						trace!("Pinging the server");
 						if let Err(error) = state.sink.send(ping.clone()).await {
 							trace!("failed to ping the server; {error:?}");
@@ -1,9 +1,10 @@
-use crate::{api::Response, Value};
+use crate::{api::Response, value::ConversionError, RecordId, Value};
 use serde::Serialize;
 use std::path::PathBuf;
 use std::{convert::Infallible, io};
 use surrealdb_core::dbs::capabilities::{ParseFuncTargetError, ParseNetTargetError};
 use thiserror::Error;
+use uuid::Uuid;
 
 /// An error originating from a remote SurrealDB database
 #[derive(Error, Debug)]
@@ -95,6 +96,21 @@ pub enum Error {
 	#[error("Invalid URL: {0}")]
 	InvalidUrl(String),
 
+	/// The endpoint URL contained an unknown query-string option or an invalid option value
+	///
+	/// Query-string parameters were ignored before these options were supported.
+	#[error("Invalid endpoint option `{key}` with value `{value}`")]
+	InvalidEndpointOption {
+		key: String,
+		value: String,
+	},
+
+	/// An option in the query string of the endpoint URL conflicts with the supplied `Config`
+	#[error("The endpoint option `{key}` conflicts with the supplied configuration")]
+	ConflictingEndpointOption {
+		key: String,
+	},
+
 	/// Failed to convert a `sql::Value` to `T`
 	#[error("Failed to convert `{value}` to `T`: {error}")]
 	FromValue {
@@ -102,6 +118,18 @@ pub enum Error {
 		error: String,
 	},
 
+	/// A value did not have the type it was being converted into
+	#[error("{0}")]
+	ValueConversion(ConversionError),
+
+	/// Failed to convert a value to `T` because of the value at the specified path
+	#[error("Failed to convert value at `{}`: {error}", display_path(path))]
+	FromValueAt {
+		/// The path to the failing value, one segment per object key or `[index]`
+		path: Vec<String>,
+		error: String,
+	},
+
 	/// Failed to deserialize a binary response
 	#[error("Failed to deserialize a binary response: {error}")]
 	ResponseFromBinary {
@@ -173,6 +201,17 @@ pub enum Error {
 	#[error("The protocol or storage engine does not support live queries on this architecture")]
 	LiveQueriesNotSupported,
 
+	/// The protocol or storage engine being used does not support running a query with a
+	/// different session
+	#[error(
+		"The protocol or storage engine does not support running queries with a session override"
+	)]
+	SessionOverrideNotSupported,
+
+	/// The protocol or storage engine being used does not return raw response frames
+	#[error("The protocol or storage engine does not support returning raw responses")]
+	RawResponsesNotSupported,
+
 	/// Tried to use a range query on an object
 	#[error("Live queries on objects not supported")]
 	LiveOnObject,
@@ -193,10 +232,28 @@ pub enum Error {
 	#[error("Query statement {0} is not a live query")]
 	NotLiveQuery(usize),
 
+	/// A statement in a query returned an error
+	#[error("Query statement {index} failed: {error}")]
+	StatementFailed {
+		index: usize,
+		error: Box<crate::Error>,
+	},
+
 	/// Tried to access a query statement falling outside the bounds of the statements supplied
 	#[error("Query statement {0} is out of bounds")]
 	QueryIndexOutOfBounds(usize),
 
+	/// Notifications of a resumed live query were missed and could not be replayed
+	#[error("Notifications of live query {0} were missed and could not be replayed")]
+	LiveQueryGap(Uuid),
+
+	/// The number of statements in a query did not match the number of typed results expected
+	#[error("Expected {expected} query statements but found {found}")]
+	StatementCountMismatch {
+		expected: usize,
+		found: usize,
+	},
+
 	/// Called `Response::take` or `Response::stream` on a query response more than once
 	#[error("Tried to take a query response that has already been taken")]
 	ResponseAlreadyTaken,
@@ -221,6 +278,30 @@ pub enum Error {
 	#[error("Insert queries on unspecified resource with no data are not supported")]
 	InsertOnUnspecified,
 
+	/// Tried to create a record which already exists
+	#[error("Database record `{record}` already exists")]
+	RecordExists {
+		record: RecordId,
+	},
+
+	/// Tried to patch a record which does not exist
+	#[error("Database record `{record}` does not exist")]
+	RecordNotFound {
+		record: RecordId,
+	},
+
+	/// A segment of an object path exists but is not an object
+	#[error("Path segment `{0}` is not an object")]
+	PathNotObject(String),
+
+	/// A JSON Patch operation contained a path which is not a valid JSON Pointer
+	#[error("Invalid JSON Patch path: `{0}`")]
+	InvalidPatchPath(String),
+
+	/// A field given to a filter or fetch was not a plain path of field names and indexes
+	#[error("Invalid field path: `{0}`")]
+	InvalidFieldPath(String),
+
 	#[error("{0}")]
 	InvalidNetTarget(#[from] ParseNetTargetError),
 
@@ -244,6 +325,24 @@ pub enum Error {
 	/// The engine used does not support data versioning
 	#[error("The '{0}' engine does not support data versioning")]
 	VersionsNotSupported(String),
+
+	/// Failed to encode a value into, or decode a value from, CBOR
+	#[error("failed to convert a value to or from CBOR: {0}")]
+	Cbor(String),
+}
+
+fn display_path(path: &[String]) -> String {
+	let mut out = String::new();
+	for segment in path {
+		if !segment.starts_with('[') {
+			out.push('.');
+		}
+		out.push_str(segment);
+	}
+	if out.is_empty() {
+		out.push('.');
+	}
+	out
 }
 
 impl serde::ser::Error for Error {
@@ -33,6 +33,10 @@ where
 }
 
 /// An ongoing transaction
+///
+/// Each method sends its own request, and the server cancels any transaction which is still
+/// open at the end of a request, so this does not group statements into a transaction. Use
+/// [`Surreal::buffered_transaction`] instead.
 #[derive(Debug)]
 #[must_use = "transactions must be committed or cancelled to complete them"]
 pub struct Transaction<C: Connection> {
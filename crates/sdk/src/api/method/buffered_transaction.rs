@@ -0,0 +1,62 @@
+use crate::api::method::BoxFuture;
+use crate::api::opt::IntoQuery;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::method::Query;
+use crate::Response;
+use serde::Serialize;
+use std::future::IntoFuture;
+use surrealdb_core::sql::statements::{BeginStatement, CommitStatement};
+use surrealdb_core::sql::Statement;
+
+/// A transaction which is sent to the database in a single request
+///
+/// The server cancels any transaction which is still open at the end of a request, so the
+/// statements added with [`BufferedTransaction::query`] are held by the client and sent to the
+/// database together, wrapped in `BEGIN` and `COMMIT`, when the transaction is awaited. Either
+/// every statement is applied or none of them are. Dropping the transaction discards the
+/// statements without sending them.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct BufferedTransaction<'r, C: Connection> {
+	pub(super) query: Query<'r, C>,
+}
+
+impl<'r, C> BufferedTransaction<'r, C>
+where
+	C: Connection,
+{
+	/// Adds a set of SurrealQL statements to the transaction
+	pub fn query(self, query: impl IntoQuery) -> Self {
+		Self {
+			query: self.query.query(query),
+		}
+	}
+
+	/// Binds a parameter or parameters to the statements of the transaction
+	///
+	/// The parameters are shared by every statement in the transaction.
+	pub fn bind(self, bindings: impl Serialize + 'static) -> Self {
+		Self {
+			query: self.query.bind(bindings),
+		}
+	}
+}
+
+impl<'r, C> IntoFuture for BufferedTransaction<'r, C>
+where
+	C: Connection,
+{
+	type Output = Result<Response>;
+	type IntoFuture = BoxFuture<'r, Self::Output>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		self.query
+			.map_valid(|mut valid| {
+				valid.query.insert(0, Statement::Begin(BeginStatement::default()));
+				valid.query.push(Statement::Commit(CommitStatement::default()));
+				Ok(valid)
+			})
+			.into_future()
+	}
+}
@@ -1,5 +1,8 @@
 use crate::api::conn::Command;
+use crate::api::err::Error;
+use crate::api::method::return_none;
 use crate::api::method::BoxFuture;
+use crate::api::method::ReturnNone;
 use crate::api::Connection;
 use crate::api::Result;
 use crate::method::OnceLockExt;
@@ -43,6 +46,43 @@ where
 			..self
 		}
 	}
+
+	/// Writes the records without returning them
+	///
+	/// This runs the statement with `RETURN NONE`, which saves sending the records back
+	/// and deserialising them when they're not needed.
+	pub fn return_none(self) -> ReturnNone<'r, C> {
+		let statement = self.command.and_then(|command| match command {
+			Command::Create {
+				what,
+				data,
+			} => Ok(return_none::create(what, data)),
+			Command::Upsert {
+				what,
+				data,
+			} => Ok(return_none::upsert(what, data)),
+			Command::Update {
+				what,
+				data,
+			} => Ok(return_none::update(what, data)),
+			Command::Insert {
+				what,
+				data,
+			} => Ok(return_none::insert(what, data, false)),
+			Command::InsertRelation {
+				what,
+				data,
+			} => Ok(return_none::insert(what, data, true)),
+			_ => {
+				Err(Error::InvalidRequest("only writes can skip returning records".to_owned())
+					.into())
+			}
+		});
+		ReturnNone {
+			client: self.client,
+			statement,
+		}
+	}
 }
 
 macro_rules! into_future {
@@ -1,5 +1,7 @@
 use crate::api::conn::Command;
+use crate::api::method::return_none;
 use crate::api::method::BoxFuture;
+use crate::api::method::ReturnNone;
 use crate::api::opt::Resource;
 use crate::api::Connection;
 use crate::api::Result;
@@ -24,7 +26,7 @@ pub struct Create<'r, C: Connection, R> {
 	pub(super) response_type: PhantomData<R>,
 }
 
-impl<C, R> Create<'_, C, R>
+impl<'r, C, R> Create<'r, C, R>
 where
 	C: Connection,
 {
@@ -35,6 +37,17 @@ where
 			..self
 		}
 	}
+
+	/// Creates the record without returning it
+	///
+	/// This runs the statement with `RETURN NONE`, which saves sending the record back
+	/// and deserialising it when it's not needed.
+	pub fn return_none(self) -> ReturnNone<'r, C> {
+		ReturnNone {
+			client: self.client,
+			statement: self.resource.map(|what| return_none::create(what, None)),
+		}
+	}
 }
 
 macro_rules! into_future {
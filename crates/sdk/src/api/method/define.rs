@@ -0,0 +1,174 @@
+use crate::api::method::BoxFuture;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::method::Query;
+use crate::Surreal;
+use std::borrow::Cow;
+use std::future::IntoFuture;
+use surrealdb_core::sql::statements::{
+	DefineFieldStatement, DefineStatement, DefineTableStatement,
+};
+use surrealdb_core::sql::{Ident, Kind, Permissions, Statement};
+use surrealdb_core::syn;
+
+/// A table definition future
+///
+/// The table and its fields are defined in a single query.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct DefineTable<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) table: String,
+	pub(super) schemafull: bool,
+	pub(super) if_not_exists: bool,
+	pub(super) permissions: Option<Permissions>,
+	pub(super) fields: Vec<(String, Kind)>,
+}
+
+impl<'r, C> DefineTable<'r, C>
+where
+	C: Connection,
+{
+	/// Only allows the fields defined on the table to be stored
+	pub fn schemafull(mut self) -> Self {
+		self.schemafull = true;
+		self
+	}
+
+	/// Allows fields which are not defined on the table to be stored
+	///
+	/// This is the default.
+	pub fn schemaless(mut self) -> Self {
+		self.schemafull = false;
+		self
+	}
+
+	/// Leaves the table and its fields untouched if the table already exists
+	pub fn if_not_exists(mut self) -> Self {
+		self.if_not_exists = true;
+		self
+	}
+
+	/// Sets the permissions of the table
+	///
+	/// Tables without permissions can only be accessed by system users.
+	pub fn permissions(mut self, permissions: Permissions) -> Self {
+		self.permissions = Some(permissions);
+		self
+	}
+
+	/// Defines a field on the table
+	///
+	/// The name may be a path to a nested field, such as `address.city`.
+	pub fn field(mut self, name: impl Into<String>, kind: Kind) -> Self {
+		self.fields.push((name.into(), kind));
+		self
+	}
+
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> DefineTable<'static, C> {
+		DefineTable {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+
+	fn statements(self) -> Result<(Cow<'r, Surreal<C>>, Vec<Statement>)> {
+		let mut table = DefineTableStatement::default();
+		table.name = Ident::from(self.table.as_str());
+		table.full = self.schemafull;
+		table.if_not_exists = self.if_not_exists;
+		table.permissions = self.permissions.unwrap_or_else(Permissions::none);
+		let mut statements = vec![Statement::Define(DefineStatement::Table(table))];
+		for (name, kind) in self.fields {
+			let field = define_field(&self.table, &name, kind, self.if_not_exists)?;
+			statements.push(Statement::Define(DefineStatement::Field(field)));
+		}
+		Ok((self.client, statements))
+	}
+}
+
+impl<'r, Client> IntoFuture for DefineTable<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<()>;
+	type IntoFuture = BoxFuture<'r, Self::Output>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let (client, statements) = self.statements()?;
+			Query::new(client, statements, Default::default(), false).await?.check()?;
+			Ok(())
+		})
+	}
+}
+
+/// A field definition future
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct DefineField<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) table: String,
+	pub(super) name: String,
+	pub(super) kind: Kind,
+	pub(super) if_not_exists: bool,
+	pub(super) readonly: bool,
+}
+
+impl<C> DefineField<'_, C>
+where
+	C: Connection,
+{
+	/// Leaves the field untouched if it already exists
+	pub fn if_not_exists(mut self) -> Self {
+		self.if_not_exists = true;
+		self
+	}
+
+	/// Only allows the field to be set when a record is created
+	pub fn readonly(mut self) -> Self {
+		self.readonly = true;
+		self
+	}
+
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> DefineField<'static, C> {
+		DefineField {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for DefineField<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<()>;
+	type IntoFuture = BoxFuture<'r, Self::Output>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let mut field = define_field(&self.table, &self.name, self.kind, self.if_not_exists)?;
+			field.readonly = self.readonly;
+			let statements = vec![Statement::Define(DefineStatement::Field(field))];
+			Query::new(self.client, statements, Default::default(), false).await?.check()?;
+			Ok(())
+		})
+	}
+}
+
+fn define_field(
+	table: &str,
+	name: &str,
+	kind: Kind,
+	if_not_exists: bool,
+) -> Result<DefineFieldStatement> {
+	let mut field = DefineFieldStatement::default();
+	field.name = syn::idiom(name)?;
+	field.what = Ident::from(table);
+	field.kind = Some(kind);
+	field.if_not_exists = if_not_exists;
+	Ok(field)
+}
@@ -1,5 +1,7 @@
 use crate::api::conn::Command;
+use crate::api::method::return_none;
 use crate::api::method::BoxFuture;
+use crate::api::method::ReturnNone;
 use crate::api::opt::Resource;
 use crate::api::Connection;
 use crate::api::Result;
@@ -21,7 +23,7 @@ pub struct Delete<'r, C: Connection, R> {
 	pub(super) response_type: PhantomData<R>,
 }
 
-impl<C, R> Delete<'_, C, R>
+impl<'r, C, R> Delete<'r, C, R>
 where
 	C: Connection,
 {
@@ -32,6 +34,17 @@ where
 			..self
 		}
 	}
+
+	/// Deletes the records without returning them
+	///
+	/// This runs the statement with `RETURN NONE`, which saves sending the deleted
+	/// records back and deserialising them when they're not needed.
+	pub fn return_none(self) -> ReturnNone<'r, C> {
+		ReturnNone {
+			client: self.client,
+			statement: self.resource.map(return_none::delete),
+		}
+	}
 }
 
 macro_rules! into_future {
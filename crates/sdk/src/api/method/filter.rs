@@ -0,0 +1,345 @@
+use crate::api::engine::resource_to_values;
+use crate::api::err::Error;
+use crate::api::method::BoxFuture;
+use crate::api::opt::Resource;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::method::Query;
+use crate::Response;
+use crate::Surreal;
+use crate::Value;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::borrow::Cow;
+use std::future::IntoFuture;
+use std::marker::PhantomData;
+use std::ops::Not;
+use surrealdb_core::sql::statements::SelectStatement;
+use surrealdb_core::sql::{
+	to_value as to_core_value, Cond, Expression, Fetch, Fetchs, Field, Function, Groups, Idiom,
+	Object as CoreObject, Operator, Param, Part, Statement, Table, Value as CoreValue,
+};
+use surrealdb_core::syn;
+
+/// A condition on the records returned by [`Select::filter`](crate::method::Select::filter)
+///
+/// Values are always sent to the database as query parameters, never written into the query
+/// itself, so they can safely come from untrusted input. Fields are paths of field names and
+/// array indexes, such as `age`, `address.city` or `emails[0]`. Anything else, such as a graph
+/// traversal or a `WHERE` clause, is rejected when the select is run.
+///
+/// Conditions can be combined with [`Filter::and`] and [`Filter::or`], and negated with `!`.
+///
+/// ```
+/// use surrealdb::method::Filter;
+///
+/// let adult = Filter::gte("age", 18);
+/// let filter = adult.and(!Filter::inside("country", ["GB", "US"]));
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct Filter(Inner);
+
+#[derive(Debug)]
+enum Inner {
+	Compare {
+		field: String,
+		operator: Operator,
+		value: Result<CoreValue>,
+	},
+	Binary(Box<Filter>, Operator, Box<Filter>),
+	Not(Box<Filter>),
+}
+
+impl Filter {
+	fn compare(
+		field: impl Into<String>,
+		operator: Operator,
+		value: impl Serialize + 'static,
+	) -> Self {
+		Self(Inner::Compare {
+			field: field.into(),
+			operator,
+			value: to_core_value(value).map_err(Into::into),
+		})
+	}
+
+	/// Matches records where the field is equal to the value
+	pub fn eq(field: impl Into<String>, value: impl Serialize + 'static) -> Self {
+		Self::compare(field, Operator::Equal, value)
+	}
+
+	/// Matches records where the field is not equal to the value
+	pub fn ne(field: impl Into<String>, value: impl Serialize + 'static) -> Self {
+		Self::compare(field, Operator::NotEqual, value)
+	}
+
+	/// Matches records where the field is greater than the value
+	pub fn gt(field: impl Into<String>, value: impl Serialize + 'static) -> Self {
+		Self::compare(field, Operator::MoreThan, value)
+	}
+
+	/// Matches records where the field is greater than or equal to the value
+	pub fn gte(field: impl Into<String>, value: impl Serialize + 'static) -> Self {
+		Self::compare(field, Operator::MoreThanOrEqual, value)
+	}
+
+	/// Matches records where the field is less than the value
+	pub fn lt(field: impl Into<String>, value: impl Serialize + 'static) -> Self {
+		Self::compare(field, Operator::LessThan, value)
+	}
+
+	/// Matches records where the field is less than or equal to the value
+	pub fn lte(field: impl Into<String>, value: impl Serialize + 'static) -> Self {
+		Self::compare(field, Operator::LessThanOrEqual, value)
+	}
+
+	/// Matches records where the field is one of the given values
+	pub fn inside(field: impl Into<String>, values: impl Serialize + 'static) -> Self {
+		Self::compare(field, Operator::Inside, values)
+	}
+
+	/// Matches records where the field, an array or a string, contains the value
+	pub fn contains(field: impl Into<String>, value: impl Serialize + 'static) -> Self {
+		Self::compare(field, Operator::Contain, value)
+	}
+
+	/// Matches records which satisfy both this condition and the other
+	pub fn and(self, other: Filter) -> Self {
+		Self(Inner::Binary(Box::new(self), Operator::And, Box::new(other)))
+	}
+
+	/// Matches records which satisfy either this condition or the other
+	pub fn or(self, other: Filter) -> Self {
+		Self(Inner::Binary(Box::new(self), Operator::Or, Box::new(other)))
+	}
+
+	/// Builds the condition, adding the value of every comparison to the bindings
+	pub(crate) fn into_cond(self, bindings: &mut CoreObject) -> Result<Cond> {
+		let mut cond = Cond::default();
+		cond.0 = self.into_value(bindings)?;
+		Ok(cond)
+	}
+
+	fn into_value(self, bindings: &mut CoreObject) -> Result<CoreValue> {
+		let expression = match self.0 {
+			Inner::Compare {
+				field,
+				operator,
+				value,
+			} => {
+				let idiom = field_path(&field)?;
+				let name = format!("filter{}", bindings.len());
+				bindings.insert(name.clone(), value?);
+				Expression::new(idiom.into(), operator, Param::from(name).into())
+			}
+			Inner::Binary(left, operator, right) => {
+				let left = left.into_value(bindings)?;
+				Expression::new(left, operator, right.into_value(bindings)?)
+			}
+			Inner::Not(filter) => Expression::Unary {
+				o: Operator::Not,
+				v: filter.into_value(bindings)?,
+			},
+		};
+		Ok(CoreValue::Expression(Box::new(expression)))
+	}
+}
+
+impl Not for Filter {
+	type Output = Filter;
+
+	/// Matches records which do not satisfy this condition
+	fn not(self) -> Self::Output {
+		Self(Inner::Not(Box::new(self)))
+	}
+}
+
+/// A select future which only returns the records matching a [`Filter`], or which fetches
+/// related records
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SelectWhere<'r, C: Connection, R> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) resource: Result<Resource>,
+	pub(super) filter: Option<Filter>,
+	pub(super) fetch: Vec<String>,
+	pub(super) response_type: PhantomData<R>,
+}
+
+impl<C, R> SelectWhere<'_, C, R>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> SelectWhere<'static, C, R> {
+		SelectWhere {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+
+	/// Replaces the record ids in the given fields with the records they point to
+	///
+	/// See [`Select::fetch`](crate::method::Select::fetch).
+	pub fn fetch(mut self, fields: &[&str]) -> Self {
+		self.fetch.extend(fields.iter().map(|field| field.to_string()));
+		self
+	}
+}
+
+/// Parses a field name, only allowing field names and array indexes
+///
+/// Field names can come from user input, so they must not be able to add expressions such as
+/// subqueries or graph traversals to the query.
+fn field_path(field: &str) -> Result<Idiom> {
+	let idiom = syn::idiom(field)?;
+	if idiom.0.is_empty()
+		|| !idiom.iter().all(|part| matches!(part, Part::Field(_) | Part::Index(_)))
+	{
+		return Err(Error::InvalidFieldPath(field.to_owned()).into());
+	}
+	Ok(idiom)
+}
+
+/// Runs the select statement and returns the response holding the matching records
+async fn execute<C: Connection>(
+	client: Cow<'_, Surreal<C>>,
+	resource: Result<Resource>,
+	filter: Option<Filter>,
+	fetch: Vec<String>,
+) -> Result<Response> {
+	let mut bindings = CoreObject::default();
+	let mut stmt = SelectStatement::default();
+	stmt.expr.0 = vec![Field::All];
+	stmt.what = resource_to_values(resource?);
+	stmt.cond = filter.map(|filter| filter.into_cond(&mut bindings)).transpose()?;
+	if !fetch.is_empty() {
+		let mut fetchs = Fetchs::default();
+		for field in fetch {
+			let mut fetch = Fetch::default();
+			fetch.0 = CoreValue::Idiom(field_path(&field)?);
+			fetchs.0.push(fetch);
+		}
+		stmt.fetch = Some(fetchs);
+	}
+	Query::new(client, vec![Statement::Select(stmt)], bindings, false).await
+}
+
+/// Counts the records in a table, only including those which match the filter if there is one
+pub(super) async fn count<C: Connection>(
+	client: &Surreal<C>,
+	table: String,
+	filter: Option<Filter>,
+) -> Result<u64> {
+	let mut bindings = CoreObject::default();
+	let mut stmt = SelectStatement::default();
+	stmt.expr.0 = vec![Field::Single {
+		expr: CoreValue::Function(Box::new(Function::Normal("count".to_owned(), Vec::new()))),
+		alias: Some(Idiom::from("count")),
+	}];
+	stmt.what.0 = vec![CoreValue::Table(Table::from(table))];
+	stmt.cond = filter.map(|filter| filter.into_cond(&mut bindings)).transpose()?;
+	stmt.group = Some(Groups::default());
+	let statements = vec![Statement::Select(stmt)];
+	let mut response = Query::new(Cow::Borrowed(client), statements, bindings, false).await?;
+	// An empty table has no group to count, so nothing is returned
+	let count: Option<u64> = response.take("count")?;
+	Ok(count.unwrap_or_default())
+}
+
+impl<'r, Client> IntoFuture for SelectWhere<'r, Client, Value>
+where
+	Client: Connection,
+{
+	type Output = Result<Value>;
+	type IntoFuture = BoxFuture<'r, Self::Output>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let mut response = execute(self.client, self.resource, self.filter, self.fetch).await?;
+			response.take(0)
+		})
+	}
+}
+
+impl<'r, Client, R> IntoFuture for SelectWhere<'r, Client, Option<R>>
+where
+	Client: Connection,
+	R: DeserializeOwned,
+{
+	type Output = Result<Option<R>>;
+	type IntoFuture = BoxFuture<'r, Self::Output>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let mut response = execute(self.client, self.resource, self.filter, self.fetch).await?;
+			response.take(0)
+		})
+	}
+}
+
+impl<'r, Client, R> IntoFuture for SelectWhere<'r, Client, Vec<R>>
+where
+	Client: Connection,
+	R: DeserializeOwned,
+{
+	type Output = Result<Vec<R>>;
+	type IntoFuture = BoxFuture<'r, Self::Output>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let mut response = execute(self.client, self.resource, self.filter, self.fetch).await?;
+			response.take(0)
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn values_are_bound_as_parameters() {
+		let mut bindings = CoreObject::default();
+		let filter = Filter::eq("name", "'; DELETE person; --")
+			.and(Filter::gt("score", 10).or(!Filter::contains("tags", "admin")));
+		let cond = filter.into_cond(&mut bindings).unwrap();
+		assert_eq!(
+			cond.to_string(),
+			"WHERE name = $filter0 AND (score > $filter1 OR !(tags CONTAINS $filter2))"
+		);
+		assert_eq!(bindings.len(), 3);
+		assert_eq!(bindings["filter0"], CoreValue::from("'; DELETE person; --"));
+		assert_eq!(bindings["filter1"], CoreValue::from(10));
+		assert_eq!(bindings["filter2"], CoreValue::from("admin"));
+	}
+
+	#[test]
+	fn invalid_fields_are_rejected() {
+		let mut bindings = CoreObject::default();
+		let filter = Filter::eq("name = 1 OR true", 1);
+		filter.into_cond(&mut bindings).unwrap_err();
+	}
+
+	#[test]
+	fn only_field_paths_are_allowed() {
+		for field in ["age", "address.city", "emails[0]", "`first name`"] {
+			field_path(field).unwrap();
+		}
+		for field in [
+			"tags[WHERE admin = true]",
+			"->knows->person",
+			"friends[WHERE id IN (SELECT id FROM secret)]",
+			"tags[$index]",
+			"name.uppercase()",
+			"tags[*]",
+		] {
+			let error = field_path(field).unwrap_err();
+			assert!(
+				matches!(error, crate::Error::Api(Error::InvalidFieldPath(..))),
+				"{field}: {error:?}"
+			);
+		}
+	}
+}
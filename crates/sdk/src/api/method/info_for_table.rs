@@ -0,0 +1,179 @@
+use crate::api::err::Error;
+use crate::api::Result;
+use surrealdb_core::sql::Kind;
+use surrealdb_core::sql::Object;
+use surrealdb_core::sql::Value as CoreValue;
+use surrealdb_core::syn;
+
+/// The schema of a table, as returned by [`Surreal::info_for_table`](crate::Surreal::info_for_table)
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct TableInfo {
+	/// The fields defined on the table, ordered by name
+	///
+	/// This is empty for schemaless tables without any field definitions.
+	pub fields: Vec<FieldInfo>,
+	/// The indexes defined on the table, ordered by name
+	pub indexes: Vec<IndexInfo>,
+	/// The events defined on the table, ordered by name
+	pub events: Vec<EventInfo>,
+}
+
+/// A field defined on a table
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct FieldInfo {
+	/// The path of the field, for example `address.city` or `tags[*]`
+	pub name: String,
+	/// The type of the field, or `None` if any value is allowed
+	pub kind: Option<Kind>,
+	/// Whether the field can only be set when the record is created
+	pub readonly: bool,
+	/// Whether the field is a flexible object which accepts undefined nested fields
+	pub flexible: bool,
+}
+
+/// An index defined on a table
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct IndexInfo {
+	/// The name of the index
+	pub name: String,
+	/// The paths of the fields covered by the index
+	pub fields: Vec<String>,
+	/// Whether the index enforces unique values
+	pub unique: bool,
+}
+
+/// An event defined on a table
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct EventInfo {
+	/// The name of the event
+	pub name: String,
+	/// The condition which triggers the event
+	pub when: String,
+	/// The statements run when the event is triggered
+	pub then: Vec<String>,
+}
+
+impl TableInfo {
+	/// Builds the table schema from the result of an `INFO FOR TABLE ... STRUCTURE` statement
+	pub(crate) fn from_info(info: CoreValue) -> Result<Self> {
+		let CoreValue::Object(mut info) = info else {
+			return Err(unexpected("an object", &info));
+		};
+		let mut table = TableInfo::default();
+		for field in definitions(info.remove("fields"))? {
+			table.fields.push(FieldInfo {
+				name: string(&field, "name")?,
+				kind: match field.get("kind") {
+					Some(CoreValue::Strand(kind)) => Some(syn::kind(kind.as_str())?),
+					_ => None,
+				},
+				readonly: field.get("readonly").is_some_and(CoreValue::is_true),
+				flexible: field.get("flex").is_some_and(CoreValue::is_true),
+			});
+		}
+		for index in definitions(info.remove("indexes"))? {
+			table.indexes.push(IndexInfo {
+				name: string(&index, "name")?,
+				fields: split_idioms(&string(&index, "cols")?),
+				unique: string(&index, "index")? == "UNIQUE",
+			});
+		}
+		for mut event in definitions(info.remove("events"))? {
+			table.events.push(EventInfo {
+				name: string(&event, "name")?,
+				when: string(&event, "when")?,
+				then: match event.remove("then") {
+					Some(CoreValue::Array(then)) => {
+						then.into_iter().map(CoreValue::as_raw_string).collect()
+					}
+					_ => Vec::new(),
+				},
+			});
+		}
+		table.fields.sort_by(|a, b| a.name.cmp(&b.name));
+		table.indexes.sort_by(|a, b| a.name.cmp(&b.name));
+		table.events.sort_by(|a, b| a.name.cmp(&b.name));
+		Ok(table)
+	}
+}
+
+/// Returns the definitions in one section of an `INFO FOR TABLE ... STRUCTURE` result
+fn definitions(section: Option<CoreValue>) -> Result<Vec<Object>> {
+	let Some(CoreValue::Array(section)) = section else {
+		return Ok(Vec::new());
+	};
+	section
+		.into_iter()
+		.map(|definition| match definition {
+			CoreValue::Object(definition) => Ok(definition),
+			definition => Err(unexpected("an object", &definition)),
+		})
+		.collect()
+}
+
+/// Returns a string property of a definition
+fn string(definition: &Object, key: &str) -> Result<String> {
+	match definition.get(key) {
+		Some(CoreValue::Strand(value)) => Ok(value.as_str().to_owned()),
+		value => {
+			Err(unexpected(&format!("`{key}` to be a string"), value.unwrap_or(&CoreValue::None)))
+		}
+	}
+}
+
+fn unexpected(expected: &str, found: &CoreValue) -> crate::Error {
+	Error::InternalError(format!(
+		"expected `INFO FOR TABLE` to return {expected} but found `{found}`"
+	))
+	.into()
+}
+
+/// Splits the comma separated field paths of an index
+///
+/// Index fields are local paths, so commas can only be part of a path within an escaped name.
+fn split_idioms(cols: &str) -> Vec<String> {
+	let mut idioms = Vec::new();
+	let mut escape_end = None;
+	let mut escaped = false;
+	let mut start = 0;
+	for (i, c) in cols.char_indices() {
+		match escape_end {
+			Some(_) if escaped => escaped = false,
+			Some(_) if c == '\\' => escaped = true,
+			Some(end) if c == end => escape_end = None,
+			Some(_) => {}
+			None => match c {
+				'`' => escape_end = Some('`'),
+				'⟨' => escape_end = Some('⟩'),
+				',' => {
+					idioms.push(cols[start..i].trim().to_owned());
+					start = i + c.len_utf8();
+				}
+				_ => {}
+			},
+		}
+	}
+	idioms.push(cols[start..].trim().to_owned());
+	idioms.retain(|idiom| !idiom.is_empty());
+	idioms
+}
+
+#[cfg(test)]
+mod tests {
+	use super::split_idioms;
+
+	#[test]
+	fn split_index_fields() {
+		assert_eq!(split_idioms("name"), ["name"]);
+		assert_eq!(
+			split_idioms("name, tags[*], address.city"),
+			["name", "tags[*]", "address.city"]
+		);
+		assert_eq!(split_idioms("⟨full, name⟩, `a, b`, c"), ["⟨full, name⟩", "`a, b`", "c"]);
+		assert_eq!(split_idioms("⟨a\\⟩, b⟩, c"), ["⟨a\\⟩, b⟩", "c"]);
+	}
+}
@@ -9,32 +9,66 @@ use crate::engine::any::Any;
 use crate::method::Live;
 use crate::method::OnceLockExt;
 use crate::method::Query;
+use crate::method::Resume;
 use crate::method::Select;
 use crate::opt::Resource;
+use crate::value::Action;
+use crate::value::Change;
 use crate::value::Notification;
 use crate::Surreal;
 use crate::Value;
 use channel::Receiver;
 use futures::StreamExt;
 use serde::de::DeserializeOwned;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::fmt;
+use std::future::Future;
 use std::future::IntoFuture;
 use std::marker::PhantomData;
+use std::ops::RangeBounds;
 use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+use std::task::ready;
 use std::task::Context;
 use std::task::Poll;
+use std::time::Duration;
 use surrealdb_core::sql::{
-	statements::LiveStatement, Cond, Expression, Field, Fields, Ident, Idiom, Operator, Part,
-	Statement, Table, Value as CoreValue,
+	statements::{LiveStatement, SelectStatement},
+	Cond, Datetime, Expression, Field, Fields, Id as CoreId, Ident, Idiom, Operator, Param, Part,
+	Statement, Table, Value as CoreValue, Values,
 };
 use uuid::Uuid;
 
 #[cfg(not(target_arch = "wasm32"))]
 use tokio::spawn;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::time::Instant;
 
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen_futures::spawn_local as spawn;
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::std::Instant;
 
 const ID: &str = "id";
+const BEFORE: &str = "before";
+const AFTER: &str = "after";
+
+/// The maximum number of change sets replayed when a live query is resumed
+const REPLAY_LIMIT: usize = 1000;
+
+/// How often a resumable stream reads the server time to move its replay position forward
+const CHECKPOINT_INTERVAL: Duration = Duration::from_secs(10);
+
+fn all_fields() -> Fields {
+	let mut fields = Fields::default();
+	fields.0 = vec![Field::All];
+	fields
+}
 
 fn into_future<C, O>(this: Select<C, O, Live>) -> BoxFuture<Result<Stream<O>>>
 where
@@ -45,65 +79,251 @@ where
 		resource,
 		..
 	} = this;
+	Box::pin(async move {
+		let (client, id, rx) = start(client, resource, all_fields()).await?;
+		Ok(Stream::new(client, id, Some(rx)))
+	})
+}
+
+fn into_resume_future<C, O>(this: Select<C, O, Resume>) -> BoxFuture<Result<Stream<O>>>
+where
+	C: Connection,
+{
+	let Select {
+		client,
+		resource,
+		..
+	} = this;
+	Box::pin(async move {
+		let resource = resource?;
+		// Read before the live query starts, so no change is missed when replaying since then
+		let since = server_time(&client).await?;
+		let (client, id, rx) = start(client, Ok(resource.clone()), all_fields()).await?;
+		let mut stream = Stream::new(client, id, Some(rx));
+		stream.resume = Some(Box::new(ResumeState::new(resource, since)));
+		Ok(stream)
+	})
+}
+
+/// Starts a live query which selects `$before` and `$after` for every change
+pub(crate) fn diff_future<C, R>(
+	client: Cow<'_, Surreal<C>>,
+	resource: Result<Resource>,
+) -> BoxFuture<'_, Result<Stream<Change<R>>>>
+where
+	C: Connection,
+{
+	let mut fields = Fields::default();
+	fields.0 = [BEFORE, AFTER]
+		.into_iter()
+		.map(|name| Field::Single {
+			expr: Param::from(name).into(),
+			alias: Some(Idiom::from(name)),
+		})
+		.collect();
+	Box::pin(async move {
+		let (client, id, rx) = start(client, resource, fields).await?;
+		Ok(Stream::new(client, id, Some(rx)))
+	})
+}
+
+/// The records selected when a live query starts, along with its stream
+type Snapshot<R> = (Vec<R>, Stream<Vec<R>>);
+
+/// Starts a live query and then selects the records it matches
+///
+/// The live query is committed before the records are selected, so a change which is not
+/// part of the selected records is delivered by the stream. Notifications which were
+/// received before the selection finished are dropped if the records already include them.
+pub(crate) fn snapshot_future<C, R>(
+	client: Cow<'_, Surreal<C>>,
+	resource: Result<Resource>,
+) -> BoxFuture<'_, Result<Snapshot<R>>>
+where
+	C: Connection,
+	R: DeserializeOwned,
+{
 	Box::pin(async move {
 		let router = client.router.extract()?;
 		if !router.features.contains(&ExtraFeatures::LiveQueries) {
 			return Err(Error::LiveQueriesNotSupported.into());
 		}
-		let mut fields = Fields::default();
-		fields.0 = vec![Field::All];
-		let mut stmt = LiveStatement::new(fields);
-		let mut table = Table::default();
-		match resource? {
-			Resource::Table(table) => {
-				let mut core_table = Table::default();
-				core_table.0 = table;
-				stmt.what = core_table.into()
+		let stmt = live_statement(resource?, all_fields())?;
+		let mut select = SelectStatement::default();
+		select.expr = all_fields();
+		select.what = Values::default();
+		select.what.0 = vec![stmt.what.clone()];
+		select.cond = stmt.cond.clone();
+		let provisional = *stmt.id;
+		let rx = register(router, provisional).await?;
+		let query =
+			Query::new(client.clone(), vec![Statement::Live(stmt)], Default::default(), false);
+		let result = async {
+			match query.await?.take::<Value>(0)?.into_inner() {
+				CoreValue::Uuid(id) => Ok(*id),
+				_ => Err(Error::InternalError(
+					"successful live query didn't return a uuid".to_string(),
+				)
+				.into()),
 			}
-			Resource::RecordId(record) => {
-				let record = record.into_inner();
-				table.0.clone_from(&record.tb);
-				stmt.what = table.into();
-				let mut ident = Ident::default();
-				ID.clone_into(&mut ident.0);
-				let mut idiom = Idiom::default();
-				idiom.0 = vec![Part::from(ident)];
-				let mut cond = Cond::default();
-				cond.0 = surrealdb_core::sql::Value::Expression(Box::new(Expression::new(
-					idiom.into(),
-					Operator::Equal,
-					record.into(),
-				)));
-				stmt.cond = Some(cond);
+		}
+		.await;
+		let id = match result {
+			Ok(id) => id,
+			Err(error) => {
+				router.forget_live_query(provisional);
+				return Err(error);
 			}
-			Resource::Object(_) => return Err(Error::LiveOnObject.into()),
-			Resource::Array(_) => return Err(Error::LiveOnArray.into()),
-			Resource::Edge(_) => return Err(Error::LiveOnEdges.into()),
-			Resource::Range(range) => {
-				let range = range.into_inner();
-				table.0.clone_from(&range.tb);
-				stmt.what = table.into();
-				stmt.cond = range.to_cond();
+		};
+		// Remote engines assign their own id to the live query. Their router holds any
+		// notifications which arrive before the live query is registered under that id.
+		let rx = if id == provisional {
+			rx
+		} else {
+			router.forget_live_query(provisional);
+			register(router, id).await?
+		};
+		let client = Surreal::new_from_router_waiter(client.router.clone(), client.waiter.clone());
+		let query = Query::new(
+			Cow::Borrowed(&client),
+			vec![Statement::Select(select)],
+			Default::default(),
+			false,
+		);
+		let result = async { query.await?.take::<Value>(0) }.await;
+		let snapshot = match result {
+			Ok(snapshot) => snapshot.into_inner(),
+			Err(error) => {
+				kill(&client, id);
+				return Err(error);
 			}
-			Resource::Unspecified => return Err(Error::LiveOnUnspecified.into()),
-		}
-		let query =
-			Query::new(client.clone(), vec![Statement::Live(stmt)], Default::default(), false);
-		let CoreValue::Uuid(id) = query.await?.take::<Value>(0)?.into_inner() else {
-			return Err(Error::InternalError(
-				"successufull live query didn't return a uuid".to_string(),
-			)
-			.into());
 		};
-		let rx = register(router, *id).await?;
-		Ok(Stream::new(
-			Surreal::new_from_router_waiter(client.router.clone(), client.waiter.clone()),
-			*id,
-			Some(rx),
-		))
+		let mut received = Vec::new();
+		while let Ok(notification) = rx.try_recv() {
+			received.push(notification);
+		}
+		let mut stream = Stream::new(client, id, Some(rx));
+		stream.queued.extend(deduplicate(&snapshot, received));
+		let snapshot = crate::value::from_value(Value::from_inner(snapshot))?;
+		Ok((snapshot, stream))
 	})
 }
 
+/// Drops the notifications whose changes are already part of the selected records
+///
+/// For each record, the last notification which matches its selected state and every
+/// notification before it are dropped.
+fn deduplicate(
+	snapshot: &CoreValue,
+	notifications: Vec<Notification<CoreValue>>,
+) -> Vec<Notification<CoreValue>> {
+	#[allow(clippy::mutable_key_type)]
+	let records: HashMap<_, _> = match snapshot {
+		CoreValue::Array(array) => {
+			array.iter().map(|record| (record.pick(&[Part::from(ID)]), record)).collect()
+		}
+		_ => HashMap::new(),
+	};
+	#[allow(clippy::mutable_key_type)]
+	let mut included = HashMap::new();
+	for (index, notification) in notifications.iter().enumerate() {
+		let id = notification.data.pick(&[Part::from(ID)]);
+		let matches = match notification.action {
+			Action::Create | Action::Update => records.get(&id) == Some(&&notification.data),
+			Action::Delete => !records.contains_key(&id),
+			_ => false,
+		};
+		if matches {
+			included.insert(id, index);
+		}
+	}
+	notifications
+		.into_iter()
+		.enumerate()
+		.filter(|(index, notification)| {
+			let id = notification.data.pick(&[Part::from(ID)]);
+			included.get(&id).map_or(true, |last| index > last)
+		})
+		.map(|(_, notification)| notification)
+		.collect()
+}
+
+async fn start<C>(
+	client: Cow<'_, Surreal<C>>,
+	resource: Result<Resource>,
+	fields: Fields,
+) -> Result<(Surreal<Any>, Uuid, Receiver<Notification<CoreValue>>)>
+where
+	C: Connection,
+{
+	let router = client.router.extract()?;
+	if !router.features.contains(&ExtraFeatures::LiveQueries) {
+		return Err(Error::LiveQueriesNotSupported.into());
+	}
+	let stmt = live_statement(resource?, fields)?;
+	let query = Query::new(client.clone(), vec![Statement::Live(stmt)], Default::default(), false);
+	let CoreValue::Uuid(id) = query.await?.take::<Value>(0)?.into_inner() else {
+		return Err(Error::InternalError(
+			"successufull live query didn't return a uuid".to_string(),
+		)
+		.into());
+	};
+	let rx = register(router, *id).await?;
+	let client = Surreal::new_from_router_waiter(client.router.clone(), client.waiter.clone());
+	Ok((client, *id, rx))
+}
+
+/// Reads the current time on the server, which change feed positions are based on
+async fn server_time<C>(client: &Surreal<C>) -> Result<Datetime>
+where
+	C: Connection,
+{
+	match client.query("RETURN time::now()").await?.take::<Value>(0)?.into_inner() {
+		CoreValue::Datetime(time) => Ok(time),
+		_ => Err(Error::InternalError("time::now() didn't return a datetime".to_owned()).into()),
+	}
+}
+
+/// Builds a live query which selects the given fields of a resource
+fn live_statement(resource: Resource, fields: Fields) -> Result<LiveStatement> {
+	let mut stmt = LiveStatement::new(fields);
+	let mut table = Table::default();
+	match resource {
+		Resource::Table(table) => {
+			let mut core_table = Table::default();
+			core_table.0 = table;
+			stmt.what = core_table.into()
+		}
+		Resource::RecordId(record) => {
+			let record = record.into_inner();
+			table.0.clone_from(&record.tb);
+			stmt.what = table.into();
+			let mut ident = Ident::default();
+			ID.clone_into(&mut ident.0);
+			let mut idiom = Idiom::default();
+			idiom.0 = vec![Part::from(ident)];
+			let mut cond = Cond::default();
+			cond.0 = surrealdb_core::sql::Value::Expression(Box::new(Expression::new(
+				idiom.into(),
+				Operator::Equal,
+				record.into(),
+			)));
+			stmt.cond = Some(cond);
+		}
+		Resource::Object(_) => return Err(Error::LiveOnObject.into()),
+		Resource::Array(_) => return Err(Error::LiveOnArray.into()),
+		Resource::Edge(_) => return Err(Error::LiveOnEdges.into()),
+		Resource::Range(range) => {
+			let range = range.into_inner();
+			table.0.clone_from(&range.tb);
+			stmt.what = table.into();
+			stmt.cond = range.to_cond();
+		}
+		Resource::Unspecified => return Err(Error::LiveOnUnspecified.into()),
+	}
+	Ok(stmt)
+}
+
 pub(crate) async fn register(
 	router: &Router,
 	id: Uuid,
@@ -156,6 +376,44 @@ where
 	}
 }
 
+impl<'r, Client> IntoFuture for Select<'r, Client, Value, Resume>
+where
+	Client: Connection,
+{
+	type Output = Result<Stream<Value>>;
+	type IntoFuture = BoxFuture<'r, Self::Output>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		into_resume_future(self)
+	}
+}
+
+impl<'r, Client, R> IntoFuture for Select<'r, Client, Option<R>, Resume>
+where
+	Client: Connection,
+	R: DeserializeOwned,
+{
+	type Output = Result<Stream<Option<R>>>;
+	type IntoFuture = BoxFuture<'r, Self::Output>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		into_resume_future(self)
+	}
+}
+
+impl<'r, Client, R> IntoFuture for Select<'r, Client, Vec<R>, Resume>
+where
+	Client: Connection,
+	R: DeserializeOwned,
+{
+	type Output = Result<Stream<Vec<R>>>;
+	type IntoFuture = BoxFuture<'r, Self::Output>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		into_resume_future(self)
+	}
+}
+
 /// A stream of live query notifications
 #[derive(Debug)]
 #[must_use = "streams do nothing unless you poll them"]
@@ -163,9 +421,92 @@ pub struct Stream<R> {
 	pub(crate) client: Surreal<Any>,
 	// We no longer need the lifetime and the type parameter
 	// Leaving them in for backwards compatibility
-	pub(crate) id: Uuid,
+	pub(crate) state: Arc<Mutex<LiveState>>,
 	pub(crate) rx: Option<Pin<Box<Receiver<Notification<CoreValue>>>>>,
+	// Notifications which are delivered before any are read from `rx`
+	pub(crate) queued: VecDeque<Notification<CoreValue>>,
 	pub(crate) response_type: PhantomData<R>,
+	pub(crate) resume: Option<Box<ResumeState>>,
+}
+
+/// The state of a live query which is shared between its stream and handles
+#[derive(Debug)]
+pub(crate) struct LiveState {
+	// The live query currently feeding the stream, which changes when it is resumed
+	id: Uuid,
+	killed: bool,
+}
+
+/// The state needed to resume a live query whose subscription was lost
+pub(crate) struct ResumeState {
+	resource: Resource,
+	// The server time since which missed changes are replayed
+	since: Datetime,
+	// The last server time read while the stream was drained, which becomes `since` once the
+	// next one is read, giving notifications sent before it time to arrive
+	checkpoint: Option<Datetime>,
+	checkpointed_at: Instant,
+	checkpointing: Option<BoxFuture<'static, Result<Datetime>>>,
+	resuming: Option<BoxFuture<'static, Result<Resumed>>>,
+}
+
+impl ResumeState {
+	fn new(resource: Resource, since: Datetime) -> Self {
+		Self {
+			resource,
+			since,
+			checkpoint: None,
+			checkpointed_at: Instant::now(),
+			checkpointing: None,
+			resuming: None,
+		}
+	}
+
+	/// Moves the replay position forward while the stream has no notifications waiting
+	fn poll_checkpoint(&mut self, client: &Surreal<Any>, cx: &mut Context<'_>) {
+		if self.checkpointing.is_none() {
+			if self.checkpointed_at.elapsed() < CHECKPOINT_INTERVAL {
+				return;
+			}
+			let client = client.clone();
+			self.checkpointing = Some(Box::pin(async move { server_time(&client).await }));
+		}
+		let Some(checkpointing) = self.checkpointing.as_mut() else {
+			return;
+		};
+		let Poll::Ready(result) = checkpointing.as_mut().poll(cx) else {
+			return;
+		};
+		self.checkpointing = None;
+		self.checkpointed_at = Instant::now();
+		match result {
+			Ok(time) => {
+				if let Some(checkpoint) = self.checkpoint.replace(time) {
+					self.since = checkpoint;
+				}
+			}
+			Err(error) => warn!("Failed to read the server time for a live query; {error}"),
+		}
+	}
+}
+
+impl fmt::Debug for ResumeState {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ResumeState")
+			.field("resource", &self.resource)
+			.field("since", &self.since)
+			.field("checkpoint", &self.checkpoint)
+			.field("resuming", &self.resuming.is_some())
+			.finish()
+	}
+}
+
+/// A live query which was started again, along with the notifications it missed
+pub(crate) struct Resumed {
+	id: Uuid,
+	rx: Receiver<Notification<CoreValue>>,
+	replayed: Vec<Notification<CoreValue>>,
+	since: Datetime,
 }
 
 impl<R> Stream<R> {
@@ -175,21 +516,277 @@ impl<R> Stream<R> {
 		rx: Option<Receiver<Notification<CoreValue>>>,
 	) -> Self {
 		Self {
-			id,
+			state: Arc::new(Mutex::new(LiveState {
+				id,
+				killed: false,
+			})),
 			rx: rx.map(Box::pin),
+			queued: VecDeque::new(),
 			client,
 			response_type: PhantomData,
+			resume: None,
 		}
 	}
-}
 
-macro_rules! poll_next {
-	($notification:ident => $body:expr) => {
-		fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-			let Some(ref mut rx) = self.as_mut().rx else {
+	fn lock_state(&self) -> MutexGuard<'_, LiveState> {
+		lock_state(&self.state)
+	}
+
+	/// The ID of the live query currently feeding this stream
+	pub(crate) fn id(&self) -> Uuid {
+		self.lock_state().id
+	}
+
+	/// Returns a handle which can be used to kill this live query
+	///
+	/// Killing the live query through the handle does not drop the stream. Any notifications
+	/// which were already received can still be drained from the stream, which then ends.
+	pub fn handle(&self) -> LiveHandle {
+		LiveHandle {
+			client: self.client.clone(),
+			state: self.state.clone(),
+		}
+	}
+
+	fn poll_notification(&mut self, cx: &mut Context<'_>) -> Poll<Option<Notification<CoreValue>>> {
+		loop {
+			if let Some(notification) = self.queued.pop_front() {
+				return Poll::Ready(Some(notification));
+			}
+			if let Some(resume) = self.resume.as_deref_mut() {
+				if let Some(resuming) = resume.resuming.as_mut() {
+					let result = ready!(resuming.as_mut().poll(cx));
+					resume.resuming = None;
+					match result {
+						Ok(resumed) => {
+							let mut state = lock_state(&self.state);
+							if state.killed {
+								// The handle killed the previous live query while this one was starting
+								drop(state);
+								kill(&self.client, resumed.id);
+								self.rx = None;
+								return Poll::Ready(None);
+							}
+							state.id = resumed.id;
+							drop(state);
+							self.rx = Some(Box::pin(resumed.rx));
+							self.queued.extend(resumed.replayed);
+							resume.since = resumed.since;
+							resume.checkpoint = None;
+							resume.checkpointed_at = Instant::now();
+						}
+						Err(error) => {
+							warn!("Failed to resume live query {}; {error}", self.id());
+							self.rx = None;
+							return Poll::Ready(None);
+						}
+					}
+					continue;
+				}
+			}
+			let Some(ref mut rx) = self.rx else {
 				return Poll::Ready(None);
 			};
 			match rx.poll_next_unpin(cx) {
+				Poll::Ready(Some(notification)) => return Poll::Ready(Some(notification)),
+				Poll::Ready(None) => {
+					// The subscription is gone, for example because the connection was lost
+					let LiveState {
+						id,
+						killed,
+					} = *self.lock_state();
+					if let Ok(router) = self.client.router.extract() {
+						router.forget_live_query(id);
+					}
+					match self.resume.as_deref_mut() {
+						Some(resume) if !killed => {
+							resume.checkpointing = None;
+							resume.resuming = Some(Box::pin(resubscribe(
+								self.client.clone(),
+								resume.resource.clone(),
+								resume.since.clone(),
+							)));
+						}
+						_ => return Poll::Ready(None),
+					}
+				}
+				Poll::Pending => {
+					if let Some(resume) = self.resume.as_deref_mut() {
+						resume.poll_checkpoint(&self.client, cx);
+					}
+					return Poll::Pending;
+				}
+			}
+		}
+	}
+}
+
+/// Starts a live query again and replays the changes made since `since`
+async fn resubscribe(client: Surreal<Any>, resource: Resource, since: Datetime) -> Result<Resumed> {
+	// Changes after this are either replayed or delivered to the new subscription
+	let next = server_time(&client).await?;
+	let (client, id, rx) = start(Cow::Owned(client), Ok(resource.clone()), all_fields()).await?;
+	// The new subscription is already running, so nothing is missed while replaying
+	let replayed = match replay(&client, id, &resource, &since).await {
+		Ok(replayed) => replayed,
+		Err(error) => {
+			warn!("Failed to replay changes for live query {id}; {error}");
+			vec![gap(id)]
+		}
+	};
+	Ok(Resumed {
+		id,
+		rx,
+		replayed,
+		since: next,
+	})
+}
+
+/// Reads the changes made to the resource since `since` from the table's change feed
+async fn replay(
+	client: &Surreal<Any>,
+	id: Uuid,
+	resource: &Resource,
+	since: &Datetime,
+) -> Result<Vec<Notification<CoreValue>>> {
+	let table = match resource {
+		Resource::Table(table) => Table::from(table.as_str()),
+		Resource::RecordId(record) => Table::from(record.into_inner_ref().tb.as_str()),
+		Resource::Range(range) => Table::from(range.into_inner_ref().tb.as_str()),
+		_ => unreachable!("live queries are only started on tables, records and ranges"),
+	};
+	// The change feed starts from the last versionstamp it recorded at or before this time
+	let sql = format!("SHOW CHANGES FOR TABLE {table} SINCE {since} LIMIT {REPLAY_LIMIT}");
+	let change_sets: Vec<CoreValue> = match client.query(sql).await?.take::<Value>(0)?.into_inner()
+	{
+		CoreValue::Array(array) => array.0,
+		_ => Vec::new(),
+	};
+	let truncated = change_sets.len() >= REPLAY_LIMIT;
+	let mut notifications = Vec::new();
+	for change_set in change_sets {
+		let CoreValue::Array(changes) = change_set.pick(&[Part::from("changes")]) else {
+			continue;
+		};
+		for change in changes {
+			let CoreValue::Object(mut change) = change else {
+				continue;
+			};
+			let (action, data) = if let Some(data) = change.remove("create") {
+				(Action::Create, data)
+			} else if let Some(data) = change.remove("current") {
+				(Action::Update, data)
+			} else if let Some(data) = change.remove("update") {
+				(Action::Update, data)
+			} else if let Some(data) = change.remove("delete") {
+				(Action::Delete, data)
+			} else {
+				continue;
+			};
+			if in_resource(resource, &data) {
+				notifications.push(Notification {
+					query_id: id,
+					action,
+					data,
+				});
+			}
+		}
+	}
+	if truncated {
+		notifications.push(gap(id));
+	}
+	Ok(notifications)
+}
+
+fn in_resource(resource: &Resource, data: &CoreValue) -> bool {
+	let CoreValue::Thing(thing) = data.pick(&[Part::from(ID)]) else {
+		return false;
+	};
+	match resource {
+		Resource::RecordId(record) => *record.into_inner_ref() == thing,
+		Resource::Range(range) => match &range.into_inner_ref().id {
+			CoreId::Range(range) => (range.beg.as_ref(), range.end.as_ref()).contains(&thing.id),
+			_ => true,
+		},
+		_ => true,
+	}
+}
+
+fn gap(id: Uuid) -> Notification<CoreValue> {
+	Notification {
+		query_id: id,
+		action: Action::Gap,
+		data: CoreValue::None,
+	}
+}
+
+/// A handle to a running live query
+///
+/// This can be obtained from a live query [`Stream`] using [`Stream::handle`].
+#[derive(Debug, Clone)]
+pub struct LiveHandle {
+	client: Surreal<Any>,
+	state: Arc<Mutex<LiveState>>,
+}
+
+impl LiveHandle {
+	/// The ID of the live query
+	///
+	/// A resumable stream starts a new live query when it resumes, so the ID can change over
+	/// the lifetime of the stream.
+	pub fn id(&self) -> Uuid {
+		lock_state(&self.state).id
+	}
+
+	/// Kills the live query
+	///
+	/// The future resolves once the database has acknowledged the kill. After that, the
+	/// associated stream ends as soon as any buffered notifications have been consumed.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # use futures::StreamExt;
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// let mut stream = db.select("person").live().await?;
+	/// let handle = stream.handle();
+	/// handle.kill().await?;
+	/// while let Some(notification) = stream.next().await {
+	///     println!("{notification:?}");
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn kill(&self) -> impl Future<Output = Result<()>> {
+		let client = self.client.clone();
+		let uuid = {
+			let mut state = lock_state(&self.state);
+			// Stops a resumable stream from starting the live query again
+			state.killed = true;
+			state.id
+		};
+		async move {
+			let router = client.router.extract()?;
+			router
+				.execute_unit(Command::Kill {
+					uuid,
+				})
+				.await
+		}
+	}
+}
+
+fn lock_state(state: &Mutex<LiveState>) -> MutexGuard<'_, LiveState> {
+	// The state is never left half updated, so it is still valid if a thread panicked
+	state.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+macro_rules! poll_next {
+	($notification:ident => $body:expr) => {
+		fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+			match self.get_mut().poll_notification(cx) {
 				Poll::Ready(Some($notification)) => $body,
 				Poll::Ready(None) => Poll::Ready(None),
 				Poll::Pending => Poll::Pending,
@@ -216,9 +813,12 @@ impl futures::Stream for Stream<Value> {
 macro_rules! poll_next_and_convert {
 	() => {
 		poll_next! {
-			notification => match notification.map_deserialize(){
-				Ok(data) => Poll::Ready(Some(Ok(data))),
-				Err(error) => Poll::Ready(Some(Err(error.into()))),
+			notification => match notification.action {
+				Action::Gap => Poll::Ready(Some(Err(Error::LiveQueryGap(notification.query_id).into()))),
+				_ => match notification.map_deserialize(){
+					Ok(data) => Poll::Ready(Some(Ok(data))),
+					Err(error) => Poll::Ready(Some(Err(error.into()))),
+				},
 			}
 		}
 	};
@@ -251,6 +851,33 @@ where
 	poll_next_and_convert! {}
 }
 
+impl<R> futures::Stream for Stream<Change<R>>
+where
+	R: DeserializeOwned + Unpin,
+{
+	type Item = Result<Notification<Change<R>>>;
+
+	poll_next! {
+		notification => {
+			// Deletions are always sent as the full record that was deleted,
+			// regardless of the fields selected by the live query
+			let result = match notification.action {
+				Action::Gap => return Poll::Ready(Some(Err(Error::LiveQueryGap(notification.query_id).into()))),
+				Action::Delete => notification.map_deserialize::<R>().map(|notification| Notification {
+					query_id: notification.query_id,
+					action: notification.action,
+					data: Change {
+						before: Some(notification.data),
+						after: None,
+					},
+				}),
+				_ => notification.map_deserialize(),
+			};
+			Poll::Ready(Some(result.map_err(Into::into)))
+		}
+	}
+}
+
 pub(crate) fn kill<Client>(client: &Surreal<Client>, uuid: Uuid)
 where
 	Client: Connection,
@@ -274,7 +901,120 @@ impl<R> Drop for Stream<R> {
 	/// This kills the live query process responsible for this stream.
 	fn drop(&mut self) {
 		if self.rx.is_some() {
-			kill(&self.client, self.id);
+			kill(&self.client, self.id());
 		}
 	}
 }
+
+#[cfg(all(test, feature = "kv-mem"))]
+mod tests {
+	use super::*;
+	use crate::engine::any::connect;
+	use crate::opt::Config;
+	use crate::RecordId;
+	use std::time::Duration;
+
+	#[derive(Debug, serde::Deserialize)]
+	struct Person {
+		id: RecordId,
+	}
+
+	#[tokio::test]
+	async fn resume_replays_missed_notifications() {
+		let config = Config::new().changefeed_gc_interval(Duration::from_millis(100));
+		let db = connect(("memory", config)).await.unwrap();
+		db.use_ns("test").use_db("test").await.unwrap();
+		db.query("DEFINE TABLE person CHANGEFEED 1h").await.unwrap().check().unwrap();
+		// Wait for the change feed to map the current time to a versionstamp
+		tokio::time::sleep(Duration::from_millis(1500)).await;
+		let mut stream = db.select("person").live().with_resume().await.unwrap();
+		let first = stream.id();
+		let _: Option<Person> = db.create(("person", "a")).await.unwrap();
+		let notification: Notification<Person> = stream.next().await.unwrap().unwrap();
+		assert_eq!(notification.data.id, RecordId::from(("person", "a")));
+		// Drop the subscription without killing the stream, as happens when the connection drops
+		let router = stream.client.router.extract().unwrap();
+		router
+			.execute_unit(Command::Kill {
+				uuid: first,
+			})
+			.await
+			.unwrap();
+		let _: Option<Person> = db.create(("person", "b")).await.unwrap();
+		// Changes made since the replay position was last read may be delivered again
+		let replayed = loop {
+			let notification: Notification<Person> = stream.next().await.unwrap().unwrap();
+			if notification.data.id == RecordId::from(("person", "b")) {
+				break notification;
+			}
+		};
+		assert_ne!(stream.id(), first);
+		assert_eq!(replayed.query_id, stream.id());
+		// The resumed live query keeps delivering new notifications
+		let _: Option<Person> = db.create(("person", "c")).await.unwrap();
+		let notification = loop {
+			let notification: Notification<Person> = stream.next().await.unwrap().unwrap();
+			if notification.data.id != RecordId::from(("person", "b")) {
+				break notification;
+			}
+		};
+		assert_eq!(notification.data.id, RecordId::from(("person", "c")));
+	}
+
+	#[tokio::test]
+	async fn handle_kills_resumed_live_query() {
+		let db = connect("memory").await.unwrap();
+		db.use_ns("test").use_db("test").await.unwrap();
+		// Without a change feed the missed changes can't be replayed, so resuming reports a gap
+		db.query("DEFINE TABLE person").await.unwrap().check().unwrap();
+		let mut stream: Stream<Vec<Person>> =
+			db.select("person").live().with_resume().await.unwrap();
+		let handle = stream.handle();
+		let first = handle.id();
+		// Drop the subscription without killing the stream, as happens when the connection drops
+		let router = stream.client.router.extract().unwrap();
+		router
+			.execute_unit(Command::Kill {
+				uuid: first,
+			})
+			.await
+			.unwrap();
+		let error = stream.next().await.unwrap().unwrap_err();
+		assert!(matches!(error, crate::Error::Api(Error::LiveQueryGap(..))), "{error:?}");
+		assert_ne!(handle.id(), first);
+		assert_eq!(handle.id(), stream.id());
+		// Killing through the handle reaches the resumed live query, which ends the stream
+		handle.kill().await.unwrap();
+		let _: Option<Person> = db.create(("person", "a")).await.unwrap();
+		assert!(stream.next().await.is_none());
+	}
+
+	#[test]
+	fn deduplicate_drops_notifications_included_in_the_snapshot() {
+		let value = |sql: &str| surrealdb_core::syn::value(sql).unwrap();
+		let notification = |action, data: &str| Notification {
+			query_id: Uuid::nil(),
+			action,
+			data: value(data),
+		};
+		let snapshot = value("[{ id: person:a, value: 2 }]");
+		let notifications = vec![
+			notification(Action::Create, "{ id: person:a, value: 1 }"),
+			notification(Action::Update, "{ id: person:a, value: 2 }"),
+			notification(Action::Create, "{ id: person:b, value: 1 }"),
+			notification(Action::Delete, "{ id: person:c, value: 1 }"),
+			notification(Action::Update, "{ id: person:a, value: 3 }"),
+		];
+		let kept: Vec<_> = deduplicate(&snapshot, notifications)
+			.into_iter()
+			.map(|notification| (notification.action, notification.data))
+			.collect();
+		assert_eq!(
+			kept,
+			vec![
+				(Action::Create, value("{ id: person:b, value: 1 }")),
+				(Action::Update, value("{ id: person:a, value: 3 }")),
+			]
+		);
+	}
+}
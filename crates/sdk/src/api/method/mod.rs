@@ -1,44 +1,72 @@
 //! Methods to use when interacting with a SurrealDB instance
 use self::query::ValidQuery;
+use crate::api::conn::Command;
+use crate::api::err::Error;
 use crate::api::opt;
 use crate::api::opt::auth;
 use crate::api::opt::auth::Credentials;
 use crate::api::opt::auth::Jwt;
 use crate::api::opt::IntoEndpoint;
+use crate::api::opt::PatchOp;
+use crate::api::opt::Resource;
 use crate::api::Connect;
 use crate::api::Connection;
 use crate::api::OnceLockExt;
+use crate::api::Response;
+use crate::api::Result;
 use crate::api::Surreal;
 use crate::opt::IntoExportDestination;
+use crate::opt::KeyRange;
 use crate::opt::WaitFor;
+use crate::RecordId;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::borrow::Cow;
 use std::marker::PhantomData;
+use std::ops::Bound;
 use std::path::Path;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::sync::OnceLock;
 use std::time::Duration;
+use surrealdb_core::sql;
+use surrealdb_core::sql::from_value as from_core_value;
+use surrealdb_core::sql::statements::SelectStatement;
 use surrealdb_core::sql::to_value as to_core_value;
+use surrealdb_core::sql::Field;
+use surrealdb_core::sql::IdRange;
+use surrealdb_core::sql::Ident;
+use surrealdb_core::sql::Kind;
+use surrealdb_core::sql::Limit;
+use surrealdb_core::sql::Statement;
+use surrealdb_core::sql::Table;
+use surrealdb_core::sql::Thing;
+use surrealdb_core::sql::Value as CoreValue;
 
 pub(crate) mod live;
 pub(crate) mod query;
 
 mod authenticate;
 mod begin;
+mod buffered_transaction;
 mod cancel;
 mod commit;
 mod content;
 mod create;
+mod define;
 mod delete;
 mod export;
+mod filter;
 mod health;
 mod import;
+mod info_for_table;
 mod insert;
 mod insert_relation;
 mod invalidate;
 mod merge;
 mod patch;
+mod relate;
+mod return_none;
 mod run;
 mod select;
 mod set;
@@ -59,42 +87,68 @@ pub use authenticate::Authenticate;
 pub use begin::Begin;
 #[doc(hidden)] // Not supported yet
 pub use begin::Transaction;
+pub use buffered_transaction::BufferedTransaction;
 #[doc(hidden)] // Not supported yet
 pub use cancel::Cancel;
 #[doc(hidden)] // Not supported yet
 pub use commit::Commit;
 pub use content::Content;
 pub use create::Create;
+pub use define::{DefineField, DefineTable};
 pub use delete::Delete;
 pub use export::{Backup, Export};
+pub use filter::{Filter, SelectWhere};
+use futures::future;
+use futures::stream;
 use futures::Future;
+use futures::StreamExt as _;
 pub use health::Health;
 pub use import::Import;
+pub use info_for_table::{EventInfo, FieldInfo, IndexInfo, TableInfo};
 pub use insert::Insert;
 pub use invalidate::Invalidate;
+pub use live::LiveHandle;
 pub use live::Stream;
 pub use merge::Merge;
 pub use patch::Patch;
+pub use query::FromResponse;
 pub use query::Query;
 pub use query::QueryStream;
+pub use return_none::ReturnNone;
 pub use run::IntoFn;
 pub use run::Run;
 pub use select::Select;
 use serde_content::Serializer;
 pub use set::Set;
 pub use signin::Signin;
+pub use signin::SigninWithInfo;
 pub use signup::Signup;
+use std::future::IntoFuture;
 use tokio::sync::watch;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::time::sleep;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::time::Instant;
 pub use unset::Unset;
 pub use update::Update;
 pub use upsert::Upsert;
 pub use use_db::UseDb;
 pub use use_ns::UseNs;
 pub use version::Version;
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::std::Instant;
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::tokio::sleep;
 
 use super::opt::CreateResource;
 use super::opt::IntoResource;
 
+/// The delay before the first retry of a conflicting transaction
+const TRANSACTION_RETRY_BACKOFF: Duration = Duration::from_millis(10);
+
+/// The maximum delay between retries of a conflicting transaction
+const TRANSACTION_RETRY_MAX_BACKOFF: Duration = Duration::from_secs(1);
+
 /// A alias for an often used type of future returned by async methods in this library.
 pub(crate) type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + Sync + 'a>>;
 
@@ -106,6 +160,16 @@ pub struct Stats {
 	pub execution_time: Option<Duration>,
 }
 
+/// A page of records returned by [`Surreal::select_paginated`]
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Page<T> {
+	/// The records on this page, ordered by their ID
+	pub rows: Vec<T>,
+	/// The cursor to pass to fetch the next page, or `None` if this is the last page
+	pub next: Option<RecordId>,
+}
+
 /// Machine learning model marker type for import and export types
 pub struct Model;
 
@@ -115,6 +179,9 @@ pub struct ExportConfig;
 /// Live query marker type
 pub struct Live;
 
+/// Resumable live query marker type
+pub struct Resume;
+
 /// Responses returned with statistics
 #[derive(Debug)]
 pub struct WithStats<T>(pub T);
@@ -254,12 +321,51 @@ where
 	}
 
 	#[doc(hidden)] // Not supported yet
+	#[deprecated(
+		note = "the server cancels a transaction at the end of each request, so `BEGIN` can't span several requests; use `buffered_transaction` instead"
+	)]
 	pub fn transaction(self) -> Begin<C> {
 		Begin {
 			client: self,
 		}
 	}
 
+	/// Starts a transaction whose statements are sent to the database in a single request
+	///
+	/// The statements are held by the client until the transaction is awaited, and are then
+	/// sent wrapped in `BEGIN` and `COMMIT`, so the transaction is applied atomically by both
+	/// the embedded and the remote engines. Nothing is sent if the transaction is dropped.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("ws://localhost:8000").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// // Move funds between two accounts
+	/// let mut response = db
+	///     .buffered_transaction()
+	///     .query("UPDATE account:one SET balance -= $amount")
+	///     .query("UPDATE account:two SET balance += $amount")
+	///     .bind(("amount", 300))
+	///     .await?;
+	///
+	/// // Check for any errors, which roll back the whole transaction
+	/// response.take_errors();
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn buffered_transaction(&self) -> BufferedTransaction<C> {
+		BufferedTransaction {
+			query: Query::new(Cow::Borrowed(self), Vec::new(), Default::default(), false),
+		}
+	}
+
 	/// Switch to a specific namespace
 	///
 	/// # Examples
@@ -299,6 +405,28 @@ where
 		}
 	}
 
+	/// Switch to a specific namespace and database in a single request
+	///
+	/// This is the same as `db.use_ns(ns).use_db(db)`, which also sends both in one request.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// db.use_ns_db("namespace", "database").await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn use_ns_db(
+		&self,
+		ns: impl Into<String>,
+		db: impl Into<String>,
+	) -> impl Future<Output = Result<()>> + Send + '_ {
+		self.use_ns(ns).use_db(db).into_future()
+	}
+
 	/// Assigns a value as a parameter for this connection
 	///
 	/// # Examples
@@ -647,6 +775,7 @@ where
 			query: x,
 			bindings: Default::default(),
 			register_live_queries: true,
+			session: None,
 		});
 
 		Query {
@@ -654,6 +783,283 @@ where
 		}
 	}
 
+	/// Runs a set of SurrealQL statements with parameters loaded from a file
+	///
+	/// The file must contain a single SurrealQL object literal. Each of its top-level
+	/// fields is bound as a parameter of the same name. This is a shorthand for
+	/// [`Query::bind_vars_file`].
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// // `vars.surql` contains `{ table: 'person', name: 'John' }`
+	/// let mut result = db
+	///     .query_with_vars_file("SELECT * FROM type::table($table) WHERE name = $name", "vars.surql")
+	///     .await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn query_with_vars_file(
+		&self,
+		query: impl opt::IntoQuery,
+		path: impl AsRef<Path>,
+	) -> Query<C> {
+		self.query(query).bind_vars_file(path)
+	}
+
+	/// Runs a set of SurrealQL statements and returns the response exactly as it was received
+	///
+	/// The returned bytes are the whole response frame sent by the server, so they can be
+	/// forwarded to another client without being decoded and encoded again. Over WebSocket
+	/// connections the frame uses the revisioned binary format, over HTTP connections it uses the
+	/// `application/surrealdb` bincode format. In both cases it decodes to a response object with
+	/// an `id` and either the query results or the error returned by the server. Server errors are
+	/// therefore part of the frame and are not returned as an [`Error`](crate::Error). Only
+	/// failures which don't produce a frame, like connection errors or HTTP error responses
+	/// without a frame body, are returned as an error.
+	///
+	/// Live queries started by the statements are not registered with this client. This is only
+	/// supported by the remote engines, embedded engines return
+	/// [`Error::RawResponsesNotSupported`](crate::error::Api::RawResponsesNotSupported).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("ws://localhost:8000").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// // Get the response frame to forward it as is
+	/// let frame = db
+	///     .query_raw_bytes("SELECT * FROM type::table($table)", ("table", "person"))
+	///     .await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn query_raw_bytes(
+		&self,
+		query: impl opt::IntoQuery,
+		bindings: impl Serialize + 'static,
+	) -> impl Future<Output = Result<Vec<u8>>> + Send + '_ {
+		self.query(query).bind(bindings).into_raw_bytes()
+	}
+
+	/// Runs a query which has already been parsed, or was built programmatically
+	///
+	/// The statements are sent to the database as they are, so a generated query does not need
+	/// to be written out as a string and parsed again. This is the same as passing the query to
+	/// [`Surreal::query`] and binding the parameters.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use surrealdb::sql::statements::SelectStatement;
+	/// use surrealdb::sql::{Cond, Expression, Field, Idiom, Operator, Param, Query, Table, Value};
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// // Build `SELECT * FROM person WHERE name = $name`
+	/// let mut select = SelectStatement::default();
+	/// select.expr.0 = vec![Field::All];
+	/// select.what.0 = vec![Value::Table(Table::from("person"))];
+	/// let mut cond = Cond::default();
+	/// cond.0 = Value::Expression(Box::new(Expression::new(
+	///     Idiom::from("name".to_owned()).into(),
+	///     Operator::Equal,
+	///     Param::from("name".to_owned()).into(),
+	/// )));
+	/// select.cond = Some(cond);
+	/// let query = Query::from(select);
+	///
+	/// let mut response = db.query_ast(query, ("name", "John Doe")).await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn query_ast(
+		&self,
+		query: sql::Query,
+		bindings: impl Serialize + 'static,
+	) -> impl Future<Output = Result<Response>> + Send + '_ {
+		self.query(query).bind(bindings).into_future()
+	}
+
+	/// Runs a set of SurrealQL statements under a different namespace, database, or authentication
+	///
+	/// The overrides are applied to a copy of the connection's session, so the session used by
+	/// other methods on this client is left untouched. This is only supported by the embedded
+	/// engines, remote engines return [`Error::SessionOverrideNotSupported`](crate::error::Api::SessionOverrideNotSupported).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use surrealdb::opt::SessionOverride;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// // Run a query in a different namespace for a single tenant
+	/// let session = SessionOverride::new().namespace("tenant").database("database");
+	/// let mut result = db
+	///     .query_with_session(session, "SELECT * FROM type::table($table)")
+	///     .bind(("table", "person"))
+	///     .await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn query_with_session(
+		&self,
+		session: opt::SessionOverride,
+		query: impl opt::IntoQuery,
+	) -> Query<'_, C> {
+		let inner = query.into_query().map(|x| ValidQuery {
+			client: Cow::Borrowed(self),
+			query: x,
+			bindings: Default::default(),
+			register_live_queries: true,
+			session: Some(session),
+		});
+
+		Query {
+			inner,
+		}
+	}
+
+	/// Runs a query and returns the first row of the first statement
+	///
+	/// Returns `None` if the first statement returned no rows. If the first statement
+	/// failed, its error is returned.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// let count: Option<usize> = db
+	///     .query_first("RETURN count(SELECT * FROM type::table($table))", ("table", "person"))
+	///     .await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn query_first<R>(
+		&self,
+		query: impl opt::IntoQuery,
+		bindings: impl Serialize + 'static,
+	) -> impl Future<Output = Result<Option<R>>> + Send + '_
+	where
+		R: DeserializeOwned,
+	{
+		let query = self.query(query).bind(bindings);
+		async move {
+			let mut response = query.await?;
+			let first = match response.take::<crate::Value>(0)?.into_inner() {
+				CoreValue::Array(rows) => rows.0.into_iter().next(),
+				CoreValue::None => None,
+				value => Some(value),
+			};
+			match first {
+				Some(value) => Ok(Some(from_core_value(value)?)),
+				None => Ok(None),
+			}
+		}
+	}
+
+	/// Runs a query and deserializes the result of each statement into an element of a tuple
+	///
+	/// The result of the first statement is taken into the first element, the result of the
+	/// second statement into the second element and so on, like [`Response::take`] does. Returns an error if the number
+	/// of statements does not match the size of the tuple, or if any of the statements failed.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[derive(serde::Deserialize)]
+	/// # struct User;
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// let (users, count): (Vec<User>, Option<i64>) = db
+	///     .query_typed("SELECT * FROM user; RETURN count(SELECT * FROM user)")
+	///     .await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn query_typed<T>(
+		&self,
+		query: impl opt::IntoQuery,
+	) -> impl Future<Output = Result<T>> + Send + '_
+	where
+		T: FromResponse,
+	{
+		let query = self.query(query);
+		async move { T::from_response(query.await?) }
+	}
+
+	/// Describes the fields, indexes and events defined on a table
+	///
+	/// Schemaless tables without any field definitions have no fields.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// let info = db.info_for_table("person").await?;
+	/// for field in info.fields {
+	///     println!("{}: {:?}", field.name, field.kind);
+	/// }
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn info_for_table(
+		&self,
+		table: &str,
+	) -> impl Future<Output = Result<TableInfo>> + Send + '_ {
+		let query = self.query(format!("INFO FOR TABLE {} STRUCTURE", Ident::from(table)));
+		async move {
+			let info = query.await?.take::<crate::Value>(0)?.into_inner();
+			TableInfo::from_info(info)
+		}
+	}
+
 	/// Selects all records in a table, or a specific record
 	///
 	/// # Examples
@@ -702,6 +1108,400 @@ where
 		}
 	}
 
+	/// Selects a range of records from a table
+	///
+	/// This is a shorthand for calling [`range`](Select::range) on [`select`](Surreal::select).
+	/// The bounds of the range are honoured as given, so `"a".."c"` excludes the end key
+	/// while `"a"..="c"` includes it.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # use std::ops::Bound;
+	/// # #[derive(serde::Deserialize)]
+	/// # struct Person;
+	/// #
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// // Select the records with keys from `jane` up to, but excluding, `john`
+	/// let people: Vec<Person> = db.select_range("person", "jane".."john").await?;
+	///
+	/// // Select the records with keys greater than `jane`
+	/// let people: Vec<Person> =
+	///     db.select_range("person", (Bound::Excluded("jane"), Bound::Unbounded)).await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn select_range<R>(
+		&self,
+		table: impl IntoResource<Vec<R>>,
+		range: impl Into<KeyRange>,
+	) -> Select<C, Vec<R>> {
+		self.select(table).range(range)
+	}
+
+	/// Counts the records in a table
+	///
+	/// This runs `SELECT count() FROM table GROUP ALL` and returns the count itself, which is
+	/// zero for an empty or missing table.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// let people = db.count("person").await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn count(&self, table: impl Into<String>) -> impl Future<Output = Result<u64>> + Send + '_ {
+		filter::count(self, table.into(), None)
+	}
+
+	/// Counts the records in a table which match a [`Filter`]
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use surrealdb::method::Filter;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// let adults = db.count_where("person", Filter::gte("age", 18)).await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn count_where(
+		&self,
+		table: impl Into<String>,
+		filter: Filter,
+	) -> impl Future<Output = Result<u64>> + Send + '_ {
+		filter::count(self, table.into(), Some(filter))
+	}
+
+	/// Creates graph edges between records
+	///
+	/// This runs `RELATE from->edge->to`, setting the content of each edge if there is any, and
+	/// returns the edges which were created. Every record in `from` is related to every record in
+	/// `to`, so a single edge is created by passing one record on each side.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use serde::Serialize;
+	/// use surrealdb::RecordId;
+	///
+	/// #[derive(Serialize)]
+	/// struct Knows {
+	///     since: u32,
+	/// }
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// let tobie = RecordId::from(("person", "tobie"));
+	/// let jaime = RecordId::from(("person", "jaime"));
+	/// let john = RecordId::from(("person", "john"));
+	///
+	/// // Create a single edge with some content
+	/// let edge = db.relate([tobie.clone()], "knows", [jaime.clone()], Some(Knows { since: 2020 })).await?;
+	///
+	/// // Relate one record to several others, without any content
+	/// let edges = db.relate([tobie], "likes", [jaime, john], None::<()>).await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn relate<T>(
+		&self,
+		from: impl IntoIterator<Item = RecordId>,
+		edge: impl Into<String>,
+		to: impl IntoIterator<Item = RecordId>,
+		content: Option<T>,
+	) -> impl Future<Output = Result<crate::Value>> + Send + '_
+	where
+		T: Serialize + 'static,
+	{
+		let content = content.map(|content| to_core_value(content).map_err(Into::into));
+		relate::relate(
+			self,
+			from.into_iter().collect(),
+			edge.into(),
+			to.into_iter().collect(),
+			content,
+		)
+	}
+
+	/// Selects a page of records from a table, ordered by their ID
+	///
+	/// Pages are fetched with the ID of the last record of the previous page as a cursor, so
+	/// records created or deleted between pages don't cause others to be skipped or repeated,
+	/// as they can be with `START`. Pass `None` to fetch the first page, and the
+	/// [`next`](Page::next) cursor of each page to fetch the one after it.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[derive(serde::Deserialize)]
+	/// # struct Person;
+	/// #
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// let mut cursor = None;
+	/// loop {
+	///     let page = db.select_paginated::<Person>("person", cursor, 100).await?;
+	///     // Process `page.rows` here
+	///     match page.next {
+	///         Some(next) => cursor = Some(next),
+	///         None => break,
+	///     }
+	/// }
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn select_paginated<T>(
+		&self,
+		table: &str,
+		after: Option<RecordId>,
+		limit: usize,
+	) -> impl Future<Output = Result<Page<T>>> + Send + '_
+	where
+		T: DeserializeOwned,
+	{
+		let mut stmt = SelectStatement::default();
+		stmt.expr.0 = vec![Field::All];
+		let what = match after {
+			// Each page scans the range of keys after the cursor, which follows ID order
+			Some(after) => {
+				let range = IdRange {
+					beg: Bound::Excluded(after.into_inner().id),
+					end: Bound::Unbounded,
+				};
+				CoreValue::Thing(Thing::from((table, range)))
+			}
+			None => CoreValue::Table(Table::from(table)),
+		};
+		stmt.what.0 = vec![what];
+		let mut page_limit = Limit::default();
+		page_limit.0 = CoreValue::from(limit);
+		stmt.limit = Some(page_limit);
+		let query = Query::new(
+			Cow::Borrowed(self),
+			vec![Statement::Select(stmt)],
+			Default::default(),
+			false,
+		);
+		async move {
+			let rows = match query.await?.take::<crate::Value>(0)?.into_inner() {
+				CoreValue::Array(rows) => rows,
+				_ => Default::default(),
+			};
+			let next = match rows.last() {
+				Some(CoreValue::Object(row)) if rows.len() == limit => match row.get("id") {
+					Some(CoreValue::Thing(id)) => Some(RecordId::from_inner(id.clone())),
+					_ => None,
+				},
+				_ => None,
+			};
+			let rows = from_core_value(rows.into())?;
+			Ok(Page {
+				rows,
+				next,
+			})
+		}
+	}
+
+	/// Kills every live query started by this client which is still running
+	///
+	/// Live queries are tracked per connection, so this includes the ones started through
+	/// clones of this client. Every live query is killed even if killing one of them fails,
+	/// in which case the first error is returned. Streams of killed live queries end once
+	/// their pending notifications have been received.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// let stream = db.select::<Vec<surrealdb::Value>>("person").live().await?;
+	/// # drop(stream);
+	///
+	/// // Clean up before shutting down
+	/// db.kill_all_live().await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub async fn kill_all_live(&self) -> Result<()> {
+		let router = self.router.extract()?;
+		let mut result = Ok(());
+		for uuid in router.live_queries() {
+			let killed = router
+				.execute_unit(Command::Kill {
+					uuid,
+				})
+				.await;
+			if let (Ok(()), Err(error)) = (&result, killed) {
+				result = Err(error);
+			}
+		}
+		result
+	}
+
+	/// Selects a single record by its ID
+	///
+	/// Returns `None` if the record does not exist. Unlike [`select`](Surreal::select), the
+	/// resource is always a record ID so the result is never an array.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[derive(serde::Deserialize)]
+	/// # struct Person;
+	/// #
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// let person: Option<Person> = db.select_one(("person", "tobie")).await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn select_one<R>(
+		&self,
+		id: impl Into<RecordId>,
+	) -> impl Future<Output = Result<Option<R>>> + Send + '_
+	where
+		R: DeserializeOwned + Send + 'static,
+	{
+		let record: RecordId = id.into();
+		async move { self.select(record).await }
+	}
+
+	/// Starts a live query which reports the state of records before and after every change
+	///
+	/// Each notification carries a [`Change`](crate::Change). For updates both `before` and
+	/// `after` are populated, for creations `before` is `None` and for deletions `after` is
+	/// `None`.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use futures::StreamExt;
+	/// use surrealdb::Change;
+	/// use surrealdb::Notification;
+	///
+	/// # #[derive(Debug, serde::Deserialize)]
+	/// # struct Person;
+	/// #
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// let mut stream = db.live_diff::<Person>("person").await?;
+	/// while let Some(result) = stream.next().await {
+	///     let notification: Notification<Change<Person>> = result?;
+	///     println!("{:?} -> {:?}", notification.data.before, notification.data.after);
+	/// }
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn live_diff<R>(
+		&self,
+		resource: impl IntoResource<Vec<R>>,
+	) -> impl Future<Output = Result<Stream<crate::Change<R>>>> + Send + '_
+	where
+		R: DeserializeOwned + Send + 'static,
+	{
+		live::diff_future(Cow::Borrowed(self), resource.into_resource())
+	}
+
+	/// Selects the current records of a resource and starts a live query on it
+	///
+	/// The live query is started before the records are selected, so changes committed
+	/// after the records were selected are delivered by the stream. Notifications received
+	/// while the records were being selected are dropped if the returned records already
+	/// include their changes, but a change committed during the selection may still be both
+	/// part of the records and delivered by the stream.
+	///
+	/// A write from a transaction which was already running when the live query started,
+	/// and which commits after the records were selected, is neither selected nor notified.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use futures::StreamExt;
+	///
+	/// # #[derive(Debug, serde::Deserialize)]
+	/// # struct Person;
+	/// #
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// let (people, mut stream) = db.live_with_snapshot::<Person>("person").await?;
+	/// println!("{people:?}");
+	/// while let Some(result) = stream.next().await {
+	///     println!("{:?}", result?);
+	/// }
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn live_with_snapshot<R>(
+		&self,
+		resource: impl IntoResource<Vec<R>>,
+	) -> impl Future<Output = Result<(Vec<R>, Stream<Vec<R>>)>> + Send + '_
+	where
+		R: DeserializeOwned + Send + 'static,
+	{
+		live::snapshot_future(Cow::Borrowed(self), resource.into_resource())
+	}
+
 	/// Creates a record in the database
 	///
 	/// # Examples
@@ -756,6 +1556,71 @@ where
 		}
 	}
 
+	/// Creates exactly one record with the specified ID and content
+	///
+	/// Unlike [`create`](Surreal::create), this returns the created record directly
+	/// and fails with [`Error::RecordExists`](crate::error::Api::RecordExists) if a
+	/// record with this ID already exists.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use serde::{Deserialize, Serialize};
+	/// use surrealdb::RecordId;
+	///
+	/// #[derive(Serialize, Deserialize)]
+	/// struct User {
+	///     id: RecordId,
+	///     name: String,
+	/// }
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// let id = RecordId::from(("user", "tobie"));
+	/// let user = db
+	///     .create_one(
+	///         id.clone(),
+	///         User {
+	///             id,
+	///             name: "Tobie".to_owned(),
+	///         },
+	///     )
+	///     .await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn create_one<R>(
+		&self,
+		id: impl Into<RecordId>,
+		content: R,
+	) -> impl Future<Output = Result<R>> + Send + '_
+	where
+		R: Serialize + DeserializeOwned + Send + 'static,
+	{
+		let record: RecordId = id.into();
+		async move {
+			let result: Result<Option<R>> = self.create(record.clone()).content(content).await;
+			match result {
+				Ok(Some(value)) => Ok(value),
+				Ok(None) => Err(Error::InternalError(format!(
+					"creating record `{record}` did not return the record"
+				))
+				.into()),
+				Err(error) if is_record_exists(&error, &record) => Err(Error::RecordExists {
+					record,
+				}
+				.into()),
+				Err(error) => Err(error),
+			}
+		}
+	}
+
 	/// Insert a record or records into a table
 	///
 	/// # Examples
@@ -1059,6 +1924,199 @@ where
 		}
 	}
 
+	/// Creates a record with the specified ID, or merges the content into it if it already exists
+	///
+	/// Unlike [`upsert`](Surreal::upsert), this always runs an `UPSERT ... MERGE` statement
+	/// and returns the final state of the record directly.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use serde::{Deserialize, Serialize};
+	/// use surrealdb::RecordId;
+	///
+	/// #[derive(Serialize, Deserialize)]
+	/// struct Event {
+	///     count: u64,
+	/// }
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// let event = db
+	///     .upsert_one(("event", "tobie"), Event {
+	///         count: 1,
+	///     })
+	///     .await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn upsert_one<R>(
+		&self,
+		id: impl Into<RecordId>,
+		content: R,
+	) -> impl Future<Output = Result<R>> + Send + '_
+	where
+		R: Serialize + DeserializeOwned + Send + 'static,
+	{
+		let record: RecordId = id.into();
+		async move {
+			let mut response = self
+				.query("UPSERT $record MERGE $content")
+				.bind(("record", record.clone()))
+				.bind(("content", content))
+				.await?
+				.check()?;
+			match response.take::<Option<R>>(0)? {
+				Some(value) => Ok(value),
+				None => Err(Error::InternalError(format!(
+					"upserting record `{record}` did not return the record"
+				))
+				.into()),
+			}
+		}
+	}
+
+	/// Merges content into a record only if its `version` field matches the expected version
+	///
+	/// This implements optimistic concurrency control. The update and the version check happen
+	/// in a single `UPDATE ... MERGE ... WHERE version = $version` statement, which also sets
+	/// `version` to the next version. Returns `None` if the version did not match, or if the
+	/// record does not exist, so the caller can re-read the record and try again. Returns an
+	/// error if the expected version is `i64::MAX`, as there is no next version.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use serde::{Deserialize, Serialize};
+	///
+	/// #[derive(Serialize, Deserialize)]
+	/// struct Account {
+	///     balance: u64,
+	///     version: i64,
+	/// }
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// let account: Option<Account> = db.select(("account", "tobie")).await?;
+	/// if let Some(mut account) = account {
+	///     account.balance += 100;
+	///     let expected = account.version;
+	///     match db.update_if_version(("account", "tobie"), expected, account).await? {
+	///         Some(account) => assert_eq!(account.version, expected + 1),
+	///         None => println!("the account was changed concurrently"),
+	///     }
+	/// }
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn update_if_version<R>(
+		&self,
+		id: impl Into<RecordId>,
+		expected_version: i64,
+		content: R,
+	) -> impl Future<Output = Result<Option<R>>> + Send + '_
+	where
+		R: Serialize + DeserializeOwned + Send + 'static,
+	{
+		let record: RecordId = id.into();
+		let content = to_core_value(content);
+		async move {
+			let mut content = match content? {
+				CoreValue::Object(content) => content,
+				content => {
+					return Err(Error::InvalidParams(format!(
+						"expected the content to be an object, found `{content}`"
+					))
+					.into())
+				}
+			};
+			let Some(next_version) = expected_version.checked_add(1) else {
+				return Err(Error::InvalidParams(format!(
+					"the version `{expected_version}` can not be incremented"
+				))
+				.into());
+			};
+			content.insert("version".to_owned(), CoreValue::from(next_version));
+			let mut response = self
+				.query("UPDATE $record MERGE $content WHERE version = $version")
+				.bind(("record", record))
+				.bind(("content", crate::Value::from_inner(CoreValue::Object(content))))
+				.bind(("version", expected_version))
+				.await?
+				.check()?;
+			response.take(0)
+		}
+	}
+
+	/// Runs a transaction, retrying it whenever it fails because of a transaction conflict
+	///
+	/// Storage engines like TiKV, RocksDB and SurrealKV abort transactions which conflict with
+	/// concurrent ones. Such errors can be safely retried. The future returned by `f` is run
+	/// again, after an exponential backoff, for up to `max_retries` additional attempts. Any
+	/// other error is returned immediately. See [`Error::is_retryable`](crate::Error::is_retryable).
+	///
+	/// Statements outside of a `BEGIN ... COMMIT` block each run in their own transaction, whose
+	/// conflicts are reported as retryable. A conflict when committing a `BEGIN ... COMMIT` block
+	/// is reported as a failed transaction for each of its statements instead, which is not
+	/// retried.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// db.with_transaction_retry(3, || async {
+	///     db.query("UPDATE counter:visits SET count += 1").await?.check()?;
+	///     Ok(())
+	/// })
+	/// .await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn with_transaction_retry<F, Fut, T>(
+		&self,
+		max_retries: usize,
+		mut f: F,
+	) -> impl Future<Output = Result<T>>
+	where
+		F: FnMut() -> Fut,
+		Fut: Future<Output = Result<T>>,
+	{
+		async move {
+			let mut backoff = TRANSACTION_RETRY_BACKOFF;
+			let mut retries = 0;
+			loop {
+				match f().await {
+					Err(error) if error.is_retryable() && retries < max_retries => {
+						retries += 1;
+						trace!("Retrying transaction after a conflict ({retries}/{max_retries})");
+						sleep(backoff).await;
+						backoff = (backoff * 2).min(TRANSACTION_RETRY_MAX_BACKOFF);
+					}
+					result => return result,
+				}
+			}
+		}
+	}
+
 	/// Updates all records in a table, or a specific record
 	///
 	/// # Examples
@@ -1217,6 +2275,85 @@ where
 		}
 	}
 
+	/// Applies [JSON Patch] operations to a specific record and returns the updated record
+	///
+	/// The path of every operation is checked to be a valid JSON Pointer before
+	/// anything is sent to the database. Fails with
+	/// [`Error::RecordNotFound`](crate::error::Api::RecordNotFound) if the record
+	/// does not exist.
+	///
+	/// [JSON Patch]: https://jsonpatch.com/
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use serde::Deserialize;
+	/// use surrealdb::opt::PatchOp;
+	///
+	/// #[derive(Deserialize)]
+	/// struct Person {
+	///     name: String,
+	///     tags: Vec<String>,
+	/// }
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// let person: Person = db
+	///     .patch(
+	///         ("person", "tobie"),
+	///         vec![
+	///             PatchOp::replace("/name", "Tobie Morgan Hitchcock"),
+	///             PatchOp::add("/tags/-", "founder"),
+	///         ],
+	///     )
+	///     .await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn patch<R>(
+		&self,
+		id: impl Into<RecordId>,
+		ops: Vec<PatchOp>,
+	) -> impl Future<Output = Result<R>> + Send + '_
+	where
+		R: DeserializeOwned + Send + 'static,
+	{
+		let record: RecordId = id.into();
+		async move {
+			let mut patches = Vec::with_capacity(ops.len());
+			for PatchOp(op) in ops {
+				let value = to_core_value(op.map_err(crate::error::Db::from)?)?;
+				if let CoreValue::Object(object) = &value {
+					if let Some(CoreValue::Strand(path)) = object.get("path") {
+						if !is_json_pointer(path) {
+							return Err(Error::InvalidPatchPath(path.as_str().to_owned()).into());
+						}
+					}
+				}
+				patches.push(value);
+			}
+			let router = self.router.extract()?;
+			let result = router
+				.execute_opt(Command::Patch {
+					what: Resource::from(record.clone()),
+					data: Some(CoreValue::from(patches)),
+				})
+				.await?;
+			result.ok_or_else(|| {
+				Error::RecordNotFound {
+					record,
+				}
+				.into()
+			})
+		}
+	}
+
 	/// Deletes all records, or a specific record
 	///
 	/// # Examples
@@ -1249,6 +2386,122 @@ where
 		}
 	}
 
+	/// Deletes a range of records from a table, returning how many were deleted
+	///
+	/// The bounds of the range are honoured as given, so `"a".."c"` excludes the end key
+	/// while `"a"..="c"` includes it.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// // Delete the records with keys from 100 up to, but excluding, 200
+	/// let deleted = db.delete_range("log", 100..200).await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn delete_range(
+		&self,
+		table: impl Into<String>,
+		range: impl Into<KeyRange>,
+	) -> impl Future<Output = Result<u64>> + Send + '_ {
+		let resource = Resource::from(table.into()).with_range(range.into());
+		async move {
+			let router = self.router.extract()?;
+			let deleted = router
+				.execute_value(Command::Delete {
+					what: resource?,
+				})
+				.await?;
+			match deleted.into_inner() {
+				CoreValue::Array(records) => Ok(records.len() as u64),
+				_ => Ok(0),
+			}
+		}
+	}
+
+	/// Defines a table along with any of its fields
+	///
+	/// This builds and runs the equivalent `DEFINE TABLE` and `DEFINE FIELD` statements.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use surrealdb::sql::{Kind, Permissions};
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// db.define_table("person")
+	///     .schemafull()
+	///     .if_not_exists()
+	///     .field("name", Kind::String)
+	///     .field("age", Kind::Option(Box::new(Kind::Int)))
+	///     .permissions(Permissions::full())
+	///     .await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn define_table(&self, table: impl Into<String>) -> DefineTable<C> {
+		DefineTable {
+			client: Cow::Borrowed(self),
+			table: table.into(),
+			schemafull: false,
+			if_not_exists: false,
+			permissions: None,
+			fields: Vec::new(),
+		}
+	}
+
+	/// Defines a field on an existing table
+	///
+	/// This builds and runs the equivalent `DEFINE FIELD` statement.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use surrealdb::sql::Kind;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// db.define_field("person", "created_at", Kind::Datetime).readonly().await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn define_field(
+		&self,
+		table: impl Into<String>,
+		name: impl Into<String>,
+		kind: Kind,
+	) -> DefineField<C> {
+		DefineField {
+			client: Cow::Borrowed(self),
+			table: table.into(),
+			name: name.into(),
+			kind,
+			if_not_exists: false,
+			readonly: false,
+		}
+	}
+
 	/// Returns the version of the server
 	///
 	/// # Examples
@@ -1315,6 +2568,32 @@ where
 		}
 	}
 
+	/// Measures the round-trip time of a ping to the server
+	///
+	/// The time is measured from sending the ping until the server responds to it, so it
+	/// includes the time the server takes to handle it. Embedded engines have no network
+	/// to cross, so the latency they report is close to zero.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("ws://localhost:8000").await?;
+	/// let latency = db.ping_latency().await?;
+	/// println!("round-trip time: {latency:?}");
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn ping_latency(&self) -> impl Future<Output = Result<Duration>> + Send + '_ {
+		let ping = self.health().into_future();
+		async move {
+			let start = Instant::now();
+			ping.await?;
+			Ok(start.elapsed())
+		}
+	}
+
 	/// Wait for the selected event to happen before proceeding
 	pub async fn wait_for(&self, event: WaitFor) {
 		let mut rx = self.waiter.0.subscribe();
@@ -1375,6 +2654,53 @@ where
 		}
 	}
 
+	/// Streams the definition and records of a single table as SurrealQL
+	///
+	/// The export contains the `DEFINE` statements of the table and its fields, indexes and
+	/// events, followed by `INSERT` statements for its records. Other tables and database
+	/// level definitions, such as users, params and functions, are left out.
+	///
+	/// # Support
+	///
+	/// Currently only supported by HTTP and the local engines. *Not* supported on WebAssembly.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # use futures::StreamExt;
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// let mut export = db.export_table("person");
+	/// while let Some(bytes) = export.next().await {
+	///     let bytes = bytes?;
+	///     // Do something with the bytes received...
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn export_table(
+		&self,
+		table: &str,
+	) -> impl futures::Stream<Item = Result<Vec<u8>>> + Send + '_ {
+		let export = self
+			.export(())
+			.with_config()
+			.users(false)
+			.accesses(false)
+			.params(false)
+			.functions(false)
+			.analyzers(false)
+			.tables(vec![table]);
+		stream::once(export.into_future()).flat_map(|backup| match backup {
+			Ok(backup) => backup.left_stream(),
+			Err(error) => stream::once(future::ready(Err(error))).right_stream(),
+		})
+	}
+
 	/// Restores the database from a file
 	///
 	/// # Support
@@ -1406,3 +2732,42 @@ where
 		}
 	}
 }
+
+/// Checks if an error was caused by creating a record which already exists
+///
+/// Embedded engines return the typed [`RecordExists`](surrealdb_core::err::Error::RecordExists)
+/// error. Remote engines only send the message of the error, so as a fallback the message is
+/// compared with the one the database gives for this record. If the server words the error
+/// differently, the fallback does not match and the original error is returned unchanged.
+fn is_record_exists(error: &crate::Error, record: &RecordId) -> bool {
+	match error {
+		crate::Error::Db(surrealdb_core::err::Error::RecordExists {
+			..
+		}) => true,
+		crate::Error::Api(Error::Query(message)) => {
+			let expected = surrealdb_core::err::Error::RecordExists {
+				thing: record.clone().into_inner(),
+			};
+			*message == expected.to_string()
+		}
+		_ => false,
+	}
+}
+
+/// Checks if a path is a valid JSON Pointer, as defined by RFC 6901
+fn is_json_pointer(path: &str) -> bool {
+	if path.is_empty() {
+		return true;
+	}
+	if !path.starts_with('/') {
+		return false;
+	}
+	// A `~` is only allowed as part of the `~0` and `~1` escape sequences
+	let mut chars = path.chars();
+	while let Some(c) = chars.next() {
+		if c == '~' && !matches!(chars.next(), Some('0' | '1')) {
+			return false;
+		}
+	}
+	true
+}
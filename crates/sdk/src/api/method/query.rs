@@ -20,13 +20,16 @@ use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::fs;
 use std::future::IntoFuture;
+use std::path::Path;
 use std::pin::Pin;
 use std::task::Context;
 use std::task::Poll;
 use surrealdb_core::sql::{
 	self, to_value as to_core_value, Object as CoreObject, Statement, Value as CoreValue,
 };
+use surrealdb_core::syn;
 
 /// A query future
 #[derive(Debug)]
@@ -41,6 +44,7 @@ pub(crate) struct ValidQuery<'r, C: Connection> {
 	pub query: Vec<Statement>,
 	pub bindings: CoreObject,
 	pub register_live_queries: bool,
+	pub session: Option<opt::SessionOverride>,
 }
 
 impl<'r, C> Query<'r, C>
@@ -59,6 +63,7 @@ where
 				query,
 				bindings,
 				register_live_queries,
+				session: None,
 			}),
 		}
 	}
@@ -77,6 +82,33 @@ where
 		}
 	}
 
+	/// Sends the query and returns the response frame without decoding it
+	pub(crate) async fn into_raw_bytes(self) -> Result<Vec<u8>> {
+		let ValidQuery {
+			client,
+			query,
+			bindings,
+			..
+		} = self.inner?;
+		let router = client.router.extract()?;
+		if !router.features.contains(&ExtraFeatures::RawResponses) {
+			return Err(Error::RawResponsesNotSupported.into());
+		}
+		let mut statements = sql::Query::default();
+		statements.0 .0 = query;
+		let command = Command::RawQuery {
+			query: statements,
+			variables: bindings,
+		};
+		match router.execute_value(command).await?.into_inner() {
+			CoreValue::Bytes(bytes) => Ok(bytes.into_inner()),
+			value => {
+				Err(Error::InternalError(format!("expected the raw response frame, got `{value}`"))
+					.into())
+			}
+		}
+	}
+
 	/// Converts to an owned type which can easily be moved to a different thread
 	pub fn into_owned(self) -> Query<'static, C> {
 		let inner = match self.inner {
@@ -85,11 +117,13 @@ where
 				query,
 				bindings,
 				register_live_queries,
+				session,
 			}) => Ok(ValidQuery::<'static, C> {
 				client: Cow::Owned(client.into_owned()),
 				query,
 				bindings,
 				register_live_queries,
+				session,
 			}),
 			Err(e) => Err(e),
 		};
@@ -113,6 +147,7 @@ where
 			query,
 			bindings,
 			register_live_queries,
+			session,
 		} = match self.inner {
 			Ok(x) => x,
 			Err(error) => return Box::pin(async move { Err(error) }),
@@ -152,12 +187,24 @@ where
 			let mut query = sql::Query::default();
 			query.0 .0 = query_statements;
 
-			let mut response = router
-				.execute_query(Command::Query {
+			let command = match session {
+				Some(session) => {
+					if !router.features.contains(&ExtraFeatures::SessionOverride) {
+						return Err(Error::SessionOverrideNotSupported.into());
+					}
+					Command::QueryWithSession {
+						query,
+						variables: bindings,
+						session,
+					}
+				}
+				None => Command::Query {
 					query,
 					variables: bindings,
-				})
-				.await?;
+				},
+			};
+
+			let mut response = router.execute_query(command).await?;
 
 			for idx in query_indicies {
 				let Some((_, result)) = response.results.get(&idx) else {
@@ -299,6 +346,44 @@ where
 			Ok(valid)
 		})
 	}
+
+	/// Binds the fields of an object stored in a file as query parameters
+	///
+	/// The file must contain a single object literal, such as
+	/// `{ name: 'John Doe', age: 42 }`. Each top-level field is bound as a parameter
+	/// of the same name. Values must be plain literals, like strings, numbers and
+	/// record IDs; expressions such as function calls or subqueries are rejected.
+	/// Returns an error if the file can not be read or does not contain an object.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// let response = db.query("CREATE user SET name = $name, age = $age")
+	///     .bind_vars_file("vars.surql")
+	///     .await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn bind_vars_file(self, path: impl AsRef<Path>) -> Self {
+		self.map_valid(move |mut valid| {
+			let path = path.as_ref();
+			let source = fs::read_to_string(path).map_err(|error| Error::FileRead {
+				path: path.to_owned(),
+				error,
+			})?;
+			match syn::json(&source)? {
+				CoreValue::Object(mut map) => valid.bindings.append(&mut map.0),
+				bindings => {
+					let bindings = Value::from_inner(bindings);
+					return Err(Error::InvalidBindings(bindings).into());
+				}
+			}
+			Ok(valid)
+		})
+	}
 }
 
 pub(crate) type QueryResult = Result<CoreValue>;
@@ -494,6 +579,9 @@ impl Response {
 
 	/// Check query response for errors and return the first error, if any, or the response
 	///
+	/// The error is returned as it was reported for the failed statement. Use
+	/// [`Response::check_indexed`] to also find out which statement failed.
+	///
 	/// # Examples
 	///
 	/// ```no_run
@@ -506,17 +594,52 @@ impl Response {
 	/// # Ok(())
 	/// # }
 	/// ```
-	pub fn check(mut self) -> Result<Self> {
-		let mut first_error = None;
-		for (key, result) in &self.results {
-			if result.1.is_err() {
-				first_error = Some(*key);
-				break;
+	pub fn check(self) -> Result<Self> {
+		self.check_statements().map_err(|(_, error)| error)
+	}
+
+	/// Checks the response for errors, returning the response if every statement succeeded
+	///
+	/// Otherwise the error of the first statement which failed is returned as a
+	/// [`StatementFailed`](crate::error::Api::StatementFailed) error, along with the index of
+	/// that statement.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// let response = db
+	///     .query("CREATE user:john SET name = 'John'")
+	///     .query("SELECT * FROM user")
+	///     .await?
+	///     .check_indexed()?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn check_indexed(self) -> Result<Self> {
+		self.check_statements().map_err(|(index, error)| {
+			Error::StatementFailed {
+				index,
+				error: Box::new(error),
 			}
-		}
+			.into()
+		})
+	}
+
+	fn check_statements(mut self) -> std::result::Result<Self, (usize, crate::Error)> {
+		// Taking results reorders the map, so the first error is not necessarily the first entry
+		let first_error = self
+			.results
+			.iter()
+			.filter(|(_, (_, result))| result.is_err())
+			.map(|(key, _)| *key)
+			.min();
 		if let Some(key) = first_error {
 			if let Some((_, Err(error))) = self.results.swap_remove(&key) {
-				return Err(error);
+				return Err((key, error));
 			}
 		}
 		Ok(self)
@@ -540,8 +663,73 @@ impl Response {
 	pub fn num_statements(&self) -> usize {
 		self.results.len()
 	}
+
+	/// Returns the statistics of the statement at the given index
+	///
+	/// Returns `None` if there is no statement at that index or if its result was already taken.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// let response = db.query("SELECT * FROM user").await?;
+	///
+	/// if let Some(execution_time) = response.stats(0).and_then(|stats| stats.execution_time) {
+	///     println!("the statement took {execution_time:?}");
+	/// }
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn stats(&self, index: usize) -> Option<Stats> {
+		self.results.get(&index).map(|(stats, _)| *stats)
+	}
+}
+
+/// Types which can be built from the results of every statement in a query response
+///
+/// This is implemented for tuples of up to 8 elements. Each element is taken from the result of
+/// the statement at the same index, like [`Response::take`] does, so elements are typically an
+/// `Option<T>`, a `Vec<T>` or a [`Value`]. The number of statements must match the number of
+/// elements. See [`Surreal::query_typed`].
+pub trait FromResponse: Sized {
+	/// Deserializes the results of all statements in the response
+	fn from_response(response: Response) -> Result<Self>;
+}
+
+macro_rules! impl_from_response_for_tuple {
+	($len:literal => $($ty:ident $index:tt),+) => {
+		impl<$($ty),+> FromResponse for ($($ty,)+)
+		where
+			$($ty: DeserializeOwned,)+
+			$(usize: opt::QueryResult<$ty>,)+
+		{
+			fn from_response(mut response: Response) -> Result<Self> {
+				let found = response.num_statements();
+				if found != $len {
+					return Err(Error::StatementCountMismatch {
+						expected: $len,
+						found,
+					}
+					.into());
+				}
+				Ok(($(response.take::<$ty>($index)?,)+))
+			}
+		}
+	};
 }
 
+impl_from_response_for_tuple!(1 => A 0);
+impl_from_response_for_tuple!(2 => A 0, B 1);
+impl_from_response_for_tuple!(3 => A 0, B 1, C 2);
+impl_from_response_for_tuple!(4 => A 0, B 1, C 2, D 3);
+impl_from_response_for_tuple!(5 => A 0, B 1, C 2, D 3, E 4);
+impl_from_response_for_tuple!(6 => A 0, B 1, C 2, D 3, E 4, F 5);
+impl_from_response_for_tuple!(7 => A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_from_response_for_tuple!(8 => A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
 impl WithStats<Response> {
 	/// Takes and returns records returned from the database
 	///
@@ -994,6 +1182,79 @@ mod tests {
 		};
 	}
 
+	#[test]
+	fn check_indexed_returns_the_first_error() {
+		let response = vec![
+			Ok(0.into()),
+			Ok(1.into()),
+			Ok(2.into()),
+			Err(Error::ConnectionUninitialised.into()),
+			Ok(3.into()),
+			Ok(4.into()),
+			Ok(5.into()),
+			Err(Error::BackupsNotSupported.into()),
+			Ok(6.into()),
+			Ok(7.into()),
+			Err(Error::DuplicateRequestId(0).into()),
+		];
+		let response = Response {
+			results: to_map(response),
+			..Response::new()
+		};
+		let crate::Error::Api(Error::StatementFailed {
+			index,
+			error,
+		}) = response.check_indexed().unwrap_err()
+		else {
+			panic!("check did not return a statement error");
+		};
+		assert_eq!(index, 3);
+		let crate::Error::Api(Error::ConnectionUninitialised) = *error else {
+			panic!("check did not return the first error");
+		};
+	}
+
+	#[test]
+	fn check_finds_the_first_error_after_taking() {
+		let response = vec![
+			Ok(0.into()),
+			Err(Error::BackupsNotSupported.into()),
+			Ok(1.into()),
+			Err(Error::ConnectionUninitialised.into()),
+		];
+		let mut response = Response {
+			results: to_map(response),
+			..Response::new()
+		};
+		// Moves the last statement to the front of the map
+		let _: Value = response.take(0).unwrap();
+		let crate::Error::Api(Error::StatementFailed {
+			index,
+			error,
+		}) = response.check_indexed().unwrap_err()
+		else {
+			panic!("check did not return a statement error");
+		};
+		assert_eq!(index, 1);
+		let crate::Error::Api(Error::BackupsNotSupported) = *error else {
+			panic!("check did not return the first error");
+		};
+	}
+
+	#[test]
+	fn check_passes_successful_responses_through() {
+		let response = vec![Ok(0.into()), Ok(1.into())];
+		let response = Response {
+			results: to_map(response),
+			..Response::new()
+		};
+		let response = response.check().unwrap();
+		let mut response = response.check_indexed().unwrap();
+		assert_eq!(response.num_statements(), 2);
+		let value: Value = response.take(1).unwrap();
+		assert_eq!(value, Value::from_inner(1.into()));
+	}
+
 	#[test]
 	fn take_errors() {
 		let response = vec![
@@ -0,0 +1,40 @@
+use crate::api::Connection;
+use crate::api::Result;
+use crate::method::Query;
+use crate::RecordId;
+use crate::Surreal;
+use crate::Value;
+use std::borrow::Cow;
+use surrealdb_core::sql::statements::RelateStatement;
+use surrealdb_core::sql::{
+	Data, Object as CoreObject, Param, Statement, Table, Value as CoreValue,
+};
+
+fn records(ids: Vec<RecordId>) -> CoreValue {
+	let things: Vec<CoreValue> =
+		ids.into_iter().map(|id| CoreValue::Thing(id.into_inner())).collect();
+	CoreValue::from(things)
+}
+
+/// Relates every record in `from` to every record in `to`, returning the edges created
+pub(super) async fn relate<C: Connection>(
+	client: &Surreal<C>,
+	from: Vec<RecordId>,
+	edge: String,
+	to: Vec<RecordId>,
+	content: Option<Result<CoreValue>>,
+) -> Result<Value> {
+	let mut bindings = CoreObject::default();
+	let mut stmt = RelateStatement::default();
+	stmt.kind = CoreValue::Table(Table::from(edge));
+	stmt.from = records(from);
+	stmt.with = records(to);
+	if let Some(content) = content {
+		bindings.insert("content".to_owned(), content?);
+		stmt.data = Some(Data::ContentExpression(Param::from("content".to_owned()).into()));
+	}
+	let statements = vec![Statement::Relate(stmt)];
+	let mut response =
+		Query::new(Cow::Borrowed(client), statements, bindings, false).await?.check()?;
+	response.take(0)
+}
@@ -0,0 +1,99 @@
+use crate::api::engine::resource_to_values;
+use crate::api::method::BoxFuture;
+use crate::api::opt::Resource;
+use crate::api::opt::Table;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::method::Query;
+use crate::Surreal;
+use std::borrow::Cow;
+use std::future::IntoFuture;
+use surrealdb_core::sql::statements::{
+	CreateStatement, DeleteStatement, InsertStatement, UpdateStatement, UpsertStatement,
+};
+use surrealdb_core::sql::{Data, Output, Statement, Value as CoreValue};
+
+/// A future which writes records without returning them
+///
+/// The statement is run with `RETURN NONE`, so the written records are neither sent back
+/// nor deserialised.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReturnNone<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) statement: Result<Statement>,
+}
+
+impl<C> ReturnNone<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> ReturnNone<'static, C> {
+		ReturnNone {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for ReturnNone<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<()>;
+	type IntoFuture = BoxFuture<'r, Self::Output>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let statement = self.statement?;
+			Query::new(self.client, vec![statement], Default::default(), false).await?.check()?;
+			Ok(())
+		})
+	}
+}
+
+/// Builds a `CREATE` statement which returns nothing
+pub(super) fn create(what: Resource, data: Option<CoreValue>) -> Statement {
+	let mut stmt = CreateStatement::default();
+	stmt.what = resource_to_values(what);
+	stmt.data = data.map(Data::ContentExpression);
+	stmt.output = Some(Output::None);
+	Statement::Create(stmt)
+}
+
+/// Builds an `UPSERT` statement which returns nothing
+pub(super) fn upsert(what: Resource, data: Option<CoreValue>) -> Statement {
+	let mut stmt = UpsertStatement::default();
+	stmt.what = resource_to_values(what);
+	stmt.data = data.map(Data::ContentExpression);
+	stmt.output = Some(Output::None);
+	Statement::Upsert(stmt)
+}
+
+/// Builds an `UPDATE` statement which returns nothing
+pub(super) fn update(what: Resource, data: Option<CoreValue>) -> Statement {
+	let mut stmt = UpdateStatement::default();
+	stmt.what = resource_to_values(what);
+	stmt.data = data.map(Data::ContentExpression);
+	stmt.output = Some(Output::None);
+	Statement::Update(stmt)
+}
+
+/// Builds an `INSERT` or `INSERT RELATION` statement which returns nothing
+pub(super) fn insert(what: Option<String>, data: CoreValue, relation: bool) -> Statement {
+	let mut stmt = InsertStatement::default();
+	stmt.into = what.map(|w| Table(w).into_core().into());
+	stmt.data = Data::SingleExpression(data);
+	stmt.output = Some(Output::None);
+	stmt.relation = relation;
+	Statement::Insert(stmt)
+}
+
+/// Builds a `DELETE` statement which returns nothing
+pub(super) fn delete(what: Resource) -> Statement {
+	let mut stmt = DeleteStatement::default();
+	stmt.what = resource_to_values(what);
+	stmt.output = Some(Output::None);
+	Statement::Delete(stmt)
+}
@@ -4,7 +4,10 @@ use crate::api::method::OnceLockExt;
 use crate::api::opt::Resource;
 use crate::api::Connection;
 use crate::api::Result;
+use crate::method::Filter;
 use crate::method::Live;
+use crate::method::Resume;
+use crate::method::SelectWhere;
 use crate::opt::KeyRange;
 use crate::Surreal;
 use crate::Value;
@@ -88,7 +91,7 @@ where
 	into_future! {execute_vec}
 }
 
-impl<C> Select<'_, C, Value>
+impl<'r, C> Select<'r, C, Value>
 where
 	C: Connection,
 {
@@ -97,9 +100,20 @@ where
 		self.resource = self.resource.and_then(|x| x.with_range(range.into()));
 		self
 	}
+
+	/// Restricts the records selected to those matching the filter
+	pub fn filter(self, filter: Filter) -> SelectWhere<'r, C, Value> {
+		SelectWhere {
+			client: self.client,
+			resource: self.resource,
+			filter: Some(filter),
+			fetch: Vec::new(),
+			response_type: PhantomData,
+		}
+	}
 }
 
-impl<C, R> Select<'_, C, Vec<R>>
+impl<'r, C, R> Select<'r, C, Vec<R>>
 where
 	C: Connection,
 {
@@ -108,6 +122,102 @@ where
 		self.resource = self.resource.and_then(|x| x.with_range(range.into()));
 		self
 	}
+
+	/// Restricts the records selected to those matching the filter
+	///
+	/// Values in the filter are sent as query parameters, so they are never written into the
+	/// query itself.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use surrealdb::method::Filter;
+	/// # #[derive(serde::Deserialize)]
+	/// # struct Person;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// // Select the adults with a high score
+	/// let people: Vec<Person> = db
+	///     .select("person")
+	///     .filter(Filter::gte("age", 18).and(Filter::gt("score", 10)))
+	///     .await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn filter(self, filter: Filter) -> SelectWhere<'r, C, Vec<R>> {
+		SelectWhere {
+			client: self.client,
+			resource: self.resource,
+			filter: Some(filter),
+			fetch: Vec::new(),
+			response_type: PhantomData,
+		}
+	}
+}
+
+impl<'r, C, R> Select<'r, C, R>
+where
+	C: Connection,
+{
+	/// Replaces the record ids in the given fields with the records they point to
+	///
+	/// This adds a `FETCH` clause to the select statement, so the response type must expect
+	/// the full records in those fields rather than record ids. Fields are paths of field names
+	/// and array indexes, such as `author` or `comments.author`. Anything else is rejected when
+	/// the select is run.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use serde::Deserialize;
+	/// use surrealdb::RecordId;
+	///
+	/// #[derive(Deserialize)]
+	/// struct Author {
+	///     id: RecordId,
+	///     name: String,
+	/// }
+	///
+	/// #[derive(Deserialize)]
+	/// struct Post {
+	///     id: RecordId,
+	///     title: String,
+	///     author: Author,
+	/// }
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// // Select every post along with its author
+	/// let posts: Vec<Post> = db.select("post").fetch(&["author"]).await?;
+	///
+	/// // Select a single post along with its author
+	/// let post: Option<Post> = db.select(("post", "first")).fetch(&["author"]).await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn fetch(self, fields: &[&str]) -> SelectWhere<'r, C, R> {
+		SelectWhere {
+			client: self.client,
+			resource: self.resource,
+			filter: None,
+			fetch: Vec::new(),
+			response_type: PhantomData,
+		}
+		.fetch(fields)
+	}
 }
 
 impl<'r, C, R> Select<'r, C, R>
@@ -171,3 +281,59 @@ where
 		}
 	}
 }
+
+impl<'r, C, R> Select<'r, C, R, Live>
+where
+	C: Connection,
+	R: DeserializeOwned,
+{
+	/// Resumes the live query if its subscription is lost, for example when the connection drops
+	///
+	/// When the subscription ends without being killed, the live query is started again and any
+	/// changes made in the meantime are replayed from the table's change feed before the new
+	/// notifications. The table must therefore be defined with a `CHANGEFEED`. Replay starts
+	/// from a position read from the server clock, which the stream moves forward periodically
+	/// while it is drained. Replay is therefore at-least-once, and notifications received in the
+	/// seconds before the subscription was lost may be delivered again. The change feed does not
+	/// tell creations and updates apart, so replayed creations may be sent as updates.
+	///
+	/// If the missed changes cannot be replayed, a notification with [`Action::Gap`] is sent
+	/// instead. Typed streams return an [`Error::LiveQueryGap`] error for it.
+	///
+	/// [`Action::Gap`]: crate::Action::Gap
+	/// [`Error::LiveQueryGap`]: crate::error::Api::LiveQueryGap
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # use futures::StreamExt;
+	/// # use surrealdb::Result;
+	/// # use surrealdb::Notification;
+	/// # #[derive(Debug, serde::Deserialize)]
+	/// # struct Person;
+	/// #
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// // Keep a change feed so missed notifications can be replayed
+	/// db.query("DEFINE TABLE person CHANGEFEED 1d").await?;
+	///
+	/// let mut stream = db.select("person").live().with_resume().await?;
+	/// # let _: Option<Result<Notification<Person>>> = stream.next().await;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn with_resume(self) -> Select<'r, C, R, Resume> {
+		Select {
+			client: self.client,
+			resource: self.resource,
+			response_type: self.response_type,
+			query_type: PhantomData,
+		}
+	}
+}
@@ -1,5 +1,7 @@
 use crate::api::conn::Command;
+use crate::api::err::Error;
 use crate::api::method::BoxFuture;
+use crate::api::opt::auth::{Jwt, SessionClaims, SessionInfo};
 use crate::api::Connection;
 use crate::api::Result;
 use crate::method::OnceLockExt;
@@ -58,3 +60,58 @@ where
 		})
 	}
 }
+
+impl<'r, C> Signin<'r, C, Jwt>
+where
+	C: Connection,
+{
+	/// Returns information about the new session along with the token
+	///
+	/// This includes the roles of system users and the record ID of record users, so they
+	/// don't need to be decoded from the token.
+	pub fn with_session_info(self) -> SigninWithInfo<'r, C> {
+		SigninWithInfo {
+			signin: self,
+		}
+	}
+}
+
+/// A signin future which resolves to information about the new session
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SigninWithInfo<'r, C: Connection> {
+	signin: Signin<'r, C, Jwt>,
+}
+
+impl<C> SigninWithInfo<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> SigninWithInfo<'static, C> {
+		SigninWithInfo {
+			signin: self.signin.into_owned(),
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for SigninWithInfo<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<SessionInfo>;
+	type IntoFuture = BoxFuture<'r, Self::Output>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let client = self.signin.client.clone();
+			let token = self.signin.await?;
+			// The claims of the token are available to the authenticated session
+			let claims: Option<SessionClaims> = client.query("RETURN $token").await?.take(0)?;
+			let claims = claims.ok_or_else(|| {
+				Error::InternalError("the session has no token after signing in".to_owned())
+			})?;
+			Ok(SessionInfo::new(token, claims))
+		})
+	}
+}
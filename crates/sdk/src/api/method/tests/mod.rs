@@ -5,26 +5,54 @@ mod protocol;
 mod server;
 mod types;
 
+use crate::api::conn::Route;
+use crate::api::conn::Router;
 use crate::api::method::tests::types::AuthParams;
 use crate::api::opt::auth::Database;
 use crate::api::opt::auth::Jwt;
 use crate::api::opt::auth::Namespace;
 use crate::api::opt::auth::Record;
 use crate::api::opt::auth::Root;
+use crate::api::opt::ConnectionState;
 use crate::api::opt::PatchOp;
+use crate::api::OnceLockExt;
 use crate::api::Response as QueryResponse;
 use crate::api::Surreal;
+use channel::Receiver;
 use protocol::Client;
 use protocol::Test;
 use semver::Version;
+use std::collections::HashSet;
 use std::ops::Bound;
+use std::sync::atomic::AtomicI64;
+use std::sync::Arc;
 use std::sync::LazyLock;
+use std::sync::OnceLock;
 use surrealdb_core::sql::statements::{BeginStatement, CommitStatement};
+use tokio::sync::watch;
+use tokio::sync::Semaphore;
 use types::User;
 use types::USER;
 
 static DB: LazyLock<Surreal<Client>> = LazyLock::new(Surreal::init);
 
+/// Builds a client on a hand-made router, returning the routes it sends for the test to answer
+fn mock_router(inflight: Option<Semaphore>) -> (Surreal<Client>, Receiver<Route>) {
+	let (route_tx, route_rx) = channel::unbounded();
+	let router = Router {
+		features: HashSet::new(),
+		sender: route_tx,
+		last_id: AtomicI64::new(0),
+		live_queries: Default::default(),
+		inflight,
+	};
+	let db = Surreal::new_from_router_waiter(
+		Arc::new(OnceLock::with_value(router)),
+		Arc::new(watch::channel(None)),
+	);
+	(db, route_rx)
+}
+
 #[tokio::test]
 async fn api() {
 	// connect to the mock server
@@ -172,6 +200,166 @@ async fn api() {
 	let _: Option<User> = DB.run("foo").await.unwrap();
 }
 
+#[tokio::test]
+async fn connect_hook_fires() {
+	let (tx, rx) = channel::unbounded();
+	let _db = Surreal::new::<Test>(())
+		.with_capacity(16)
+		.on_connect(move |event| {
+			let _ = tx.try_send(event);
+		})
+		.await
+		.unwrap();
+	let event = rx.recv().await.unwrap();
+	assert_eq!(event.state, ConnectionState::Connected);
+	assert_eq!(event.address, "test://");
+}
+
+#[tokio::test]
+async fn use_ns_db_sends_one_request() {
+	use crate::api::conn::{Command, DbResponse};
+	use surrealdb_core::sql::Value as CoreValue;
+
+	let (db, route_rx) = mock_router(None);
+	// Record every command the client sends until it is dropped
+	let server = tokio::spawn(async move {
+		let mut commands = Vec::new();
+		while let Ok(Route {
+			request,
+			response,
+		}) = route_rx.recv().await
+		{
+			commands.push(request.command);
+			response.send(Ok(DbResponse::Other(CoreValue::None))).await.unwrap();
+		}
+		commands
+	});
+	db.use_ns_db("test-ns", "test-db").await.unwrap();
+	drop(db);
+	let commands = server.await.unwrap();
+	let [Command::Use {
+		namespace: Some(ns),
+		database: Some(db),
+	}] = commands.as_slice()
+	else {
+		panic!("unexpected commands: {commands:?}");
+	};
+	assert_eq!((ns.as_str(), db.as_str()), ("test-ns", "test-db"));
+}
+
+#[tokio::test]
+async fn max_inflight_limits_concurrent_requests() {
+	use crate::api::conn::DbResponse;
+	use std::future::IntoFuture;
+	use std::sync::atomic::{AtomicUsize, Ordering};
+	use std::time::Duration;
+	use surrealdb_core::sql::Value as CoreValue;
+
+	const MAX_INFLIGHT: usize = 2;
+
+	let (db, route_rx) = mock_router(Some(Semaphore::new(MAX_INFLIGHT)));
+	// Answer every request concurrently after a delay, recording how many were pending at once
+	let inflight = Arc::new(AtomicUsize::new(0));
+	let peak = Arc::new(AtomicUsize::new(0));
+	let server = tokio::spawn({
+		let inflight = inflight.clone();
+		let peak = peak.clone();
+		async move {
+			while let Ok(Route {
+				response,
+				..
+			}) = route_rx.recv().await
+			{
+				let current = inflight.fetch_add(1, Ordering::SeqCst) + 1;
+				peak.fetch_max(current, Ordering::SeqCst);
+				let inflight = inflight.clone();
+				tokio::spawn(async move {
+					tokio::time::sleep(Duration::from_millis(20)).await;
+					inflight.fetch_sub(1, Ordering::SeqCst);
+					response.send(Ok(DbResponse::Other(CoreValue::None))).await.unwrap();
+				});
+			}
+		}
+	});
+	let requests = (0..MAX_INFLIGHT * 4).map(|_| db.health().into_future());
+	for result in futures::future::join_all(requests).await {
+		result.unwrap();
+	}
+	drop(db);
+	server.await.unwrap();
+	assert_eq!(peak.load(Ordering::SeqCst), MAX_INFLIGHT);
+}
+
+#[tokio::test]
+async fn with_transaction_retry_retries_conflicts() {
+	use crate::api::conn::DbResponse;
+	use crate::error::Db as DbError;
+	use crate::method::Stats;
+	use std::sync::atomic::{AtomicUsize, Ordering};
+
+	let (db, route_rx) = mock_router(None);
+	// Fail the first query with a transaction conflict and let the others succeed
+	let server = tokio::spawn(async move {
+		let mut conflict = true;
+		while let Ok(Route {
+			response,
+			..
+		}) = route_rx.recv().await
+		{
+			let mut result = QueryResponse::new();
+			if std::mem::take(&mut conflict) {
+				let stats = Stats {
+					execution_time: None,
+				};
+				result.results.insert(0, (stats, Err(DbError::TxRetryable.into())));
+			}
+			response.send(Ok(DbResponse::Query(result))).await.unwrap();
+		}
+	});
+	let attempts = AtomicUsize::new(0);
+	db.with_transaction_retry(3, || async {
+		attempts.fetch_add(1, Ordering::SeqCst);
+		db.query("UPDATE counter:one SET count += 1").await?.check()?;
+		Ok(())
+	})
+	.await
+	.unwrap();
+	assert_eq!(attempts.into_inner(), 2);
+	drop(db);
+	server.await.unwrap();
+}
+
+#[test]
+fn max_inflight_beyond_the_semaphore_limit_is_unlimited() {
+	let connect = Surreal::new::<Test>(()).with_max_inflight(Semaphore::MAX_PERMITS);
+	assert_eq!(connect.address.unwrap().max_inflight, Some(Semaphore::MAX_PERMITS));
+	let connect = Surreal::new::<Test>(()).with_max_inflight(usize::MAX);
+	assert_eq!(connect.address.unwrap().max_inflight, None);
+}
+
+#[test]
+fn record_exists_errors() {
+	use super::is_record_exists;
+	use crate::api::err::Error as ApiError;
+	use crate::RecordId;
+	use surrealdb_core::err::Error as DbError;
+
+	let record = RecordId::from(("user", "john"));
+	let exists = DbError::RecordExists {
+		thing: record.clone().into_inner(),
+	};
+	// Embedded engines return the typed error
+	let message = exists.to_string();
+	assert!(is_record_exists(&exists.into(), &record));
+	// Remote engines only send the message
+	assert!(is_record_exists(&ApiError::Query(message.clone()).into(), &record));
+	let other = RecordId::from(("user", "jane"));
+	assert!(!is_record_exists(&ApiError::Query(message).into(), &other));
+	let unrelated = ApiError::Query("There was a problem with the database".to_owned());
+	assert!(!is_record_exists(&unrelated.into(), &record));
+	assert!(!is_record_exists(&DbError::TxRetryable.into(), &record));
+}
+
 fn assert_send_sync(_: impl Send + Sync) {}
 
 #[test]
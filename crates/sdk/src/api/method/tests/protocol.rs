@@ -15,6 +15,7 @@ use std::sync::atomic::AtomicI64;
 use std::sync::Arc;
 use std::sync::OnceLock;
 use tokio::sync::watch;
+use tokio::sync::Semaphore;
 use url::Url;
 
 #[derive(Debug)]
@@ -50,8 +51,9 @@ impl Surreal<Client> {
 impl crate::api::Connection for Client {}
 
 impl Connection for Client {
-	fn connect(_address: Endpoint, capacity: usize) -> BoxFuture<'static, Result<Surreal<Self>>> {
+	fn connect(address: Endpoint, capacity: usize) -> BoxFuture<'static, Result<Surreal<Self>>> {
 		Box::pin(async move {
+			let inflight = address.max_inflight.map(Semaphore::new);
 			let (route_tx, route_rx) = channel::bounded(capacity);
 			let mut features = HashSet::new();
 			features.insert(ExtraFeatures::Backup);
@@ -59,6 +61,8 @@ impl Connection for Client {
 				features,
 				sender: route_tx,
 				last_id: AtomicI64::new(0),
+				live_queries: Default::default(),
+				inflight,
 			};
 			server::mock(route_rx);
 			Ok(Surreal::new_from_router_waiter(
@@ -43,7 +43,13 @@ pub(super) fn mock(route_rx: Receiver<Route>) {
 				} => Ok(DbResponse::Other(CoreValue::None)),
 				Command::Query {
 					..
+				}
+				| Command::QueryWithSession {
+					..
 				} => Ok(DbResponse::Query(QueryResponse::new())),
+				Command::RawQuery {
+					..
+				} => Ok(DbResponse::Other(CoreValue::Bytes(Vec::new().into()))),
 				Command::Create {
 					data,
 					..
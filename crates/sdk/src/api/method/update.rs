@@ -1,8 +1,10 @@
 use crate::api::conn::Command;
+use crate::api::method::return_none;
 use crate::api::method::BoxFuture;
 use crate::api::method::Content;
 use crate::api::method::Merge;
 use crate::api::method::Patch;
+use crate::api::method::ReturnNone;
 use crate::api::opt::PatchOp;
 use crate::api::opt::Resource;
 use crate::api::Connection;
@@ -27,7 +29,7 @@ pub struct Update<'r, C: Connection, R> {
 	pub(super) response_type: PhantomData<R>,
 }
 
-impl<C, R> Update<'_, C, R>
+impl<'r, C, R> Update<'r, C, R>
 where
 	C: Connection,
 {
@@ -38,6 +40,17 @@ where
 			..self
 		}
 	}
+
+	/// Updates the records without returning them
+	///
+	/// This runs the statement with `RETURN NONE`, which saves sending the records back
+	/// and deserialising them when they're not needed.
+	pub fn return_none(self) -> ReturnNone<'r, C> {
+		ReturnNone {
+			client: self.client,
+			statement: self.resource.map(|what| return_none::update(what, None)),
+		}
+	}
 }
 
 macro_rules! into_future {
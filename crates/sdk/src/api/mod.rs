@@ -10,7 +10,9 @@ use std::future::IntoFuture;
 use std::marker::PhantomData;
 use std::sync::Arc;
 use std::sync::OnceLock;
+use std::time::Duration;
 use tokio::sync::watch;
+use tokio::sync::Semaphore;
 
 macro_rules! transparent_wrapper{
 	(
@@ -130,6 +132,8 @@ mod conn;
 
 use self::conn::Router;
 use self::err::Error;
+use self::opt::ConnectionEvent;
+use self::opt::ConnectionState;
 use self::opt::Endpoint;
 use self::opt::EndpointKind;
 use self::opt::WaitFor;
@@ -175,6 +179,9 @@ where
 	/// it might be helpful to set this to a number that works best
 	/// for you.
 	///
+	/// When set, this takes precedence over the `capacity` option
+	/// specified in the query string of the endpoint URL.
+	///
 	/// # Examples
 	///
 	/// ```no_run
@@ -193,6 +200,214 @@ where
 		self.capacity = capacity;
 		self
 	}
+
+	/// Sets the maximum number of requests which can wait for a response at the same time
+	///
+	/// Once this many requests are in flight, any further requests wait for one of them to
+	/// finish before they are sent. This applies backpressure to the caller instead of letting
+	/// requests pile up on the client and the server. Notifications of live queries do not
+	/// count towards the limit.
+	///
+	/// Imports, and exports to a file, stay in flight until the whole file has been
+	/// transferred. Exports to a stream of bytes only count until the stream has started.
+	///
+	/// Setting this to `0` (the default) means the number of requests in flight is not limited.
+	/// So does any value larger than [`Semaphore::MAX_PERMITS`].
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// use surrealdb::engine::remote::ws::Ws;
+	/// use surrealdb::Surreal;
+	///
+	/// let db = Surreal::new::<Ws>("localhost:8000")
+	///     .with_max_inflight(64)
+	///     .await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn with_max_inflight(mut self, max: usize) -> Self {
+		if let Ok(endpoint) = &mut self.address {
+			let limited = (1..=Semaphore::MAX_PERMITS).contains(&max);
+			endpoint.max_inflight = limited.then_some(max);
+		}
+		self
+	}
+
+	/// Sets the interval at which keep-alive pings are sent to the server
+	///
+	/// This only applies to the WebSocket engine. Once set, the client pings
+	/// the server at this interval, keeping idle connections open through
+	/// proxies and load balancers. If nothing is received from the server
+	/// within a further interval, the connection is considered dead and the
+	/// client reconnects. A zero interval is rejected when connecting.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// use std::time::Duration;
+	/// use surrealdb::engine::remote::ws::Ws;
+	/// use surrealdb::Surreal;
+	///
+	/// let db = Surreal::new::<Ws>("localhost:8000")
+	///     .with_keepalive(Duration::from_secs(30))
+	///     .await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn with_keepalive(mut self, interval: Duration) -> Self {
+		if interval.is_zero() {
+			self.address =
+				Err(Error::InvalidParams("the keep-alive interval must not be zero".to_owned())
+					.into());
+		} else if let Ok(endpoint) = &mut self.address {
+			endpoint.keepalive = Some(interval);
+		}
+		self
+	}
+
+	/// Registers a callback invoked whenever the client connects to the database
+	///
+	/// The callback receives a [`ConnectionEvent`](crate::opt::ConnectionEvent) once the
+	/// connection is first established, and again each time the WebSocket engine reconnects
+	/// after the connection dropped. Callbacks run on a separate task, in the order the events
+	/// happened, so they never hold up the connection itself.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// use surrealdb::engine::remote::ws::Ws;
+	/// use surrealdb::Surreal;
+	///
+	/// let db = Surreal::new::<Ws>("localhost:8000")
+	///     .on_connect(|event| println!("connected to {}", event.address))
+	///     .await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn on_connect<F>(mut self, callback: F) -> Self
+	where
+		F: Fn(ConnectionEvent) + Send + Sync + 'static,
+	{
+		if let Ok(endpoint) = &mut self.address {
+			endpoint.hooks.on_connect = Some(Arc::new(callback));
+		}
+		self
+	}
+
+	/// Registers a callback invoked whenever the connection to the database drops
+	///
+	/// Only the WebSocket engine keeps a connection open, so the other engines never invoke
+	/// this callback. Callbacks run on a separate task, in the order the events happened, so
+	/// they never hold up reconnecting.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// use surrealdb::engine::remote::ws::Ws;
+	/// use surrealdb::Surreal;
+	///
+	/// let db = Surreal::new::<Ws>("localhost:8000")
+	///     .on_disconnect(|event| eprintln!("lost the connection to {}", event.address))
+	///     .await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn on_disconnect<F>(mut self, callback: F) -> Self
+	where
+		F: Fn(ConnectionEvent) + Send + Sync + 'static,
+	{
+		if let Ok(endpoint) = &mut self.address {
+			endpoint.hooks.on_disconnect = Some(Arc::new(callback));
+		}
+		self
+	}
+
+	/// Sets custom headers to send with every request
+	///
+	/// This only applies to the HTTP engine. It is useful when the server sits
+	/// behind a gateway which expects its own authentication headers. Headers
+	/// required by SurrealDB, such as `Accept`, `Content-Type` and the namespace
+	/// and database headers, take precedence over any custom header with the
+	/// same name.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// use reqwest::header::{HeaderMap, HeaderValue};
+	/// use surrealdb::engine::remote::http::Http;
+	/// use surrealdb::Surreal;
+	///
+	/// let mut headers = HeaderMap::new();
+	/// headers.insert("x-api-key", HeaderValue::from_static("secret"));
+	///
+	/// let db = Surreal::new::<Http>("localhost:8000")
+	///     .with_headers(headers)
+	///     .await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[cfg(feature = "protocol-http")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "protocol-http")))]
+	pub fn with_headers(mut self, headers: reqwest::header::HeaderMap) -> Self {
+		if let Ok(endpoint) = &mut self.address {
+			endpoint.headers = headers;
+		}
+		self
+	}
+
+	/// Sets the Rustls configuration used for secure connections
+	///
+	/// This applies to the `wss` and `https` engines. Use it to trust a private
+	/// certificate authority or to present a client certificate for mutual TLS.
+	/// When not set, the platform's root certificates are used.
+	///
+	/// WARNING: `rustls` is not stable yet. As we may need to upgrade this dependency from time to time
+	/// to keep up with its security fixes, this method is excluded from our stability guarantee.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// use std::sync::Arc;
+	/// use surrealdb::engine::remote::ws::Wss;
+	/// use surrealdb::Surreal;
+	///
+	/// # let ca_cert_der: Vec<u8> = Vec::new();
+	/// let mut roots = rustls::RootCertStore::empty();
+	/// roots.add(ca_cert_der.into()).unwrap();
+	/// let provider = Arc::new(rustls::crypto::ring::default_provider());
+	/// let config = rustls::ClientConfig::builder_with_provider(provider)
+	///     .with_safe_default_protocol_versions()
+	///     .unwrap()
+	///     .with_root_certificates(roots)
+	///     .with_no_client_auth();
+	///
+	/// let db = Surreal::new::<Wss>("db.internal:8000")
+	///     .with_tls_config(config)
+	///     .await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[cfg(feature = "rustls")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+	pub fn with_tls_config(mut self, config: rustls::ClientConfig) -> Self {
+		if let Ok(endpoint) = &mut self.address {
+			endpoint.config.tls_config = Some(opt::Tls::Rust(config));
+		}
+		self
+	}
 }
 
 impl<Client> IntoFuture for Connect<Client, Surreal<Client>>
@@ -204,9 +419,15 @@ where
 
 	fn into_future(self) -> Self::IntoFuture {
 		Box::pin(async move {
-			let endpoint = self.address?;
+			let mut endpoint = self.address?;
 			let endpoint_kind = EndpointKind::from(endpoint.url.scheme());
-			let client = Client::connect(endpoint, self.capacity).await?;
+			let capacity = match self.capacity {
+				0 => endpoint.capacity.unwrap_or_default(),
+				capacity => capacity,
+			};
+			endpoint.hooks.start(&endpoint.url);
+			let hooks = endpoint.hooks.clone();
+			let client = Client::connect(endpoint, capacity).await?;
 			if endpoint_kind.is_remote() {
 				match client.version().await {
 					Ok(mut version) => {
@@ -220,6 +441,7 @@ where
 			}
 			// Both ends of the channel are still alive at this point
 			client.waiter.0.send(Some(WaitFor::Connection)).ok();
+			hooks.emit(ConnectionState::Connected);
 			Ok(client)
 		})
 	}
@@ -238,9 +460,15 @@ where
 			if self.router.get().is_some() {
 				return Err(Error::AlreadyConnected.into());
 			}
-			let endpoint = self.address?;
+			let mut endpoint = self.address?;
 			let endpoint_kind = EndpointKind::from(endpoint.url.scheme());
-			let client = Client::connect(endpoint, self.capacity).await?;
+			let capacity = match self.capacity {
+				0 => endpoint.capacity.unwrap_or_default(),
+				capacity => capacity,
+			};
+			endpoint.hooks.start(&endpoint.url);
+			let hooks = endpoint.hooks.clone();
+			let client = Client::connect(endpoint, capacity).await?;
 			if endpoint_kind.is_remote() {
 				match client.version().await {
 					Ok(mut version) => {
@@ -258,6 +486,7 @@ where
 			self.router.set(router).map_err(|_| Error::AlreadyConnected)?;
 			// Both ends of the channel are still alive at this point
 			self.waiter.0.send(Some(WaitFor::Connection)).ok();
+			hooks.emit(ConnectionState::Connected);
 			Ok(())
 		})
 	}
@@ -267,6 +496,8 @@ where
 pub(crate) enum ExtraFeatures {
 	Backup,
 	LiveQueries,
+	SessionOverride,
+	RawResponses,
 }
 
 /// A database client instance for embedded or remote databases
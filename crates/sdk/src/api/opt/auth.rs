@@ -1,5 +1,8 @@
 //! Authentication types
 
+use crate::RecordId;
+use chrono::DateTime;
+use chrono::Utc;
 use serde::Deserialize;
 use serde::Serialize;
 use std::fmt;
@@ -140,6 +143,66 @@ impl fmt::Debug for Jwt {
 	}
 }
 
+/// Information about the session created by signing in
+///
+/// Returned by [`Signin::with_session_info`](crate::method::Signin::with_session_info).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct SessionInfo {
+	/// The token authenticating the session
+	pub token: Jwt,
+	/// The namespace the session is authenticated to, if any
+	pub namespace: Option<String>,
+	/// The database the session is authenticated to, if any
+	pub database: Option<String>,
+	/// The access method used to sign in, if any
+	pub access: Option<String>,
+	/// The roles of the system user which signed in
+	///
+	/// This is empty for record users, and for system users of servers which don't report
+	/// the roles in the token.
+	pub roles: Vec<String>,
+	/// When the token expires, if it expires at all
+	pub expires: Option<DateTime<Utc>>,
+	/// The record which signed in, for record access
+	pub record: Option<RecordId>,
+}
+
+/// The claims of a token which describe the session
+#[derive(Debug, Deserialize)]
+pub(crate) struct SessionClaims {
+	#[serde(rename = "NS")]
+	ns: Option<String>,
+	#[serde(rename = "DB")]
+	db: Option<String>,
+	#[serde(rename = "AC")]
+	ac: Option<String>,
+	#[serde(rename = "ID")]
+	id: Option<String>,
+	#[serde(rename = "RL")]
+	roles: Option<Vec<String>>,
+	exp: Option<i64>,
+}
+
+impl SessionInfo {
+	pub(crate) fn new(token: Jwt, claims: SessionClaims) -> Self {
+		// System users have roles, while the ID of a record user is its record ID
+		let record = match (&claims.roles, &claims.ac, claims.id) {
+			(None, Some(_), Some(id)) => id.parse().ok(),
+			_ => None,
+		};
+		SessionInfo {
+			token,
+			namespace: claims.ns,
+			database: claims.db,
+			access: claims.ac,
+			roles: claims.roles.unwrap_or_default(),
+			expires: claims.exp.and_then(|exp| DateTime::from_timestamp(exp, 0)),
+			record,
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
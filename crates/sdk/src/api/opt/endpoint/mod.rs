@@ -21,8 +21,10 @@ mod tikv;
 use crate::api::err::Error;
 use crate::api::Connection;
 use crate::api::Result;
+use std::time::Duration;
 use url::Url;
 
+use super::hooks::ConnectionHooks;
 use super::Config;
 
 /// A server address used to connect to the server
@@ -34,6 +36,21 @@ pub struct Endpoint {
 	#[doc(hidden)]
 	pub path: String,
 	pub(crate) config: Config,
+	pub(crate) capacity: Option<usize>,
+	pub(crate) keepalive: Option<Duration>,
+	pub(crate) max_inflight: Option<usize>,
+	pub(crate) hooks: ConnectionHooks,
+	#[cfg(feature = "protocol-http")]
+	pub(crate) headers: reqwest::header::HeaderMap,
+	// The configuration options which were set in the query string of the URL
+	query_options: QueryOptions,
+}
+
+#[derive(Debug, Clone, Default)]
+struct QueryOptions {
+	strict: Option<bool>,
+	#[cfg(any(feature = "native-tls", feature = "rustls"))]
+	tls_insecure: bool,
 }
 
 impl Endpoint {
@@ -42,7 +59,87 @@ impl Endpoint {
 			url,
 			path: String::new(),
 			config: Default::default(),
+			capacity: None,
+			keepalive: None,
+			max_inflight: None,
+			hooks: Default::default(),
+			#[cfg(feature = "protocol-http")]
+			headers: Default::default(),
+			query_options: Default::default(),
+		}
+	}
+
+	/// Applies any options specified in the query string of the URL
+	///
+	/// The supported options are `strict`, `capacity` and `tls_insecure`. They
+	/// are removed from the URL once applied. Unknown options, or options with
+	/// invalid values, result in an error.
+	///
+	/// As `tls_insecure` disables the verification of server certificates, a
+	/// warning is logged whenever it is enabled.
+	pub(crate) fn with_query_options(mut self) -> Result<Self> {
+		if self.url.query().is_none() {
+			return Ok(self);
+		}
+		let options: Vec<(String, String)> = self.url.query_pairs().into_owned().collect();
+		for (key, value) in options {
+			let invalid = || Error::InvalidEndpointOption {
+				key: key.clone(),
+				value: value.clone(),
+			};
+			match key.as_str() {
+				"strict" => {
+					let strict = parse_flag(&value).ok_or_else(invalid)?;
+					self.config.strict = strict;
+					self.query_options.strict = Some(strict);
+				}
+				"capacity" => {
+					self.capacity = Some(value.parse().map_err(|_| invalid())?);
+				}
+				#[cfg(any(feature = "native-tls", feature = "rustls"))]
+				"tls_insecure" => {
+					if parse_flag(&value).ok_or_else(invalid)? {
+						warn!(
+							"TLS certificate verification is disabled for {} by the `tls_insecure` option; do not use this in production",
+							self.url.host_str().unwrap_or_default()
+						);
+						self.config.tls_config = Some(super::Tls::insecure()?);
+						self.query_options.tls_insecure = true;
+					}
+				}
+				_ => return Err(invalid().into()),
+			}
+		}
+		self.url.set_query(None);
+		Ok(self)
+	}
+
+	/// Replaces the configuration, keeping the options set in the query string of the URL
+	///
+	/// A configuration which enables `strict` when the URL disables it, or which sets a TLS
+	/// configuration when the URL sets `tls_insecure`, conflicts with the URL and results in
+	/// an error.
+	pub(crate) fn with_config(mut self, config: Config) -> Result<Self> {
+		let conflict = |key: &str| Error::ConflictingEndpointOption {
+			key: key.to_owned(),
+		};
+		#[cfg(any(feature = "native-tls", feature = "rustls"))]
+		let tls_config = self.config.tls_config.take();
+		self.config = config;
+		if let Some(strict) = self.query_options.strict {
+			if self.config.strict && !strict {
+				return Err(conflict("strict").into());
+			}
+			self.config.strict = strict;
 		}
+		#[cfg(any(feature = "native-tls", feature = "rustls"))]
+		if self.query_options.tls_insecure {
+			if self.config.tls_config.is_some() {
+				return Err(conflict("tls_insecure").into());
+			}
+			self.config.tls_config = tls_config;
+		}
+		Ok(self)
 	}
 
 	#[doc(hidden)]
@@ -62,6 +159,14 @@ pub trait IntoEndpoint<Scheme> {
 	fn into_endpoint(self) -> Result<Endpoint>;
 }
 
+fn parse_flag(value: &str) -> Option<bool> {
+	match value {
+		"" | "true" | "1" => Some(true),
+		"false" | "0" => Some(false),
+		_ => None,
+	}
+}
+
 fn replace_tilde(path: &str) -> String {
 	if path.starts_with("~/") {
 		let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_owned());
@@ -112,6 +217,70 @@ mod tests {
 			assert_eq!(converted, format!("{scheme}{path}"), "failed to convert `{path}`");
 		}
 	}
+
+	#[test]
+	fn test_query_options() {
+		let url = Url::parse("ws://localhost:8000/rpc?strict=true&capacity=100").unwrap();
+		let endpoint = Endpoint::new(url).with_query_options().unwrap();
+		assert!(endpoint.config.strict);
+		assert_eq!(endpoint.capacity, Some(100));
+		assert_eq!(endpoint.url.as_str(), "ws://localhost:8000/rpc");
+	}
+
+	#[cfg(any(feature = "native-tls", feature = "rustls"))]
+	#[test]
+	fn test_query_options_tls_insecure() {
+		let url = Url::parse("wss://localhost:8000?tls_insecure&strict=false").unwrap();
+		let endpoint = Endpoint::new(url).with_query_options().unwrap();
+		assert!(!endpoint.config.strict);
+		assert!(endpoint.config.tls_config.is_some());
+	}
+
+	#[test]
+	fn test_query_options_invalid() {
+		let url = Url::parse("ws://localhost:8000?strict=maybe").unwrap();
+		let error = Endpoint::new(url).with_query_options().unwrap_err();
+		assert!(matches!(
+			error,
+			crate::Error::Api(Error::InvalidEndpointOption { ref key, .. }) if key == "strict"
+		));
+		let url = Url::parse("ws://localhost:8000?unknown=1").unwrap();
+		let error = Endpoint::new(url).with_query_options().unwrap_err();
+		assert!(matches!(
+			error,
+			crate::Error::Api(Error::InvalidEndpointOption { ref key, .. }) if key == "unknown"
+		));
+	}
+
+	#[test]
+	fn test_query_options_with_config() {
+		let config = Config::new().query_timeout(Duration::from_secs(5));
+		let url = Url::parse("ws://localhost:8000?strict=true&capacity=100").unwrap();
+		let endpoint =
+			Endpoint::new(url).with_query_options().unwrap().with_config(config).unwrap();
+		assert!(endpoint.config.strict);
+		assert_eq!(endpoint.config.query_timeout, Some(Duration::from_secs(5)));
+		assert_eq!(endpoint.capacity, Some(100));
+		let url = Url::parse("ws://localhost:8000?strict=false").unwrap();
+		let error = Endpoint::new(url)
+			.with_query_options()
+			.unwrap()
+			.with_config(Config::new().strict())
+			.unwrap_err();
+		assert!(matches!(
+			error,
+			crate::Error::Api(Error::ConflictingEndpointOption { ref key }) if key == "strict"
+		));
+	}
+
+	#[cfg(any(feature = "native-tls", feature = "rustls"))]
+	#[test]
+	fn test_query_options_tls_insecure_with_config() {
+		let url = Url::parse("wss://localhost:8000?tls_insecure").unwrap();
+		let endpoint =
+			Endpoint::new(url).with_query_options().unwrap().with_config(Config::new()).unwrap();
+		assert!(endpoint.config.tls_config.is_some());
+	}
 }
 
 #[derive(Debug)]
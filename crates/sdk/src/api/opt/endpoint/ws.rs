@@ -17,7 +17,8 @@ macro_rules! endpoints {
 
 				fn into_endpoint(self) -> Result<Endpoint> {
 					let url = format!("ws://{self}");
-					Ok(Endpoint::new(Url::parse(&url).map_err(|_| Error::InvalidUrl(url))?))
+					Endpoint::new(Url::parse(&url).map_err(|_| Error::InvalidUrl(url))?)
+						.with_query_options()
 				}
 			}
 
@@ -25,9 +26,7 @@ macro_rules! endpoints {
 				type Client = Client;
 
 				fn into_endpoint(self) -> Result<Endpoint> {
-					let mut endpoint = IntoEndpoint::<Ws>::into_endpoint(self.0)?;
-					endpoint.config = self.1;
-					Ok(endpoint)
+					IntoEndpoint::<Ws>::into_endpoint(self.0)?.with_config(self.1)
 				}
 			}
 
@@ -36,7 +35,8 @@ macro_rules! endpoints {
 
 				fn into_endpoint(self) -> Result<Endpoint> {
 					let url = format!("wss://{self}");
-					Ok(Endpoint::new(Url::parse(&url).map_err(|_| Error::InvalidUrl(url))?))
+					Endpoint::new(Url::parse(&url).map_err(|_| Error::InvalidUrl(url))?)
+						.with_query_options()
 				}
 			}
 
@@ -44,9 +44,7 @@ macro_rules! endpoints {
 				type Client = Client;
 
 				fn into_endpoint(self) -> Result<Endpoint> {
-					let mut endpoint = IntoEndpoint::<Wss>::into_endpoint(self.0)?;
-					endpoint.config = self.1;
-					Ok(endpoint)
+					IntoEndpoint::<Wss>::into_endpoint(self.0)?.with_config(self.1)
 				}
 			}
 		)*
@@ -0,0 +1,94 @@
+use channel::Sender;
+use std::fmt;
+use std::sync::Arc;
+use url::Url;
+
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::spawn;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_futures::spawn_local as spawn;
+
+type Callback = Arc<dyn Fn(ConnectionEvent) + Send + Sync>;
+
+/// The state of the connection reported by a [`ConnectionEvent`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConnectionState {
+	/// The client connected to the database for the first time
+	Connected,
+	/// The client connected to the database again after the connection dropped
+	Reconnected,
+	/// The connection to the database dropped
+	Disconnected,
+}
+
+/// A change in the state of the connection to the database
+///
+/// Passed to the callbacks registered with [`Connect::on_connect`](crate::Connect::on_connect)
+/// and [`Connect::on_disconnect`](crate::Connect::on_disconnect).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ConnectionEvent {
+	/// The address of the database
+	pub address: String,
+	/// The new state of the connection
+	pub state: ConnectionState,
+}
+
+/// The callbacks invoked when the state of the connection changes
+#[derive(Clone, Default)]
+pub(crate) struct ConnectionHooks {
+	pub(crate) on_connect: Option<Callback>,
+	pub(crate) on_disconnect: Option<Callback>,
+	address: String,
+	sender: Option<Sender<ConnectionEvent>>,
+}
+
+impl fmt::Debug for ConnectionHooks {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ConnectionHooks")
+			.field("on_connect", &self.on_connect.is_some())
+			.field("on_disconnect", &self.on_disconnect.is_some())
+			.field("address", &self.address)
+			.finish()
+	}
+}
+
+impl ConnectionHooks {
+	/// Spawns the task which runs the callbacks, so they never hold up the connection
+	pub(crate) fn start(&mut self, address: &Url) {
+		if self.on_connect.is_none() && self.on_disconnect.is_none() {
+			return;
+		}
+		let (sender, receiver) = channel::unbounded::<ConnectionEvent>();
+		let on_connect = self.on_connect.clone();
+		let on_disconnect = self.on_disconnect.clone();
+		spawn(async move {
+			// Events are delivered in order until every copy of the hooks is dropped
+			while let Ok(event) = receiver.recv().await {
+				let callback = match event.state {
+					ConnectionState::Disconnected => &on_disconnect,
+					_ => &on_connect,
+				};
+				if let Some(callback) = callback {
+					callback(event);
+				}
+			}
+		});
+		self.address = address.to_string();
+		self.sender = Some(sender);
+	}
+
+	/// Reports a change in the state of the connection
+	pub(crate) fn emit(&self, state: ConnectionState) {
+		if let Some(sender) = &self.sender {
+			let event = ConnectionEvent {
+				address: self.address.clone(),
+				state,
+			};
+			// The channel is unbounded, so this only fails once the task is gone
+			sender.try_send(event).ok();
+		}
+	}
+}
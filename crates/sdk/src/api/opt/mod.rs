@@ -8,17 +8,21 @@ pub mod capabilities;
 mod config;
 mod endpoint;
 mod export;
+mod hooks;
 mod query;
 mod resource;
+mod session;
 mod tls;
 
 pub use config::*;
 pub use endpoint::*;
 pub use export::*;
+pub use hooks::{ConnectionEvent, ConnectionState};
 pub use query::*;
 pub use resource::*;
 use serde_content::Serializer;
 use serde_content::Value as Content;
+pub use session::*;
 #[cfg(any(feature = "native-tls", feature = "rustls"))]
 pub use tls::*;
 
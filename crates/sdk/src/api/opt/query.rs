@@ -508,9 +508,11 @@ where
 			})?;
 		Ok(method::QueryStream(Either::Left(Stream {
 			client: stream.client.clone(),
-			id: mem::take(&mut stream.id),
+			state: stream.state.clone(),
 			rx: stream.rx.take(),
+			queued: mem::take(&mut stream.queued),
 			response_type: PhantomData,
+			resume: stream.resume.take(),
 		})))
 	}
 }
@@ -539,9 +541,11 @@ where
 			};
 			streams.push(Stream {
 				client: stream.client.clone(),
-				id: mem::take(&mut stream.id),
+				state: stream.state.clone(),
 				rx: stream.rx.take(),
+				queued: mem::take(&mut stream.queued),
 				response_type: PhantomData,
+				resume: stream.resume.take(),
 			});
 		}
 		Ok(method::QueryStream(Either::Right(select_all(streams))))
@@ -0,0 +1,37 @@
+use crate::opt::auth::Jwt;
+
+/// Overrides applied to a copy of the connection's session for a single query
+///
+/// Used with [`Surreal::query_with_session`](crate::Surreal::query_with_session). Anything left
+/// unset is taken from the connection's current session.
+#[derive(Debug, Clone, Default)]
+pub struct SessionOverride {
+	pub(crate) namespace: Option<String>,
+	pub(crate) database: Option<String>,
+	pub(crate) token: Option<Jwt>,
+}
+
+impl SessionOverride {
+	/// Create an override which leaves the session unchanged
+	pub fn new() -> Self {
+		Default::default()
+	}
+
+	/// Run the query in the given namespace
+	pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+		self.namespace = Some(namespace.into());
+		self
+	}
+
+	/// Run the query in the given database
+	pub fn database(mut self, database: impl Into<String>) -> Self {
+		self.database = Some(database.into());
+		self
+	}
+
+	/// Run the query authenticated with the given token
+	pub fn token(mut self, token: impl Into<Jwt>) -> Self {
+		self.token = Some(token.into());
+		self
+	}
+}
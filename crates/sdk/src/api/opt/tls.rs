@@ -15,3 +15,83 @@ pub enum Tls {
 	#[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
 	Rust(rustls::ClientConfig),
 }
+
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+impl Tls {
+	/// A TLS configuration which accepts any server certificate
+	///
+	/// WARNING: this disables certificate and hostname verification, making the connection
+	/// vulnerable to man-in-the-middle attacks. Only use it for local development or testing.
+	pub(crate) fn insecure() -> crate::api::Result<Self> {
+		#[cfg(feature = "rustls")]
+		{
+			use std::sync::Arc;
+
+			let provider = Arc::new(rustls::crypto::ring::default_provider());
+			let config = rustls::ClientConfig::builder_with_provider(provider.clone())
+				.with_safe_default_protocol_versions()
+				.map_err(|error| crate::api::err::Error::InternalError(error.to_string()))?
+				.dangerous()
+				.with_custom_certificate_verifier(Arc::new(insecure::NoVerification(provider)))
+				.with_no_client_auth();
+			Ok(Self::Rust(config))
+		}
+		#[cfg(not(feature = "rustls"))]
+		{
+			let config = native_tls::TlsConnector::builder()
+				.danger_accept_invalid_certs(true)
+				.danger_accept_invalid_hostnames(true)
+				.build()
+				.map_err(|error| crate::api::err::Error::InternalError(error.to_string()))?;
+			Ok(Self::Native(config))
+		}
+	}
+}
+
+#[cfg(feature = "rustls")]
+mod insecure {
+	use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+	use rustls::crypto::{verify_tls12_signature, verify_tls13_signature, CryptoProvider};
+	use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+	use rustls::{DigitallySignedStruct, SignatureScheme};
+	use std::sync::Arc;
+
+	/// Skips server certificate verification but still checks handshake signatures
+	#[derive(Debug)]
+	pub(super) struct NoVerification(pub(super) Arc<CryptoProvider>);
+
+	impl ServerCertVerifier for NoVerification {
+		fn verify_server_cert(
+			&self,
+			_end_entity: &CertificateDer<'_>,
+			_intermediates: &[CertificateDer<'_>],
+			_server_name: &ServerName<'_>,
+			_ocsp_response: &[u8],
+			_now: UnixTime,
+		) -> Result<ServerCertVerified, rustls::Error> {
+			Ok(ServerCertVerified::assertion())
+		}
+
+		fn verify_tls12_signature(
+			&self,
+			message: &[u8],
+			cert: &CertificateDer<'_>,
+			dss: &DigitallySignedStruct,
+		) -> Result<HandshakeSignatureValid, rustls::Error> {
+			verify_tls12_signature(message, cert, dss, &self.0.signature_verification_algorithms)
+		}
+
+		fn verify_tls13_signature(
+			&self,
+			message: &[u8],
+			cert: &CertificateDer<'_>,
+			dss: &DigitallySignedStruct,
+		) -> Result<HandshakeSignatureValid, rustls::Error> {
+			verify_tls13_signature(message, cert, dss, &self.0.signature_verification_algorithms)
+		}
+
+		fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+			self.0.signature_verification_algorithms.supported_schemes()
+		}
+	}
+}
@@ -1,6 +1,8 @@
 use crate::Error;
 use chrono::{DateTime, Utc};
 use revision::revisioned;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::{
 	cmp::{Ordering, PartialEq, PartialOrd},
@@ -18,6 +20,9 @@ use surrealdb_core::{
 };
 use uuid::Uuid;
 
+#[cfg(feature = "cbor")]
+use surrealdb_core::rpc::format::cbor::Cbor;
+
 mod obj;
 pub use obj::{IntoIter, Iter, IterMut, Object};
 
@@ -247,6 +252,72 @@ transparent_wrapper!(
 );
 impl_serialize_wrapper!(Number);
 
+macro_rules! number_from_prim {
+	($($prim: ty),*) => {
+		$(
+			impl From<$prim> for Number {
+				fn from(v: $prim) -> Self {
+					Self(CoreNumber::from(v))
+				}
+			}
+		)*
+	};
+}
+
+number_from_prim!(i8, i16, i32, i64, u8, u16, u32, f32, f64);
+
+impl Number {
+	fn from_wide_int(
+		int: Option<i64>,
+		decimal: Option<Decimal>,
+		from: &'static str,
+	) -> Result<Self, ConversionError> {
+		match (int, decimal) {
+			(Some(v), _) => Ok(Self(CoreNumber::Int(v))),
+			(None, Some(v)) => Ok(Self(CoreNumber::Decimal(v))),
+			(None, None) => Err(ConversionError {
+				from,
+				expected: "number",
+			}),
+		}
+	}
+}
+
+/// Converts an integer into a number
+///
+/// Integers above `i64::MAX` are stored as a decimal, so this conversion never fails.
+impl TryFrom<u64> for Number {
+	type Error = ConversionError;
+
+	fn try_from(v: u64) -> Result<Self, Self::Error> {
+		Self::from_wide_int(v.to_i64(), Decimal::from_u64(v), "u64")
+	}
+}
+
+/// Converts an integer into a number
+///
+/// Integers which do not fit in an `i64` are stored as a decimal. Returns an error if the
+/// integer is too large for a decimal as well, which holds up to 96 bits.
+impl TryFrom<u128> for Number {
+	type Error = ConversionError;
+
+	fn try_from(v: u128) -> Result<Self, Self::Error> {
+		Self::from_wide_int(v.to_i64(), Decimal::from_u128(v), "u128")
+	}
+}
+
+/// Converts an integer into a number
+///
+/// Integers which do not fit in an `i64` are stored as a decimal. Returns an error if the
+/// integer is too large for a decimal as well, which holds up to 96 bits.
+impl TryFrom<i128> for Number {
+	type Error = ConversionError;
+
+	fn try_from(v: i128) -> Result<Self, Self::Error> {
+		Self::from_wide_int(v.to_i64(), Decimal::from_i128(v), "i128")
+	}
+}
+
 transparent_wrapper!(
 	#[derive(Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
 	pub struct Value(pub(crate) CoreValue)
@@ -310,18 +381,406 @@ impl Value {
 	}
 }
 
+impl Value {
+	/// Converts this value into a concrete type
+	///
+	/// This behaves like [`from_value`], but when the conversion fails the error
+	/// records the path to the offending field, such as `.address.city`.
+	///
+	/// ```
+	/// use serde::Deserialize;
+	/// use surrealdb::Value;
+	///
+	/// #[derive(Debug, Deserialize)]
+	/// struct Person {
+	///     name: String,
+	/// }
+	///
+	/// let value: Value = "{ name: 'Tobie' }".parse().unwrap();
+	/// let person: Person = value.into_typed().unwrap();
+	/// assert_eq!(person.name, "Tobie");
+	/// ```
+	pub fn into_typed<T: DeserializeOwned>(self) -> Result<T, Error> {
+		surrealdb_core::sql::from_value_with_path(self.0).map_err(|error| {
+			let (path, error) = *error;
+			crate::api::err::Error::FromValueAt {
+				path,
+				error: error.to_string(),
+			}
+			.into()
+		})
+	}
+
+	/// Converts this value into a list of values
+	///
+	/// An array is returned as is, while `NONE` and `NULL` become an empty list. Any other
+	/// value becomes a list containing just that value. This is useful when a result may be
+	/// either a single record or many.
+	///
+	/// ```
+	/// use surrealdb::Value;
+	///
+	/// let value: Value = "{ name: 'Tobie' }".parse().unwrap();
+	/// assert_eq!(value.coerce_to_array().len(), 1);
+	///
+	/// let value: Value = "NONE".parse().unwrap();
+	/// assert!(value.coerce_to_array().is_empty());
+	/// ```
+	pub fn coerce_to_array(self) -> Vec<Value> {
+		match self.0 {
+			CoreValue::Array(array) => Value::core_to_array(array.0),
+			CoreValue::None | CoreValue::Null => Vec::new(),
+			value => vec![Value(value)],
+		}
+	}
+
+	/// Interprets this value as a record id
+	///
+	/// Record ids are returned as is, strings are parsed as record ids and objects are read
+	/// from their `tb` and `id` fields. Returns `None` for any other value, or when the
+	/// string or object does not describe a valid record id.
+	///
+	/// ```
+	/// use surrealdb::{RecordId, Value};
+	///
+	/// let value: Value = "'person:john'".parse().unwrap();
+	/// assert_eq!(value.coerce_to_record_id(), Some(RecordId::from(("person", "john"))));
+	///
+	/// let value: Value = "{ tb: 'person', id: 'john' }".parse().unwrap();
+	/// assert_eq!(value.coerce_to_record_id(), Some(RecordId::from(("person", "john"))));
+	/// ```
+	pub fn coerce_to_record_id(&self) -> Option<RecordId> {
+		match &self.0 {
+			CoreValue::Thing(thing) => Some(RecordId::from_inner(thing.clone())),
+			CoreValue::Strand(strand) => syn::thing(strand.as_str()).ok().map(RecordId::from_inner),
+			CoreValue::Object(object) => {
+				let CoreValue::Strand(tb) = object.get("tb")? else {
+					return None;
+				};
+				let id = CoreId::try_from(object.get("id")?.clone()).ok()?;
+				Some(RecordId::from_inner(CoreThing::from((tb.as_str(), id))))
+			}
+			_ => None,
+		}
+	}
+
+	/// Converts this value into a string
+	///
+	/// Returns an error if the value is not a string.
+	///
+	/// ```
+	/// use surrealdb::Value;
+	///
+	/// let value: Value = "'Tobie'".parse().unwrap();
+	/// assert_eq!(value.try_into_string().unwrap(), "Tobie");
+	///
+	/// let value: Value = "1".parse().unwrap();
+	/// let error = value.try_into_string().unwrap_err();
+	/// assert_eq!(error.to_string(), "failed to convert into `string` from value with type `int`");
+	/// ```
+	pub fn try_into_string(self) -> Result<String, Error> {
+		match self.0 {
+			CoreValue::Strand(strand) => Ok(strand.0),
+			value => Err(ConversionError::new(&value, "string").into()),
+		}
+	}
+
+	/// Converts this value into an integer
+	///
+	/// Floats and decimals are converted as long as they are whole numbers which fit in an
+	/// `i64`. Any other value returns an error.
+	pub fn try_into_i64(self) -> Result<i64, Error> {
+		Self::core_to_i64(&self.0).ok_or_else(|| ConversionError::new(&self.0, "int").into())
+	}
+
+	fn core_to_i64(value: &CoreValue) -> Option<i64> {
+		match value {
+			CoreValue::Number(CoreNumber::Int(v)) => Some(*v),
+			// The upper bound is exclusive as `i64::MAX` can not be represented as a float
+			CoreValue::Number(CoreNumber::Float(v))
+				if v.fract() == 0.0 && *v >= i64::MIN as f64 && *v < i64::MAX as f64 =>
+			{
+				Some(*v as i64)
+			}
+			CoreValue::Number(CoreNumber::Decimal(v)) if v.is_integer() => v.to_i64(),
+			_ => None,
+		}
+	}
+
+	/// Converts this value into a float
+	///
+	/// Integers and decimals are converted to the closest float. Any other value returns an
+	/// error.
+	pub fn try_into_f64(self) -> Result<f64, Error> {
+		match self.0 {
+			CoreValue::Number(number) => Ok(number.to_float()),
+			value => Err(ConversionError::new(&value, "float").into()),
+		}
+	}
+
+	/// Returns the elements of an array as integers
+	///
+	/// Elements are converted as with [`Value::try_into_i64`]. Returns `None` if the value is not
+	/// an array, or if any element is not a whole number which fits in an `i64`.
+	pub fn as_i64_vec(&self) -> Option<Vec<i64>> {
+		match &self.0 {
+			CoreValue::Array(array) => array.iter().map(Self::core_to_i64).collect(),
+			_ => None,
+		}
+	}
+
+	/// Returns the elements of an array as floats
+	///
+	/// Integers and decimals are converted to the closest float. Returns `None` if the value is
+	/// not an array, or if any element is not a number.
+	pub fn as_f64_vec(&self) -> Option<Vec<f64>> {
+		match &self.0 {
+			CoreValue::Array(array) => array
+				.iter()
+				.map(|value| match value {
+					CoreValue::Number(number) => Some(number.to_float()),
+					_ => None,
+				})
+				.collect(),
+			_ => None,
+		}
+	}
+
+	/// Converts this value into a boolean
+	///
+	/// Returns an error if the value is not a boolean. Use [`Value::is_truthy`] to check
+	/// other values in a boolean context.
+	pub fn try_into_bool(self) -> Result<bool, Error> {
+		match self.0 {
+			CoreValue::Bool(v) => Ok(v),
+			value => Err(ConversionError::new(&value, "bool").into()),
+		}
+	}
+
+	/// Converts this value into an object
+	///
+	/// Returns an error if the value is not an object.
+	pub fn try_into_object(self) -> Result<Object, Error> {
+		match self.0 {
+			CoreValue::Object(object) => Ok(Object::from_inner(object)),
+			value => Err(ConversionError::new(&value, "object").into()),
+		}
+	}
+
+	/// Converts this value into a list of values
+	///
+	/// Returns an error if the value is not an array. Use [`Value::coerce_to_array`] to also
+	/// accept single values.
+	pub fn try_into_array(self) -> Result<Vec<Value>, Error> {
+		match self.0 {
+			CoreValue::Array(array) => Ok(Value::core_to_array(array.0)),
+			value => Err(ConversionError::new(&value, "array").into()),
+		}
+	}
+
+	/// Folds the elements of an array into a single result
+	///
+	/// The closure is called with the accumulator and each element in turn, and the first
+	/// error it returns stops the fold. Returns an error if the value is not an array.
+	///
+	/// ```
+	/// use surrealdb::Value;
+	///
+	/// let value: Value = "[1, 2, 3]".parse().unwrap();
+	/// let sum = value.try_fold(0, |sum, v| Ok(sum + v.clone().try_into_i64()?)).unwrap();
+	/// assert_eq!(sum, 6);
+	/// ```
+	pub fn try_fold<B, F>(&self, init: B, f: F) -> Result<B, Error>
+	where
+		F: FnMut(B, &Value) -> Result<B, Error>,
+	{
+		match &self.0 {
+			CoreValue::Array(array) => Value::core_to_array_ref(&array.0).iter().try_fold(init, f),
+			value => Err(ConversionError::new(value, "array").into()),
+		}
+	}
+
+	/// Reduces the elements of an array to a single value
+	///
+	/// This behaves like [`Value::try_fold`], using the first element as the initial
+	/// accumulator. Returns `None` if the array is empty.
+	pub fn reduce<F>(&self, mut f: F) -> Result<Option<Value>, Error>
+	where
+		F: FnMut(Value, &Value) -> Result<Value, Error>,
+	{
+		match &self.0 {
+			CoreValue::Array(array) => {
+				let mut iter = Value::core_to_array_ref(&array.0).iter();
+				let Some(first) = iter.next() else {
+					return Ok(None);
+				};
+				iter.try_fold(first.clone(), &mut f).map(Some)
+			}
+			value => Err(ConversionError::new(value, "array").into()),
+		}
+	}
+
+	/// Iterates over the entries of an array or an object
+	///
+	/// Array elements are yielded with no key and object fields with their key, in key
+	/// order. Any other value yields nothing.
+	///
+	/// ```
+	/// use surrealdb::Value;
+	///
+	/// let value: Value = "{ a: 1, b: 2 }".parse().unwrap();
+	/// let keys: Vec<_> = value.entries().filter_map(|(key, _)| key).collect();
+	/// assert_eq!(keys, ["a", "b"]);
+	/// ```
+	pub fn entries(self) -> Box<dyn Iterator<Item = (Option<String>, Value)>> {
+		match self.0 {
+			CoreValue::Array(array) => {
+				Box::new(array.0.into_iter().map(|v| (None, Value::from_inner(v))))
+			}
+			CoreValue::Object(object) => {
+				Box::new(object.0.into_iter().map(|(k, v)| (Some(k), Value::from_inner(v))))
+			}
+			_ => Box::new(std::iter::empty()),
+		}
+	}
+
+	/// Checks whether this value is truthy, following the rules SurrealQL uses in conditions
+	///
+	/// - `NONE` and `NULL` are falsy.
+	/// - Booleans are their own value.
+	/// - Numbers are falsy when zero, whether integer, float or decimal.
+	/// - Strings, arrays and objects are falsy when empty.
+	/// - Durations are falsy when zero.
+	/// - Datetimes, UUIDs, record ids and geometries are always truthy.
+	/// - Bytes and every other value are falsy.
+	///
+	/// ```
+	/// use surrealdb::Value;
+	///
+	/// let value: Value = "[1]".parse().unwrap();
+	/// assert!(value.is_truthy());
+	///
+	/// let value: Value = "''".parse().unwrap();
+	/// assert!(!value.is_truthy());
+	/// ```
+	pub fn is_truthy(&self) -> bool {
+		self.0.is_truthy()
+	}
+
+	/// Returns a deterministic byte representation of this value, suitable for hashing
+	///
+	/// Values which are equal produce the same bytes. Object keys are written in sorted
+	/// order and numbers are written by value rather than by type, so `1`, `1.0f` and `1dec`
+	/// are encoded the same. The encoding may change between releases, so it should not be
+	/// persisted.
+	pub fn canonical_bytes(&self) -> Vec<u8> {
+		self.0.canonical_bytes()
+	}
+
+	/// Returns a hash of the [canonical bytes](Value::canonical_bytes) of this value
+	///
+	/// ```
+	/// use surrealdb::Value;
+	///
+	/// let a: Value = "{ a: 1, b: 2 }".parse().unwrap();
+	/// let b: Value = "{ b: 2.0f, a: 1dec }".parse().unwrap();
+	/// assert_eq!(a.content_hash(), b.content_hash());
+	/// ```
+	pub fn content_hash(&self) -> u64 {
+		self.0.content_hash()
+	}
+
+	/// Formats this value as a SurrealQL literal which evaluates back to the same value
+	///
+	/// Strings are quoted with any quotes and backslashes escaped, so their content can
+	/// never end the literal early. Datetimes, UUIDs and durations use their typed literal
+	/// forms, such as `d'2024-01-02T03:04:05Z'`, and record ids are written unquoted, with
+	/// any key which needs it escaped. There is no literal for bytes, so they are written as
+	/// a call to `encoding::base64::decode`. Prefer binding values as query parameters, and
+	/// use this only where a parameter can not be used.
+	///
+	/// ```
+	/// use surrealdb::Value;
+	///
+	/// let value = Value::from_inner("it's".into());
+	/// assert_eq!(value.to_sql_literal(), r#""it's""#);
+	/// ```
+	pub fn to_sql_literal(&self) -> String {
+		self.0.to_string()
+	}
+}
+
+#[cfg(feature = "cbor")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cbor")))]
+impl Value {
+	/// Encodes this value as CBOR
+	///
+	/// The encoding uses the same tags as the CBOR format of the RPC protocol, so record ids,
+	/// durations, datetimes and the other SurrealQL types keep their type when decoded.
+	///
+	/// ```
+	/// use surrealdb::Value;
+	///
+	/// let value: Value = "{ id: person:tobie, age: 1y }".parse().unwrap();
+	/// let bytes = value.to_cbor().unwrap();
+	/// assert_eq!(Value::from_cbor(&bytes).unwrap(), value);
+	/// ```
+	pub fn to_cbor(&self) -> Result<Vec<u8>, Error> {
+		let cbor = Cbor::try_from(self.0.clone())
+			.map_err(|error| crate::api::err::Error::Cbor(error.to_owned()))?;
+		let mut bytes = Vec::new();
+		ciborium::into_writer(&cbor.0, &mut bytes)
+			.map_err(|error| crate::api::err::Error::Cbor(error.to_string()))?;
+		Ok(bytes)
+	}
+
+	/// Decodes a value from CBOR produced by [`Value::to_cbor`]
+	pub fn from_cbor(bytes: &[u8]) -> Result<Value, Error> {
+		let data = ciborium::from_reader(bytes)
+			.map_err(|error| crate::api::err::Error::Cbor(error.to_string()))?;
+		let value = CoreValue::try_from(Cbor(data))
+			.map_err(|error| crate::api::err::Error::Cbor(error.to_owned()))?;
+		Ok(Value::from_inner(value))
+	}
+}
+
+/// A value did not have the type it was being converted into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ConversionError {
 	from: &'static str,
 	expected: &'static str,
 }
 
+impl ConversionError {
+	fn new(value: &CoreValue, expected: &'static str) -> Self {
+		Self {
+			from: value.kindof(),
+			expected,
+		}
+	}
+
+	/// The type of the value which could not be converted, such as `string` or `float`
+	pub fn from(&self) -> &'static str {
+		self.from
+	}
+
+	/// The type the value was being converted into
+	pub fn expected(&self) -> &'static str {
+		self.expected
+	}
+}
+
 impl fmt::Display for ConversionError {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		writeln!(
-			f,
-			"failed to convert into `{}` from value with type `{:?}`",
-			self.expected, self.from
-		)
+		write!(f, "failed to convert into `{}` from value with type `{}`", self.expected, self.from)
+	}
+}
+
+impl std::error::Error for ConversionError {}
+
+impl From<ConversionError> for Error {
+	fn from(error: ConversionError) -> Self {
+		crate::api::err::Error::ValueConversion(error).into()
 	}
 }
 
@@ -334,6 +793,12 @@ pub enum Action {
 	Create,
 	Update,
 	Delete,
+	/// Notifications were missed while a resumed live query was disconnected
+	///
+	/// This is only sent by live queries started with
+	/// [`Select::with_resume`](crate::method::Select::with_resume) when the missed
+	/// notifications could not be replayed from the change feed.
+	Gap,
 }
 
 impl Action {
@@ -373,3 +838,385 @@ impl Notification<CoreValue> {
 		})
 	}
 }
+
+/// The state of a record before and after a change
+///
+/// This is the data of the notifications returned by [`Surreal::live_diff`](crate::Surreal::live_diff).
+/// `before` is `None` for newly created records and `after` is `None` for deleted records.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct Change<R> {
+	pub before: Option<R>,
+	pub after: Option<R>,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::api::err::Error as ApiError;
+
+	#[derive(Debug, Deserialize)]
+	#[allow(dead_code)]
+	struct Address {
+		city: String,
+	}
+
+	#[derive(Debug, Deserialize)]
+	#[allow(dead_code)]
+	struct Person {
+		name: String,
+		address: Address,
+		tags: Vec<String>,
+	}
+
+	#[test]
+	fn numeric_arrays() {
+		let value: Value = "[1, 2.0f, 3dec]".parse().unwrap();
+		assert_eq!(value.as_i64_vec(), Some(vec![1, 2, 3]));
+		assert_eq!(value.as_f64_vec(), Some(vec![1.0, 2.0, 3.0]));
+		let value: Value = "[1.5f, -2]".parse().unwrap();
+		assert_eq!(value.as_i64_vec(), None);
+		assert_eq!(value.as_f64_vec(), Some(vec![1.5, -2.0]));
+		let value: Value = "[]".parse().unwrap();
+		assert_eq!(value.as_i64_vec(), Some(vec![]));
+		assert_eq!(value.as_f64_vec(), Some(vec![]));
+	}
+
+	#[test]
+	fn numeric_arrays_reject_other_values() {
+		let value: Value = "[1, '2', 3]".parse().unwrap();
+		assert_eq!(value.as_i64_vec(), None);
+		assert_eq!(value.as_f64_vec(), None);
+		let value: Value = "1".parse().unwrap();
+		assert_eq!(value.as_i64_vec(), None);
+		assert_eq!(value.as_f64_vec(), None);
+	}
+
+	#[test]
+	fn number_from_primitives() {
+		assert_eq!(Number::from(7u8).into_inner(), CoreNumber::Int(7));
+		assert_eq!(Number::from(-7i32).into_inner(), CoreNumber::Int(-7));
+		assert_eq!(Number::from(i64::MIN).into_inner(), CoreNumber::Int(i64::MIN));
+		assert_eq!(Number::from(1.5f32).into_inner(), CoreNumber::Float(1.5));
+		assert_eq!(Number::try_from(7u64).unwrap().into_inner(), CoreNumber::Int(7));
+		assert_eq!(Number::try_from(-7i128).unwrap().into_inner(), CoreNumber::Int(-7));
+	}
+
+	#[test]
+	fn number_from_wide_integers() {
+		let big = i64::MAX as u64 + 1;
+		let CoreNumber::Decimal(v) = Number::try_from(big).unwrap().into_inner() else {
+			panic!("expected a decimal");
+		};
+		assert_eq!(v.to_u64(), Some(big));
+		let CoreNumber::Decimal(v) =
+			Number::try_from(i128::from(i64::MIN) - 1).unwrap().into_inner()
+		else {
+			panic!("expected a decimal");
+		};
+		assert_eq!(v.to_i128(), Some(i128::from(i64::MIN) - 1));
+		let error = Number::try_from(u128::MAX).unwrap_err();
+		assert_eq!(error.from(), "u128");
+		Number::try_from(i128::MIN).unwrap_err();
+	}
+
+	#[test]
+	fn is_truthy() {
+		let truthy = [
+			"true",
+			"1",
+			"-1.5f",
+			"0.1dec",
+			"'a'",
+			"[NONE]",
+			"{ a: NONE }",
+			"1ns",
+			"d'1970-01-01T00:00:00Z'",
+			"u'00000000-0000-0000-0000-000000000000'",
+			"person:tobie",
+			"(0.0, 0.0)",
+		];
+		for value in truthy {
+			assert!(value.parse::<Value>().unwrap().is_truthy(), "{value} should be truthy");
+		}
+		let falsy = ["NONE", "NULL", "false", "0", "0f", "0dec", "-0f", "''", "[]", "{}", "0ns"];
+		for value in falsy {
+			assert!(!value.parse::<Value>().unwrap().is_truthy(), "{value} should be falsy");
+		}
+		assert!(!Value::from_inner(CoreValue::Bytes(vec![1].into())).is_truthy());
+	}
+
+	#[test]
+	fn content_hash() {
+		let a: Value = "{ name: 'john', tags: ['a', 'b'], age: 30 }".parse().unwrap();
+		let b: Value = "{ age: 30.0f, tags: ['a', 'b'], name: 'john' }".parse().unwrap();
+		let c: Value = "{ name: 'john', tags: ['b', 'a'], age: 30 }".parse().unwrap();
+		assert_eq!(a.canonical_bytes(), b.canonical_bytes());
+		assert_eq!(a.content_hash(), b.content_hash());
+		assert_ne!(a.content_hash(), c.content_hash());
+	}
+
+	#[test]
+	fn into_typed() {
+		let value: Value =
+			"{ name: 'Tobie', address: { city: 'London' }, tags: ['rust'] }".parse().unwrap();
+		let person: Person = value.into_typed().unwrap();
+		assert_eq!(person.address.city, "London");
+	}
+
+	#[test]
+	fn into_typed_nested_error_path() {
+		let value: Value = "{ name: 'Tobie', address: { city: 10 }, tags: [] }".parse().unwrap();
+		let error = value.into_typed::<Person>().unwrap_err();
+		assert!(error.to_string().contains("at `.address.city`"), "{error}");
+		match error {
+			Error::Api(ApiError::FromValueAt {
+				path,
+				..
+			}) => assert_eq!(path, ["address", "city"]),
+			error => panic!("unexpected error: {error:?}"),
+		}
+	}
+
+	#[test]
+	fn into_typed_array_error_path() {
+		let value: Value =
+			"{ name: 'Tobie', address: { city: 'London' }, tags: ['rust', true] }".parse().unwrap();
+		let error = value.into_typed::<Person>().unwrap_err();
+		assert!(error.to_string().contains("at `.tags[1]`"), "{error}");
+	}
+
+	fn conversion_error(error: Error) -> ConversionError {
+		match error {
+			Error::Api(ApiError::ValueConversion(error)) => error,
+			error => panic!("unexpected error: {error:?}"),
+		}
+	}
+
+	#[test]
+	fn try_into_extractors() {
+		let value = |s: &str| s.parse::<Value>().unwrap();
+		assert_eq!(value("'Tobie'").try_into_string().unwrap(), "Tobie");
+		assert_eq!(value("10").try_into_i64().unwrap(), 10);
+		assert_eq!(value("10f").try_into_i64().unwrap(), 10);
+		assert_eq!(value("10dec").try_into_i64().unwrap(), 10);
+		assert_eq!(value("1.5f").try_into_f64().unwrap(), 1.5);
+		assert_eq!(value("2").try_into_f64().unwrap(), 2.0);
+		assert_eq!(value("0.25dec").try_into_f64().unwrap(), 0.25);
+		assert!(value("true").try_into_bool().unwrap());
+		let object = value("{ a: 1 }").try_into_object().unwrap();
+		assert_eq!(object.get("a"), Some(&value("1")));
+		let array = value("[1, 'a']").try_into_array().unwrap();
+		assert_eq!(array, [value("1"), value("'a'")]);
+	}
+
+	#[test]
+	fn try_into_extractors_mismatch() {
+		let value = |s: &str| s.parse::<Value>().unwrap();
+		let cases = [
+			(value("1").try_into_string().unwrap_err(), "string", "int"),
+			(value("1.5f").try_into_i64().unwrap_err(), "int", "float"),
+			(value("1.5dec").try_into_i64().unwrap_err(), "int", "decimal"),
+			(value("1e30f").try_into_i64().unwrap_err(), "int", "float"),
+			(value("'1'").try_into_i64().unwrap_err(), "int", "string"),
+			(value("'1.5'").try_into_f64().unwrap_err(), "float", "string"),
+			(value("1").try_into_bool().unwrap_err(), "bool", "int"),
+			(value("[]").try_into_object().unwrap_err(), "object", "array"),
+			(value("NONE").try_into_array().unwrap_err(), "array", "none"),
+		];
+		for (error, expected, from) in cases {
+			assert_eq!(
+				error.to_string(),
+				format!("failed to convert into `{expected}` from value with type `{from}`")
+			);
+			let error = conversion_error(error);
+			assert_eq!((error.expected(), error.from()), (expected, from));
+		}
+	}
+
+	#[test]
+	fn coerce_to_array() {
+		let parse = |s: &str| s.parse::<Value>().unwrap();
+		assert_eq!(parse("[1, 2, 3]").coerce_to_array(), vec![parse("1"), parse("2"), parse("3")]);
+		assert_eq!(parse("[]").coerce_to_array(), Vec::<Value>::new());
+		assert_eq!(parse("NONE").coerce_to_array(), Vec::<Value>::new());
+		assert_eq!(parse("NULL").coerce_to_array(), Vec::<Value>::new());
+		assert_eq!(parse("42").coerce_to_array(), vec![parse("42")]);
+		assert_eq!(parse("'text'").coerce_to_array(), vec![parse("'text'")]);
+		assert_eq!(parse("person:tobie").coerce_to_array(), vec![parse("person:tobie")]);
+		assert_eq!(parse("{ a: [1] }").coerce_to_array(), vec![parse("{ a: [1] }")]);
+		assert_eq!(parse("[[1], NONE]").coerce_to_array(), vec![parse("[1]"), parse("NONE")]);
+	}
+
+	#[test]
+	fn coerce_to_record_id() {
+		let coerce = |s: &str| s.parse::<Value>().unwrap().coerce_to_record_id();
+		let john = RecordId::from_table_key("person", "john");
+		assert_eq!(coerce("person:john"), Some(john.clone()));
+		assert_eq!(coerce("'person:john'"), Some(john.clone()));
+		assert_eq!(coerce("{ tb: 'person', id: 'john' }"), Some(john));
+		assert_eq!(coerce("{ tb: 'person', id: 1 }"), Some(RecordId::from_table_key("person", 1)));
+		assert_eq!(
+			coerce("{ tb: 'person', id: [1, 'a'] }"),
+			Some("person:[1, 'a']".parse().unwrap())
+		);
+	}
+
+	#[test]
+	fn coerce_to_record_id_rejects() {
+		let coerce = |s: &str| s.parse::<Value>().unwrap().coerce_to_record_id();
+		for source in [
+			"NONE",
+			"42",
+			"'john'",
+			"''",
+			"[person:john]",
+			"{ tb: 'person' }",
+			"{ id: 'john' }",
+			"{ tb: 1, id: 'john' }",
+			"{ tb: 'person', id: true }",
+		] {
+			assert_eq!(coerce(source), None, "{source}");
+		}
+	}
+
+	#[test]
+	fn try_fold_sums_numbers() {
+		let value: Value = "[1, 2.5f, 3dec]".parse().unwrap();
+		let sum = value.try_fold(0.0, |sum, v| Ok(sum + v.clone().try_into_f64()?)).unwrap();
+		assert_eq!(sum, 6.5);
+		let value: Value = "['a', 'b', 'c']".parse().unwrap();
+		let joined = value
+			.reduce(|acc, v| {
+				let joined = acc.try_into_string()? + &v.clone().try_into_string()?;
+				Ok(Value::from_inner(joined.into()))
+			})
+			.unwrap();
+		assert_eq!(joined, Some("'abc'".parse().unwrap()));
+		let empty: Value = "[]".parse().unwrap();
+		assert_eq!(empty.try_fold(0, |sum, _| Ok(sum + 1)).unwrap(), 0);
+		assert_eq!(empty.reduce(|acc, _| Ok(acc)).unwrap(), None);
+	}
+
+	#[test]
+	fn try_fold_short_circuits() {
+		let value: Value = "[1, 'two', 3]".parse().unwrap();
+		let mut visited = 0;
+		let error = value
+			.try_fold(0, |sum, v| {
+				visited += 1;
+				Ok(sum + v.clone().try_into_i64()?)
+			})
+			.unwrap_err();
+		assert_eq!(visited, 2);
+		assert_eq!(conversion_error(error).from(), "string");
+		let value: Value = "{ a: 1 }".parse().unwrap();
+		let error = value.try_fold(0, |sum, _| Ok(sum + 1)).unwrap_err();
+		assert_eq!(conversion_error(error).expected(), "array");
+	}
+
+	#[test]
+	fn entries_of_arrays_and_objects() {
+		let v = |source: &str| -> Value { source.parse().unwrap() };
+		let entries: Vec<_> = v("[1, 'two']").entries().collect();
+		assert_eq!(entries, [(None, v("1")), (None, v("'two'"))]);
+		let entries: Vec<_> = v("{ b: [], a: 1 }").entries().collect();
+		assert_eq!(entries, [(Some("a".to_owned()), v("1")), (Some("b".to_owned()), v("[]"))]);
+	}
+
+	#[test]
+	fn sql_literals() {
+		for (source, literal) in [
+			("NONE", "NONE"),
+			("NULL", "NULL"),
+			("true", "true"),
+			("42", "42"),
+			("-1.5f", "-1.5f"),
+			("1.5dec", "1.5dec"),
+			("'text'", "'text'"),
+			("1h30m", "1h30m"),
+			("d'2024-01-02T03:04:05Z'", "d'2024-01-02T03:04:05Z'"),
+			("u'8f1e5a6a-6a1f-4a8e-9a3e-2b1d7a4c9e10'", "u'8f1e5a6a-6a1f-4a8e-9a3e-2b1d7a4c9e10'"),
+			("person:tobie", "person:tobie"),
+			("person:⟨a b⟩", "person:⟨a b⟩"),
+			("person:100", "person:100"),
+			("[1, 'two']", "[1, 'two']"),
+			("{ a: 1, 'b c': [] }", r#"{ a: 1, "b c": [] }"#),
+			("(51.5, -0.1)", "(51.5, -0.1)"),
+		] {
+			let value: Value = source.parse().unwrap();
+			assert_eq!(value.to_sql_literal(), literal, "{source}");
+			assert_eq!(literal.parse::<Value>().unwrap(), value, "{source}");
+		}
+		let value = Value::from_inner(CoreValue::Bytes(vec![0, 1, 255].into()));
+		assert_eq!(value.to_sql_literal(), r#"encoding::base64::decode("AAH/")"#);
+	}
+
+	#[test]
+	fn sql_literal_escapes_strings() {
+		for text in [
+			"it's",
+			r#"say "hi""#,
+			r"back\slash",
+			r#"'; DELETE person; --"#,
+			r#"' + "; DELETE person; -- \"#,
+		] {
+			let value = Value::from_inner(text.into());
+			let literal = value.to_sql_literal();
+			// The literal parses back as a single string, so it can not inject statements
+			assert_eq!(literal.parse::<Value>().unwrap(), value, "{literal}");
+		}
+	}
+
+	#[test]
+	fn entries_of_scalars() {
+		for source in ["NONE", "42", "'text'", "person:tobie"] {
+			let value: Value = source.parse().unwrap();
+			assert_eq!(value.entries().count(), 0, "{source}");
+		}
+	}
+
+	#[cfg(feature = "cbor")]
+	#[test]
+	fn cbor_round_trip() {
+		for source in [
+			"NONE",
+			"NULL",
+			"true",
+			"42",
+			"-1.5f",
+			"1.5dec",
+			"'text'",
+			"1y2w3d4h5m6s7ms8µs9ns",
+			"0s",
+			"d'2024-01-02T03:04:05.123456789Z'",
+			"u'8f1e5a6a-6a1f-4a8e-9a3e-2b1d7a4c9e10'",
+			"person:tobie",
+			"person:100",
+			"person:['London', d'2024-01-02T03:04:05Z']",
+			"person:{ city: 'London', year: 2024 }",
+			"person:u'8f1e5a6a-6a1f-4a8e-9a3e-2b1d7a4c9e10'",
+			"person:1..=10",
+			"(51.5, -0.1)",
+			"1..5",
+			"[1, 'two', [3, { four: 4 }], NONE]",
+			"{ a: { b: [person:tobie, 1d, { c: NULL }] }, d: [] }",
+		] {
+			let value: Value = source.parse().unwrap();
+			let bytes = value.to_cbor().unwrap();
+			assert_eq!(Value::from_cbor(&bytes).unwrap(), value, "{source}");
+		}
+		let value = Value::from_inner(CoreValue::Bytes(vec![0, 1, 2, 255].into()));
+		assert_eq!(Value::from_cbor(&value.to_cbor().unwrap()).unwrap(), value);
+	}
+
+	#[cfg(feature = "cbor")]
+	#[test]
+	fn cbor_errors() {
+		let error = Value::from_cbor(&[0xff, 0x00]).unwrap_err();
+		assert!(matches!(error, Error::Api(ApiError::Cbor(_))), "{error:?}");
+		let value: Value = "person:rand()".parse().unwrap();
+		let error = value.to_cbor().unwrap_err();
+		assert!(matches!(error, Error::Api(ApiError::Cbor(_))), "{error:?}");
+	}
+}
@@ -88,6 +88,89 @@ impl Object {
 	{
 		self.0.insert(key, value.into().into_inner()).map(Value::from_inner)
 	}
+
+	/// Returns the value at a dot-separated path like `a.b.c`
+	///
+	/// Returns `None` if any segment of the path is missing or is not an object.
+	pub fn get_path(&self, path: &str) -> Option<&Value> {
+		let mut segments = path.split('.');
+		let mut value = self.0.get(segments.next()?)?;
+		for segment in segments {
+			let CoreValue::Object(object) = value else {
+				return None;
+			};
+			value = object.get(segment)?;
+		}
+		Some(Value::from_inner_ref(value))
+	}
+
+	/// Sets the value at a dot-separated path like `a.b.c`
+	///
+	/// Missing intermediate segments are created as empty objects, just like `SET a.b.c = x`
+	/// does in SurrealQL. Returns an error if an intermediate segment exists but is not an
+	/// object.
+	pub fn set_path<V>(&mut self, path: &str, value: V) -> crate::Result<()>
+	where
+		V: Into<Value>,
+	{
+		let (parents, key) = match path.rsplit_once('.') {
+			Some((parents, key)) => (Some(parents), key),
+			None => (None, path),
+		};
+		let mut object = &mut self.0;
+		let mut end = 0;
+		for segment in parents.into_iter().flat_map(|parents| parents.split('.')) {
+			end += segment.len();
+			let entry = object
+				.entry(segment.to_owned())
+				.or_insert_with(|| CoreValue::Object(CoreObject::default()));
+			let CoreValue::Object(inner) = entry else {
+				return Err(crate::api::err::Error::PathNotObject(path[..end].to_owned()).into());
+			};
+			object = inner;
+			end += 1;
+		}
+		object.insert(key.to_owned(), value.into().into_inner());
+		Ok(())
+	}
+
+	/// Merges the fields of another object into this one
+	///
+	/// Fields which only exist in `other` are inserted as they are. For fields which exist in
+	/// both objects, `resolver` is called with the key, the current value and the value from
+	/// `other`, and decides what the field ends up holding.
+	///
+	/// ```
+	/// use surrealdb::Value;
+	///
+	/// let mut counts = "{ a: 1, b: 5 }".parse::<Value>().unwrap().try_into_object().unwrap();
+	/// let other = "{ b: 3, c: 2 }".parse::<Value>().unwrap().try_into_object().unwrap();
+	///
+	/// // Keep the larger count for every key
+	/// counts.merge_with(other, |_, current, new| {
+	///     if new > *current {
+	///         *current = new;
+	///     }
+	/// });
+	///
+	/// let expected = "{ a: 1, b: 5, c: 2 }".parse::<Value>().unwrap().try_into_object().unwrap();
+	/// assert_eq!(counts, expected);
+	/// ```
+	pub fn merge_with<F>(&mut self, other: Object, mut resolver: F)
+	where
+		F: FnMut(&str, &mut Value, Value),
+	{
+		for (key, value) in other.0 .0 {
+			match self.0.get_mut(&key) {
+				Some(current) => {
+					resolver(&key, Value::from_inner_mut(current), Value::from_inner(value))
+				}
+				None => {
+					self.0.insert(key, value);
+				}
+			}
+		}
+	}
 }
 
 pub struct IntoIter {
@@ -242,3 +325,75 @@ impl ExactSizeIterator for IterMut<'_> {
 		self.iter.len()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::api::err::Error as ApiError;
+	use crate::Error;
+
+	fn parse(s: &str) -> Object {
+		let CoreValue::Object(object) = s.parse::<Value>().unwrap().into_inner() else {
+			panic!("not an object: {s}");
+		};
+		Object::from_inner(object)
+	}
+
+	#[test]
+	fn get_path() {
+		let object = parse("{ a: { b: { c: 42 } }, d: 'text' }");
+		assert_eq!(object.get_path("a.b.c"), Some(&"42".parse().unwrap()));
+		assert_eq!(object.get_path("a.b"), Some(&"{ c: 42 }".parse().unwrap()));
+		assert_eq!(object.get_path("d"), Some(&"'text'".parse().unwrap()));
+		assert_eq!(object.get_path("a.x.c"), None);
+		assert_eq!(object.get_path("d.e"), None);
+	}
+
+	#[test]
+	fn set_path_creates_intermediates() {
+		let mut object = parse("{ a: { x: 1 } }");
+		object.set_path("a.b.c", Value::from_inner(CoreValue::from(42))).unwrap();
+		object.set_path("d", Value::from_inner(CoreValue::from("text"))).unwrap();
+		assert_eq!(object, parse("{ a: { b: { c: 42 }, x: 1 }, d: 'text' }"));
+	}
+
+	#[test]
+	fn set_path_conflicting_intermediate() {
+		let mut object = parse("{ a: { b: 'text' } }");
+		let error = object.set_path("a.b.c", Value::default()).unwrap_err();
+		match error {
+			Error::Api(ApiError::PathNotObject(path)) => assert_eq!(path, "a.b"),
+			error => panic!("unexpected error: {error:?}"),
+		}
+		assert_eq!(object, parse("{ a: { b: 'text' } }"));
+	}
+
+	#[test]
+	fn merge_with_max() {
+		let mut object = parse("{ a: 1, b: 5, c: 'text' }");
+		let mut conflicts = Vec::new();
+		object.merge_with(parse("{ b: 3, c: 'zzz', d: 2 }"), |key, current, new| {
+			conflicts.push(key.to_owned());
+			if new > *current {
+				*current = new;
+			}
+		});
+		assert_eq!(object, parse("{ a: 1, b: 5, c: 'zzz', d: 2 }"));
+		assert_eq!(conflicts, ["b", "c"]);
+	}
+
+	#[test]
+	fn merge_with_concatenated_arrays() {
+		let mut object = parse("{ tags: ['a', 'b'], name: 'first' }");
+		object.merge_with(parse("{ tags: ['c'], name: 'second', count: 1 }"), |_, current, new| {
+			match (current.clone().into_inner(), new.into_inner()) {
+				(CoreValue::Array(mut current_array), CoreValue::Array(new_array)) => {
+					current_array.extend(new_array.0);
+					*current = Value::from_inner(CoreValue::Array(current_array));
+				}
+				(_, new) => *current = Value::from_inner(new),
+			}
+		});
+		assert_eq!(object, parse("{ tags: ['a', 'b', 'c'], name: 'second', count: 1 }"));
+	}
+}
@@ -148,7 +148,8 @@ pub use api::headers;
 pub use api::{
 	engine, method, opt,
 	value::{
-		self, Action, Bytes, Datetime, Notification, Number, Object, RecordId, RecordIdKey, Value,
+		self, Action, Bytes, Change, Datetime, Notification, Number, Object, RecordId, RecordIdKey,
+		Value,
 	},
 	Connect, Connection, Response, Result, Surreal,
 };
@@ -163,3 +164,20 @@ pub enum Error {
 	#[error("{0}")]
 	Api(#[from] crate::error::Api),
 }
+
+impl Error {
+	/// Whether this error is a transaction conflict which can be safely retried
+	///
+	/// This is the case for [`Db::TxRetryable`](crate::error::Db::TxRetryable) errors, including
+	/// the ones returned by remote instances, and for statements which failed with one.
+	pub fn is_retryable(&self) -> bool {
+		match self {
+			Self::Db(crate::error::Db::TxRetryable) => true,
+			Self::Api(crate::error::Api::StatementFailed {
+				error,
+				..
+			}) => error.is_retryable(),
+			_ => false,
+		}
+	}
+}
@@ -8,3 +8,66 @@ macro_rules! map {
 		m
 	}};
 }*/
+
+/// Creates a new [`RecordId`](crate::RecordId) from a table name and a key
+///
+/// The table name must be a valid identifier. The key is interpreted as follows:
+///
+/// - An identifier, such as `record!(person:john)`, creates a string key
+/// - A literal, such as `record!(person:123)`, creates a key from the literal
+/// - A parenthesised expression, such as `record!(person:(id))`, is evaluated
+///   and converted into a [`RecordIdKey`](crate::RecordIdKey)
+///
+/// # Examples
+///
+/// ```
+/// use surrealdb::record;
+/// use surrealdb::RecordId;
+///
+/// assert_eq!(record!(person:john), RecordId::from(("person", "john")));
+/// assert_eq!(record!(person:123), RecordId::from(("person", 123)));
+///
+/// let id = uuid::Uuid::nil();
+/// assert_eq!(record!(person:(id)), RecordId::from(("person", id)));
+/// ```
+#[macro_export]
+macro_rules! record {
+	($tb:ident : ($key:expr)) => {
+		$crate::RecordId::from_table_key(stringify!($tb), $key)
+	};
+	($tb:ident : $key:ident) => {
+		$crate::RecordId::from_table_key(stringify!($tb), stringify!($key))
+	};
+	($tb:ident : $key:literal) => {
+		$crate::RecordId::from_table_key(stringify!($tb), $key)
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{RecordId, RecordIdKey};
+
+	#[test]
+	fn record_string_key() {
+		let id = record!(person:john);
+		assert_eq!(id.table(), "person");
+		assert_eq!(id.key(), &RecordIdKey::from("john"));
+		assert_eq!(id, RecordId::from(("person", "john")));
+	}
+
+	#[test]
+	fn record_numeric_key() {
+		let id = record!(person:123);
+		assert_eq!(id.table(), "person");
+		assert_eq!(id.key(), &RecordIdKey::from(123));
+	}
+
+	#[test]
+	fn record_expression_key() {
+		let key = format!("{}_{}", "tobie", 1);
+		let id = record!(person:(key.clone()));
+		assert_eq!(id.key(), &RecordIdKey::from(key));
+		let id = record!(person:(vec![crate::Value::from_inner(1.into())]));
+		assert_eq!(id.to_string(), "person:[1]");
+	}
+}
@@ -24,6 +24,7 @@ mod api_integration {
 	use surrealdb::opt::Config;
 	use surrealdb::opt::PatchOp;
 	use surrealdb::opt::Resource;
+	use surrealdb::opt::SessionOverride;
 	use surrealdb::sql::statements::BeginStatement;
 	use surrealdb::sql::statements::CommitStatement;
 	use surrealdb::sql::thing;
@@ -142,6 +143,14 @@ mod api_integration {
 			drop(permit);
 		}
 
+		#[test_log::test(tokio::test)]
+		async fn ping_latency() {
+			let (permit, db) = new_db().await;
+			let latency = db.ping_latency().await.unwrap();
+			drop(permit);
+			assert!(latency > Duration::ZERO);
+		}
+
 		include!("api/mod.rs");
 		include!("api/serialisation.rs");
 		include!("api/live.rs");
@@ -267,6 +276,14 @@ mod api_integration {
 			db.use_ns("test").use_db("test").await.unwrap();
 		}
 
+		#[test_log::test(tokio::test)]
+		async fn ping_latency() {
+			let (permit, db) = new_db().await;
+			// The time an embedded engine takes depends on the load of the machine
+			db.ping_latency().await.unwrap();
+			drop(permit);
+		}
+
 		include!("api/mod.rs");
 		include!("api/serialisation.rs");
 		include!("api/live.rs");
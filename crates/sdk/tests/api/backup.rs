@@ -118,6 +118,50 @@ async fn export_with_config() {
 	}
 }
 
+#[tokio::test]
+async fn export_table() {
+	use futures::TryStreamExt;
+
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+
+	db.query("DEFINE TABLE user SCHEMAFULL; DEFINE FIELD name ON user TYPE string")
+		.await
+		.unwrap()
+		.check()
+		.unwrap();
+	db.query("DEFINE TABLE group; DEFINE PARAM $limit VALUE 10").await.unwrap().check().unwrap();
+	let _: Option<ApiRecordId> = db
+		.create(("user", "john"))
+		.content(Record {
+			name: "John Doe".to_owned(),
+		})
+		.await
+		.unwrap();
+	let _: Option<ApiRecordId> = db
+		.create(("group", "admins"))
+		.content(Record {
+			name: "Admins".to_owned(),
+		})
+		.await
+		.unwrap();
+
+	// Export only the user table
+	let bytes = db.export_table("user").try_concat().await.unwrap();
+	let export = String::from_utf8(bytes).unwrap();
+
+	// The table definition and its records are included
+	assert!(export.contains("DEFINE TABLE user"), "{export}");
+	assert!(export.contains("DEFINE FIELD name ON user"), "{export}");
+	assert!(export.contains("user:john"), "{export}");
+	assert!(export.contains("John Doe"), "{export}");
+	// Other tables and database level definitions are not
+	assert!(!export.contains("group"), "{export}");
+	assert!(!export.contains("Admins"), "{export}");
+	assert!(!export.contains("DEFINE PARAM"), "{export}");
+}
+
 #[test_log::test(tokio::test)]
 #[cfg(feature = "ml")]
 async fn ml_export_import() {
@@ -7,6 +7,7 @@ use futures::TryStreamExt;
 use std::ops::DerefMut;
 use surrealdb::method::QueryStream;
 use surrealdb::Action;
+use surrealdb::Change;
 use surrealdb::Notification;
 use surrealdb_core::sql::Object;
 use tokio::sync::RwLock;
@@ -422,6 +423,181 @@ async fn live_select_query() {
 	drop(permit);
 }
 
+#[test_log::test(tokio::test)]
+async fn live_select_kill_handle() {
+	let (permit, db) = new_db().await;
+
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+
+	let table = format!("table_{}", Ulid::new());
+	if FFLAGS.change_feed_live_queries.enabled() {
+		db.query(format!("DEFINE TABLE {table} CHANGEFEED 10m INCLUDE ORIGINAL")).await.unwrap();
+	} else {
+		db.query(format!("DEFINE TABLE {table}")).await.unwrap();
+	}
+
+	// Start listening
+	let mut users = db.select(&table).live().await.unwrap();
+	let handle = users.handle();
+
+	// Create a record
+	let created: Option<ApiRecordId> = db.create(&table).await.unwrap();
+	// Pull the notification
+	let notification: Notification<ApiRecordId> =
+		tokio::time::timeout(LQ_TIMEOUT, users.next()).await.unwrap().unwrap().unwrap();
+	// The returned record should match the created record
+	assert_eq!(created, Some(notification.data.clone()));
+
+	// Kill the live query while keeping the stream around
+	handle.kill().await.unwrap();
+
+	// Create another record
+	let _: Option<ApiRecordId> = db.create(&table).await.unwrap();
+	// The stream should end without any further notifications
+	let next: Option<Result<Notification<ApiRecordId>, Error>> =
+		tokio::time::timeout(LQ_TIMEOUT, users.next()).await.unwrap();
+	assert!(next.is_none(), "{:?}", next);
+
+	drop(permit);
+}
+
+#[test_log::test(tokio::test)]
+async fn live_select_kill_all() {
+	let (permit, db) = new_db().await;
+
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+
+	let table = format!("table_{}", Ulid::new());
+	if FFLAGS.change_feed_live_queries.enabled() {
+		db.query(format!("DEFINE TABLE {table} CHANGEFEED 10m INCLUDE ORIGINAL")).await.unwrap();
+	} else {
+		db.query(format!("DEFINE TABLE {table}")).await.unwrap();
+	}
+
+	// Start three live queries
+	let mut streams = Vec::new();
+	for _ in 0..3 {
+		let stream = db.select(&table).live().await.unwrap();
+		streams.push(stream);
+	}
+	let lives = |mut response: Response| {
+		let info: Value = response.take(0).unwrap();
+		match info.into_inner().pick(&["lives".into()]) {
+			CoreValue::Object(lives) => lives.len(),
+			lives => panic!("unexpected live queries: {lives}"),
+		}
+	};
+	let info = db.query(format!("INFO FOR TABLE {table}")).await.unwrap();
+	assert_eq!(lives(info), 3);
+
+	// Kill them all at once
+	db.kill_all_live().await.unwrap();
+
+	// The server should not report any active live queries
+	let info = db.query(format!("INFO FOR TABLE {table}")).await.unwrap();
+	assert_eq!(lives(info), 0);
+
+	// The streams should end without any further notifications
+	let _: Option<ApiRecordId> = db.create(&table).await.unwrap();
+	for mut users in streams {
+		let next: Option<Result<Notification<ApiRecordId>, Error>> =
+			tokio::time::timeout(LQ_TIMEOUT, users.next()).await.unwrap();
+		assert!(next.is_none(), "{:?}", next);
+	}
+
+	drop(permit);
+}
+
+#[test_log::test(tokio::test)]
+async fn live_with_snapshot() {
+	let (permit, db) = new_db().await;
+
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+
+	let table = format!("table_{}", Ulid::new());
+	if FFLAGS.change_feed_live_queries.enabled() {
+		db.query(format!("DEFINE TABLE {table} CHANGEFEED 10m INCLUDE ORIGINAL")).await.unwrap();
+	} else {
+		db.query(format!("DEFINE TABLE {table}")).await.unwrap();
+	}
+	let mut ids = Vec::new();
+	for _ in 0..5 {
+		let created: Option<ApiRecordId> = db.create(&table).await.unwrap();
+		ids.push(created.unwrap().id);
+	}
+
+	let (snapshot, mut users) = db.live_with_snapshot::<ApiRecordId>(&table).await.unwrap();
+
+	// The existing records are selected
+	assert_eq!(snapshot.len(), ids.len());
+	assert!(snapshot.iter().all(|record| ids.contains(&record.id)));
+
+	// Records created afterwards are delivered by the stream
+	let created: Option<ApiRecordId> = db.create(&table).await.unwrap();
+	let notification: Notification<ApiRecordId> =
+		tokio::time::timeout(LQ_TIMEOUT, users.next()).await.unwrap().unwrap().unwrap();
+	assert_eq!(notification.action, Action::Create);
+	assert_eq!(notification.data.id, created.unwrap().id);
+
+	drop(permit);
+}
+
+#[test_log::test(tokio::test)]
+async fn live_diff_table() {
+	let (permit, db) = new_db().await;
+
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+
+	let table = format!("table_{}", Ulid::new());
+	if FFLAGS.change_feed_live_queries.enabled() {
+		db.query(format!("DEFINE TABLE {table} CHANGEFEED 10m INCLUDE ORIGINAL")).await.unwrap();
+	} else {
+		db.query(format!("DEFINE TABLE {table}")).await.unwrap();
+	}
+
+	#[derive(Debug, PartialEq, Deserialize)]
+	struct Person {
+		name: String,
+	}
+
+	// Start listening
+	let mut users = db.live_diff::<Person>(&table).await.unwrap();
+
+	// Create a record
+	let created: Option<ApiRecordId> =
+		db.create(&table).content(json!({"name": "before"})).await.unwrap();
+	let id = created.unwrap().id;
+	// Pull the notification
+	let notification: Notification<Change<Person>> =
+		tokio::time::timeout(LQ_TIMEOUT, users.next()).await.unwrap().unwrap().unwrap();
+	// There is no previous state for a newly created record
+	assert_eq!(notification.action, Action::Create);
+	assert_eq!(notification.data.before, None);
+	assert_eq!(notification.data.after.unwrap().name, "before");
+
+	// Update the record
+	let _: Option<ApiRecordId> = db.update(&id).content(json!({"name": "after"})).await.unwrap();
+	// Pull the notification
+	let notification: Notification<Change<Person>> =
+		tokio::time::timeout(LQ_TIMEOUT, users.next()).await.unwrap().unwrap().unwrap();
+	// Both the previous and the new state should be populated
+	assert_eq!(notification.action, Action::Update);
+	assert_eq!(notification.data.before.unwrap().name, "before");
+	assert_eq!(notification.data.after.unwrap().name, "after");
+
+	// Delete the record
+	let _: Option<ApiRecordId> = db.delete(&id).await.unwrap();
+	// Pull the notification
+	let notification: Notification<Change<Person>> =
+		tokio::time::timeout(LQ_TIMEOUT, users.next()).await.unwrap().unwrap().unwrap();
+	// There is no new state for a deleted record
+	assert_eq!(notification.action, Action::Delete);
+	assert_eq!(notification.data.before.unwrap().name, "after");
+	assert_eq!(notification.data.after, None);
+
+	drop(permit);
+}
+
 async fn receive_all_pending_notifications<
 	S: Stream<Item = Result<Notification<I>, Error>> + Unpin,
 	I,
@@ -3,7 +3,7 @@
 use surrealdb::fflags::FFLAGS;
 use surrealdb::value;
 use surrealdb::Response;
-use surrealdb_core::sql::{Id, Value as CoreValue};
+use surrealdb_core::sql::{Id, Kind, Value as CoreValue};
 
 static PERMITS: Semaphore = Semaphore::const_new(1);
 
@@ -82,6 +82,28 @@ async fn signup_record() {
 	.unwrap();
 }
 
+#[test_log::test(tokio::test)]
+async fn signin_root_with_session_info() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let info = db
+		.signin(Root {
+			username: ROOT_USER,
+			password: ROOT_PASS,
+		})
+		.with_session_info()
+		.await
+		.unwrap();
+	assert!(!info.token.as_insecure_token().is_empty());
+	assert_eq!(info.roles, vec!["owner".to_owned()]);
+	assert_eq!(info.namespace, None);
+	assert_eq!(info.database, None);
+	assert_eq!(info.access, None);
+	assert_eq!(info.record, None);
+	assert!(info.expires.unwrap() > chrono::Utc::now());
+}
+
 #[test_log::test(tokio::test)]
 async fn signin_ns() {
 	let (permit, db) = new_db().await;
@@ -165,6 +187,55 @@ async fn signin_record() {
 	.unwrap();
 }
 
+#[test_log::test(tokio::test)]
+async fn signin_record_with_session_info() {
+	let (permit, db) = new_db().await;
+	let database = Ulid::new().to_string();
+	db.use_ns(NS).use_db(&database).await.unwrap();
+	let access = Ulid::new().to_string();
+	let email = format!("{access}@example.com");
+	let pass = "password123";
+	let sql = format!(
+		"
+        DEFINE ACCESS `{access}` ON DB TYPE RECORD
+        SIGNUP ( CREATE user SET email = $email, pass = crypto::argon2::generate($pass) )
+        SIGNIN ( SELECT * FROM user WHERE email = $email AND crypto::argon2::compare(pass, $pass) )
+		DURATION FOR SESSION 1d FOR TOKEN 15s
+    "
+	);
+	let response = db.query(sql).await.unwrap();
+	drop(permit);
+	response.check().unwrap();
+	db.signup(RecordAccess {
+		namespace: NS,
+		database: &database,
+		access: &access,
+		params: AuthParams {
+			pass,
+			email: &email,
+		},
+	})
+	.await
+	.unwrap();
+	let info = db
+		.signin(RecordAccess {
+			namespace: NS,
+			database: &database,
+			access: &access,
+			params: AuthParams {
+				pass,
+				email: &email,
+			},
+		})
+		.with_session_info()
+		.await
+		.unwrap();
+	assert_eq!(info.access, Some(access));
+	assert_eq!(info.database, Some(database));
+	assert_eq!(info.record.unwrap().table(), "user");
+	assert!(info.roles.is_empty());
+}
+
 #[test_log::test(tokio::test)]
 async fn record_access_throws_error() {
 	let (permit, db) = new_db().await;
@@ -391,6 +462,42 @@ async fn query_binds() {
 	assert_eq!(record.name, "John Doe");
 }
 
+#[test_log::test(tokio::test)]
+async fn query_with_vars_file() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let dir = temp_dir::TempDir::new().unwrap();
+	let vars = dir.child("vars.surql");
+	std::fs::write(&vars, "{ id: user:john, name: 'John Doe' }").unwrap();
+	let mut response = db.query_with_vars_file("CREATE $id SET name = $name", &vars).await.unwrap();
+	let Some(record): Option<RecordName> = response.take(0).unwrap() else {
+		panic!("query returned no record");
+	};
+	assert_eq!(record.name, "John Doe");
+	// Only objects can be bound
+	let array = dir.child("array.surql");
+	std::fs::write(&array, "[1, 2, 3]").unwrap();
+	match db.query("RETURN $name").bind_vars_file(&array).await.unwrap_err() {
+		Error::Api(surrealdb::error::Api::InvalidBindings(_)) => {}
+		error => panic!("unexpected error: {error:?}"),
+	}
+	// Values are not evaluated
+	let expression = dir.child("expression.surql");
+	std::fs::write(&expression, "{ name: (SELECT * FROM user) }").unwrap();
+	match db.query("RETURN $name").bind_vars_file(&expression).await.unwrap_err() {
+		Error::Db(DbError::InvalidQuery(_)) => {}
+		error => panic!("unexpected error: {error:?}"),
+	}
+	let missing = dir.child("missing.surql");
+	match db.query("RETURN $name").bind_vars_file(&missing).await.unwrap_err() {
+		Error::Api(surrealdb::error::Api::FileRead {
+			..
+		}) => {}
+		error => panic!("unexpected error: {error:?}"),
+	}
+}
+
 #[test_log::test(tokio::test)]
 async fn query_with_stats() {
 	let (permit, db) = new_db().await;
@@ -408,6 +515,135 @@ async fn query_with_stats() {
 	let _: Vec<ApiRecordId> = result.unwrap();
 }
 
+#[test_log::test(tokio::test)]
+async fn query_response_stats() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let sql = "CREATE foo; SELECT * FROM foo";
+	let mut response = db.query(sql).await.unwrap();
+	let stats = response.stats(0).unwrap();
+	assert!(stats.execution_time > Some(Duration::ZERO));
+	assert!(response.stats(1).unwrap().execution_time > Some(Duration::ZERO));
+	assert_eq!(response.stats(2), None);
+	// Taking a result also removes its statistics
+	let _: Value = response.take(0).unwrap();
+	assert_eq!(response.stats(0), None);
+}
+
+#[test_log::test(tokio::test)]
+async fn define_table_with_fields() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	db.define_table("person")
+		.schemafull()
+		.field("name", Kind::String)
+		.field("age", Kind::Option(Box::new(Kind::Int)))
+		.await
+		.unwrap();
+	let info = db.info_for_table("person").await.unwrap();
+	let fields: Vec<_> =
+		info.fields.iter().map(|field| (field.name.as_str(), field.kind.clone())).collect();
+	assert_eq!(
+		fields,
+		[("age", Some(Kind::Option(Box::new(Kind::Int)))), ("name", Some(Kind::String))]
+	);
+	let mut response = db.query("INFO FOR DB").await.unwrap();
+	let tables: Value = response.take("tables").unwrap();
+	assert!(tables.to_string().contains("SCHEMAFULL"), "{tables}");
+	// The table is schemafull, so undefined fields are not stored
+	db.query("CREATE person:john SET name = 'John', email = 'john@example.com'")
+		.await
+		.unwrap()
+		.check()
+		.unwrap();
+	let email: Option<String> =
+		db.query("RETURN person:john.email").await.unwrap().take(0).unwrap();
+	assert_eq!(email, None);
+	// Defining the table again fails unless it may already exist
+	db.define_table("person").await.unwrap_err();
+	db.define_table("person").if_not_exists().field("name", Kind::Int).await.unwrap();
+	db.define_field("person", "created_at", Kind::Datetime).readonly().await.unwrap();
+	let info = db.info_for_table("person").await.unwrap();
+	let name = info.fields.iter().find(|field| field.name == "name").unwrap();
+	assert_eq!(name.kind, Some(Kind::String));
+	let created_at = info.fields.iter().find(|field| field.name == "created_at").unwrap();
+	assert_eq!(created_at.kind, Some(Kind::Datetime));
+	assert!(created_at.readonly);
+}
+
+#[test_log::test(tokio::test)]
+async fn info_for_table() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let sql = "
+		DEFINE TABLE person SCHEMAFULL;
+		DEFINE FIELD name ON person TYPE string;
+		DEFINE FIELD age ON person TYPE option<int> READONLY;
+		DEFINE FIELD tags ON person TYPE array<string>;
+		DEFINE INDEX unique_name ON person FIELDS name UNIQUE;
+		DEFINE INDEX name_and_tags ON person FIELDS name, tags[*];
+		DEFINE EVENT renamed ON person WHEN $before.name != $after.name THEN (CREATE log);
+	";
+	db.query(sql).await.unwrap().check().unwrap();
+	let info = db.info_for_table("person").await.unwrap();
+	let fields: Vec<_> =
+		info.fields.iter().map(|field| (field.name.as_str(), field.kind.clone())).collect();
+	assert_eq!(
+		fields,
+		[
+			("age", Some(Kind::Option(Box::new(Kind::Int)))),
+			("name", Some(Kind::String)),
+			("tags", Some(Kind::Array(Box::new(Kind::String), None))),
+			("tags[*]", Some(Kind::String)),
+		]
+	);
+	assert!(info.fields[0].readonly);
+	assert_eq!(info.indexes.len(), 2);
+	assert_eq!(info.indexes[0].name, "name_and_tags");
+	assert_eq!(info.indexes[0].fields, ["name", "tags[*]"]);
+	assert!(!info.indexes[0].unique);
+	assert_eq!(info.indexes[1].name, "unique_name");
+	assert_eq!(info.indexes[1].fields, ["name"]);
+	assert!(info.indexes[1].unique);
+	assert_eq!(info.events.len(), 1);
+	assert_eq!(info.events[0].name, "renamed");
+	assert_eq!(info.events[0].when, "$before.name != $after.name");
+	assert_eq!(info.events[0].then, ["(CREATE log)"]);
+	// Schemaless tables have no fields
+	db.query("DEFINE TABLE note SCHEMALESS").await.unwrap().check().unwrap();
+	let info = db.info_for_table("note").await.unwrap();
+	assert!(info.fields.is_empty());
+}
+
+#[test_log::test(tokio::test)]
+async fn query_typed_pair() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	db.query("CREATE user:john SET name = 'John Doe'; CREATE user:jane SET name = 'Jane Doe'")
+		.await
+		.unwrap()
+		.check()
+		.unwrap();
+	let sql = "SELECT * FROM user ORDER BY id; RETURN count(SELECT * FROM user)";
+	let (users, count): (Vec<RecordName>, Option<i64>) = db.query_typed(sql).await.unwrap();
+	let names: Vec<_> = users.into_iter().map(|user| user.name).collect();
+	assert_eq!(names, ["Jane Doe", "John Doe"]);
+	assert_eq!(count, Some(2));
+	// The number of statements must match the size of the tuple
+	let error = db.query_typed::<(Value, Value)>("RETURN 1").await.unwrap_err();
+	assert!(matches!(
+		error,
+		Error::Api(ApiError::StatementCountMismatch {
+			expected: 2,
+			found: 1
+		})
+	));
+}
+
 #[test_log::test(tokio::test)]
 async fn query_chaining() {
 	let (permit, db) = new_db().await;
@@ -425,6 +661,78 @@ async fn query_chaining() {
 	response.check().unwrap();
 }
 
+#[test_log::test(tokio::test)]
+async fn query_with_session() {
+	let (permit, db) = new_db().await;
+	let database = Ulid::new().to_string();
+	db.use_ns(NS).use_db(&database).await.unwrap();
+	drop(permit);
+	let first = SessionOverride::new().namespace(Ulid::new().to_string());
+	let second = SessionOverride::new().namespace(Ulid::new().to_string());
+	match db.query_with_session(first.clone(), "CREATE user:john SET name = 'John Doe'").await {
+		// Remote engines can not run queries with a different session
+		Err(Error::Api(ApiError::SessionOverrideNotSupported)) => return,
+		result => result.unwrap().check().unwrap(),
+	};
+	let mut response = db.query_with_session(first, "SELECT * FROM user:john").await.unwrap();
+	let Some(record): Option<RecordName> = response.take(0).unwrap() else {
+		panic!("query returned no record");
+	};
+	assert_eq!(record.name, "John Doe");
+	// The record is neither visible from a different namespace...
+	let mut response = db.query_with_session(second, "SELECT * FROM user:john").await.unwrap();
+	let record: Option<RecordName> = response.take(0).unwrap();
+	assert!(record.is_none());
+	// ...nor from the session of the connection, which is left untouched
+	let mut response = db.query("SELECT * FROM user:john").await.unwrap();
+	let record: Option<RecordName> = response.take(0).unwrap();
+	assert!(record.is_none());
+}
+
+#[test_log::test(tokio::test)]
+async fn query_first_rows() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	db.query("CREATE user:john SET name = 'John'; CREATE user:jane SET name = 'Jane'")
+		.await
+		.unwrap()
+		.check()
+		.unwrap();
+	let record: Option<RecordName> = db
+		.query_first("SELECT name FROM type::table($table) ORDER BY name", ("table", "user"))
+		.await
+		.unwrap();
+	assert_eq!(record.unwrap().name, "Jane");
+	let count: Option<usize> = db
+		.query_first("RETURN count(SELECT * FROM type::table($table))", ("table", "user"))
+		.await
+		.unwrap();
+	assert_eq!(count, Some(2));
+}
+
+#[test_log::test(tokio::test)]
+async fn query_first_no_rows() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let record: Option<RecordName> =
+		db.query_first("SELECT * FROM type::table($table)", ("table", "user")).await.unwrap();
+	assert!(record.is_none());
+}
+
+#[test_log::test(tokio::test)]
+async fn query_first_error() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let error = db
+		.query_first::<Value>("THROW $message; SELECT * FROM user", ("message", "boom"))
+		.await
+		.unwrap_err();
+	assert!(error.to_string().contains("boom"), "{error}");
+}
+
 #[test_log::test(tokio::test)]
 async fn mixed_results_query() {
 	let (permit, db) = new_db().await;
@@ -455,6 +763,45 @@ async fn create_record_with_id() {
 	let _: Value = db.create(Resource::from("user:doe")).await.unwrap();
 }
 
+#[test_log::test(tokio::test)]
+async fn create_one_record() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let id: RecordId = ("user", "john").into();
+	let record = db
+		.create_one(
+			id.clone(),
+			RecordBuf {
+				id: id.clone(),
+				name: "John Doe".to_owned(),
+			},
+		)
+		.await
+		.unwrap();
+	assert_eq!(record.id, id);
+	assert_eq!(record.name, "John Doe");
+}
+
+#[test_log::test(tokio::test)]
+async fn create_one_record_exists() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let id: RecordId = ("user", "jane").into();
+	let record = RecordBuf {
+		id: id.clone(),
+		name: "Jane Doe".to_owned(),
+	};
+	db.create_one(id.clone(), record.clone()).await.unwrap();
+	match db.create_one(id.clone(), record).await.unwrap_err() {
+		Error::Api(ApiError::RecordExists {
+			record,
+		}) => assert_eq!(record, id),
+		error => panic!("unexpected error: {error:?}"),
+	}
+}
+
 #[test_log::test(tokio::test)]
 async fn create_record_no_id_with_content() {
 	let (permit, db) = new_db().await;
@@ -570,6 +917,52 @@ async fn create_record_with_id_in_content() {
 		.unwrap();
 }
 
+#[test_log::test(tokio::test)]
+async fn create_record_return_none() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let () = db
+		.create::<Option<Record>>(("user", "john"))
+		.content(Record {
+			name: "John Doe".to_owned(),
+		})
+		.return_none()
+		.await
+		.unwrap();
+	let () = db.create::<Option<RecordName>>(("user", "jane")).return_none().await.unwrap();
+	let record: Option<RecordName> = db.select(("user", "john")).await.unwrap();
+	assert_eq!(record.unwrap().name, "John Doe");
+	// The other write methods can skip returning records too
+	let () = db
+		.update::<Option<RecordName>>(("user", "john"))
+		.content(Record {
+			name: "John Smith".to_owned(),
+		})
+		.return_none()
+		.await
+		.unwrap();
+	let record: Option<RecordName> = db.select(("user", "john")).await.unwrap();
+	assert_eq!(record.unwrap().name, "John Smith");
+	let () = db.delete::<Vec<RecordName>>("user").return_none().await.unwrap();
+	let records: Vec<ApiRecordId> = db.select("user").await.unwrap();
+	assert!(records.is_empty());
+	let () = db
+		.insert::<Vec<RecordName>>("user")
+		.content(vec![Record {
+			name: "Jane Doe".to_owned(),
+		}])
+		.return_none()
+		.await
+		.unwrap();
+	let records: Vec<RecordName> = db.select("user").await.unwrap();
+	assert_eq!(records.len(), 1);
+	let () = db.delete::<Vec<RecordName>>("user").return_none().await.unwrap();
+	// Errors are still reported
+	db.create::<Value>(Resource::from(("user", "jane"))).return_none().await.unwrap();
+	db.create::<Option<RecordName>>(("user", "jane")).return_none().await.unwrap_err();
+}
+
 #[test_log::test(tokio::test)]
 async fn insert_table() {
 	let (permit, db) = new_db().await;
@@ -695,6 +1088,26 @@ async fn select_record_id() {
 	);
 }
 
+#[test_log::test(tokio::test)]
+async fn select_one_present() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let record_id = ("user", "john");
+	let _: Option<ApiRecordId> = db.create(record_id).await.unwrap();
+	let record: Option<ApiRecordId> = db.select_one(record_id).await.unwrap();
+	assert_eq!(record.unwrap().id, "user:john".parse().unwrap());
+}
+
+#[test_log::test(tokio::test)]
+async fn select_one_absent() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let record: Option<ApiRecordId> = db.select_one(("user", "nobody")).await.unwrap();
+	assert!(record.is_none());
+}
+
 #[test_log::test(tokio::test)]
 async fn select_record_ranges() {
 	let (permit, db) = new_db().await;
@@ -738,6 +1151,318 @@ async fn select_record_ranges() {
 	assert_eq!(convert(users), vec!["john"]);
 }
 
+#[test_log::test(tokio::test)]
+async fn select_filter() {
+	use surrealdb::method::Filter;
+
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	db.query(
+		"
+		CREATE person:amos SET name = 'amos', age = 16, score = 5, tags = [];
+		CREATE person:jane SET name = 'jane', age = 30, score = 20, tags = ['admin'];
+		CREATE person:john SET name = 'john', age = 45, score = 8, tags = ['staff'];
+		CREATE person:zoey SET name = 'zoey', age = 21, score = 12, tags = ['staff', 'admin'];
+	",
+	)
+	.await
+	.unwrap()
+	.check()
+	.unwrap();
+	let names = |people: Vec<RecordName>| -> Vec<String> {
+		let mut names: Vec<String> = people.into_iter().map(|p| p.name).collect();
+		names.sort();
+		names
+	};
+	let select = |filter: Filter| db.select::<Vec<RecordName>>("person").filter(filter);
+	let people = select(Filter::eq("age", 30).or(Filter::ne("score", 5))).await.unwrap();
+	assert_eq!(names(people), vec!["jane", "john", "zoey"]);
+	let people = select(Filter::gte("age", 21).and(Filter::gt("score", 10))).await.unwrap();
+	assert_eq!(names(people), vec!["jane", "zoey"]);
+	let people = select(Filter::lt("age", 21).or(Filter::lte("score", 8))).await.unwrap();
+	assert_eq!(names(people), vec!["amos", "john"]);
+	let people = select(Filter::inside("name", ["amos", "zoey"])).await.unwrap();
+	assert_eq!(names(people), vec!["amos", "zoey"]);
+	let people = select(Filter::contains("tags", "staff").and(!Filter::contains("tags", "admin")))
+		.await
+		.unwrap();
+	assert_eq!(names(people), vec!["john"]);
+	// Values are bound as parameters, so they can not change the query
+	let people = select(Filter::eq("name", "amos' OR true OR '")).await.unwrap();
+	assert!(people.is_empty());
+	let people: Vec<RecordName> =
+		db.select("person").range("a".."k").filter(Filter::gt("age", 18)).await.unwrap();
+	assert_eq!(names(people), vec!["jane", "john"]);
+	let value: Value =
+		db.select(Resource::from("person")).filter(Filter::eq("age", 45)).await.unwrap();
+	let people: Vec<RecordName> = surrealdb::value::from_value(value).unwrap();
+	assert_eq!(names(people), vec!["john"]);
+}
+
+#[test_log::test(tokio::test)]
+async fn query_ast() {
+	use surrealdb_core::sql::statements::SelectStatement;
+	use surrealdb_core::sql::{Cond, Expression, Field, Idiom, Operator, Param, Query, Table};
+
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	db.query("CREATE person:amos SET name = 'amos'; CREATE person:jane SET name = 'jane'")
+		.await
+		.unwrap()
+		.check()
+		.unwrap();
+	// SELECT * FROM person WHERE name = $name
+	let mut select = SelectStatement::default();
+	select.expr.0 = vec![Field::All];
+	select.what.0 = vec![CoreValue::Table(Table::from("person"))];
+	let mut cond = Cond::default();
+	cond.0 = CoreValue::Expression(Box::new(Expression::new(
+		Idiom::from("name".to_owned()).into(),
+		Operator::Equal,
+		Param::from("name".to_owned()).into(),
+	)));
+	select.cond = Some(cond);
+	let query = Query::from(select);
+	let mut response = db.query_ast(query, ("name", "jane")).await.unwrap();
+	let people: Vec<RecordName> = response.take(0).unwrap();
+	assert_eq!(people.len(), 1);
+	assert_eq!(people[0].name, "jane");
+}
+
+#[test_log::test(tokio::test)]
+async fn buffered_transaction() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let count = |db: Surreal<_>| async move {
+		let people: Vec<ApiRecordId> = db.select("person").await.unwrap();
+		people.len()
+	};
+	// Dropped transactions write nothing
+	let transaction = db.buffered_transaction().query("CREATE person:amos");
+	drop(transaction.query("CREATE person:jane"));
+	assert_eq!(count(db.clone()).await, 0);
+	// A failing statement rolls back the earlier writes
+	let mut response = db
+		.buffered_transaction()
+		.query("CREATE person:amos")
+		.query("CREATE person:jane; THROW $reason")
+		.bind(("reason", "rolled back"))
+		.await
+		.unwrap();
+	assert!(!response.take_errors().is_empty());
+	assert_eq!(count(db.clone()).await, 0);
+	// Committed transactions apply every write
+	let mut response = db
+		.buffered_transaction()
+		.query("CREATE person:amos")
+		.query("CREATE person:jane")
+		.await
+		.unwrap();
+	let jane: Option<ApiRecordId> = response.take(1).unwrap();
+	assert!(jane.is_some());
+	assert_eq!(count(db).await, 2);
+}
+
+#[test_log::test(tokio::test)]
+async fn count_records() {
+	use surrealdb::method::Filter;
+
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	assert_eq!(db.count("person").await.unwrap(), 0);
+	assert_eq!(db.count_where("person", Filter::gt("age", 18)).await.unwrap(), 0);
+	db.query(
+		"
+		CREATE person:amos SET age = 16;
+		CREATE person:jane SET age = 30;
+		CREATE person:john SET age = 45;
+	",
+	)
+	.await
+	.unwrap()
+	.check()
+	.unwrap();
+	assert_eq!(db.count("person").await.unwrap(), 3);
+	assert_eq!(db.count_where("person", Filter::gt("age", 18)).await.unwrap(), 2);
+	assert_eq!(db.count_where("person", Filter::gt("age", 50)).await.unwrap(), 0);
+}
+
+#[test_log::test(tokio::test)]
+async fn relate_records() {
+	#[derive(Debug, Serialize, Deserialize)]
+	struct Knows {
+		since: i64,
+	}
+
+	#[derive(Debug, Deserialize)]
+	struct Edge {
+		r#in: RecordId,
+		out: RecordId,
+		since: Option<i64>,
+	}
+
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let tobie = RecordId::from(("person", "tobie"));
+	let jaime = RecordId::from(("person", "jaime"));
+	let john = RecordId::from(("person", "john"));
+
+	let edge = db
+		.relate(
+			[tobie.clone()],
+			"knows",
+			[jaime.clone()],
+			Some(Knows {
+				since: 2020,
+			}),
+		)
+		.await
+		.unwrap();
+	let edges: Vec<Edge> = edge.into_typed().unwrap();
+	assert_eq!(edges.len(), 1);
+	assert_eq!(edges[0].r#in, tobie);
+	assert_eq!(edges[0].out, jaime);
+	assert_eq!(edges[0].since, Some(2020));
+
+	db.relate([tobie.clone()], "likes", [jaime.clone(), john.clone()], None::<()>).await.unwrap();
+	let mut response = db.query("SELECT * FROM likes ORDER BY out").await.unwrap();
+	let edges: Vec<Edge> = response.take(0).unwrap();
+	assert_eq!(edges.len(), 2);
+	assert!(edges.iter().all(|edge| edge.r#in == tobie && edge.since.is_none()));
+	assert_eq!(edges[0].out, jaime);
+	assert_eq!(edges[1].out, john);
+}
+
+#[test_log::test(tokio::test)]
+async fn select_fetch() {
+	use surrealdb::method::Filter;
+
+	#[derive(Debug, Deserialize)]
+	struct Post {
+		title: String,
+		author: RecordName,
+		comments: Vec<RecordName>,
+	}
+
+	#[derive(Debug, Deserialize)]
+	struct PostAuthorId {
+		author: RecordId,
+	}
+
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	db.query(
+		"
+		CREATE person:jane SET name = 'jane';
+		CREATE person:john SET name = 'john';
+		CREATE comment:one SET name = 'first';
+		CREATE comment:two SET name = 'second';
+		CREATE post:a SET title = 'a', author = person:jane, comments = [comment:one, comment:two];
+		CREATE post:b SET title = 'b', author = person:john, comments = [];
+	",
+	)
+	.await
+	.unwrap()
+	.check()
+	.unwrap();
+	// Without a fetch the fields only hold record ids
+	let post: Option<PostAuthorId> = db.select(("post", "a")).await.unwrap();
+	assert_eq!(post.unwrap().author, RecordId::from(("person", "jane")));
+	let mut posts: Vec<Post> = db.select("post").fetch(&["author", "comments"]).await.unwrap();
+	posts.sort_by(|a, b| a.title.cmp(&b.title));
+	assert_eq!(posts.len(), 2);
+	assert_eq!(posts[0].author.name, "jane");
+	let comments: Vec<&str> = posts[0].comments.iter().map(|c| c.name.as_str()).collect();
+	assert_eq!(comments, ["first", "second"]);
+	assert_eq!(posts[1].author.name, "john");
+	let post: Option<Post> = db.select(("post", "b")).fetch(&["author", "comments"]).await.unwrap();
+	assert_eq!(post.unwrap().author.name, "john");
+	let posts: Vec<Post> = db
+		.select("post")
+		.filter(Filter::eq("title", "a"))
+		.fetch(&["author"])
+		.fetch(&["comments"])
+		.await
+		.unwrap();
+	assert_eq!(posts.len(), 1);
+	assert_eq!(posts[0].author.name, "jane");
+}
+
+#[test_log::test(tokio::test)]
+async fn select_range_sequential_keys() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let table = "item";
+	for i in 1..=10 {
+		let _: Option<ApiRecordId> = db.create((table, i)).await.unwrap();
+	}
+	let convert = |items: Vec<ApiRecordId>| -> Vec<i64> {
+		items
+			.into_iter()
+			.map(|item| {
+				let Id::Number(x) = item.id.into_inner().id else {
+					panic!()
+				};
+				x
+			})
+			.collect()
+	};
+	let items: Vec<ApiRecordId> = db.select_range(table, 3..6).await.unwrap();
+	assert_eq!(convert(items), vec![3, 4, 5]);
+	let items: Vec<ApiRecordId> = db.select_range(table, 3..=6).await.unwrap();
+	assert_eq!(convert(items), vec![3, 4, 5, 6]);
+	let items: Vec<ApiRecordId> =
+		db.select_range(table, (Bound::Excluded(8), Bound::Unbounded)).await.unwrap();
+	assert_eq!(convert(items), vec![9, 10]);
+}
+
+#[test_log::test(tokio::test)]
+async fn select_paginated() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let table = "item";
+	for i in (2..=20).step_by(2) {
+		let _: Option<ApiRecordId> = db.create((table, i)).await.unwrap();
+	}
+	let mut seen = Vec::new();
+	let mut cursor = None;
+	let mut pages = 0;
+	loop {
+		let page = db.select_paginated::<ApiRecordId>(table, cursor, 3).await.unwrap();
+		assert!(page.rows.len() <= 3);
+		seen.extend(page.rows.into_iter().map(|item| {
+			let Id::Number(x) = item.id.into_inner().id else {
+				panic!()
+			};
+			x
+		}));
+		pages += 1;
+		if pages == 1 {
+			// Records written before and after the cursor while paging
+			let _: Option<ApiRecordId> = db.create((table, 1)).await.unwrap();
+			let _: Option<ApiRecordId> = db.create((table, 11)).await.unwrap();
+		}
+		match page.next {
+			Some(next) => cursor = Some(next),
+			None => break,
+		}
+	}
+	assert_eq!(seen, [2, 4, 6, 8, 10, 11, 12, 14, 16, 18, 20]);
+	assert_eq!(pages, 4);
+	// An empty table has a single empty page
+	let page = db.select_paginated::<ApiRecordId>("empty", None, 3).await.unwrap();
+	assert!(page.rows.is_empty());
+	assert!(page.next.is_none());
+}
+
 #[test_log::test(tokio::test)]
 async fn select_records_order_by_start_limit() {
 	let (permit, db) = new_db().await;
@@ -1098,6 +1823,196 @@ async fn merge_record_id() {
 	);
 }
 
+#[test_log::test(tokio::test)]
+async fn upsert_one_record_create() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let jaime = db
+		.upsert_one(
+			("person", "jaime"),
+			Person {
+				id: None,
+				title: "Founder & COO".into(),
+				name: Name {
+					first: "Jaime".into(),
+					last: "Morgan Hitchcock".into(),
+				},
+				marketing: false,
+			},
+		)
+		.await
+		.unwrap();
+	assert_eq!(jaime.id.unwrap(), "person:jaime".parse().unwrap());
+	assert_eq!(jaime.title, "Founder & COO");
+	assert!(!jaime.marketing);
+}
+
+#[test_log::test(tokio::test)]
+async fn upsert_one_record_merge_existing() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let record_id = ("person", "jaime");
+	let _: Option<ApiRecordId> = db
+		.create(record_id)
+		.content(json!({
+			"title": "Founder",
+			"name": { "first": "Jaime", "last": "Morgan Hitchcock" },
+			"marketing": false,
+			"company": "SurrealDB",
+		}))
+		.await
+		.unwrap();
+	let jaime = db
+		.upsert_one(
+			record_id,
+			Person {
+				id: None,
+				title: "Founder & COO".into(),
+				name: Name {
+					first: "Jaime".into(),
+					last: "Morgan Hitchcock".into(),
+				},
+				marketing: true,
+			},
+		)
+		.await
+		.unwrap();
+	assert_eq!(
+		jaime,
+		Person {
+			id: Some("person:jaime".parse().unwrap()),
+			title: "Founder & COO".into(),
+			name: Name {
+				first: "Jaime".into(),
+				last: "Morgan Hitchcock".into(),
+			},
+			marketing: true,
+		}
+	);
+	let mut response = db
+		.query("SELECT VALUE company FROM ONLY $id")
+		.bind(("id", RecordId::from(record_id)))
+		.await
+		.unwrap();
+	let company: Option<String> = response.take(0).unwrap();
+	assert_eq!(company.as_deref(), Some("SurrealDB"));
+}
+
+#[test_log::test(tokio::test)]
+async fn update_if_version_matches() {
+	#[derive(Debug, Serialize, Deserialize, PartialEq)]
+	struct Account {
+		balance: i64,
+		version: i64,
+	}
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let record_id = ("account", "tobie");
+	let _: Option<ApiRecordId> = db
+		.create(record_id)
+		.content(json!({ "balance": 100, "version": 1, "owner": "Tobie" }))
+		.await
+		.unwrap();
+	let account = Account {
+		balance: 200,
+		version: 1,
+	};
+	let account = db.update_if_version(record_id, 1, account).await.unwrap();
+	assert_eq!(
+		account,
+		Some(Account {
+			balance: 200,
+			version: 2,
+		})
+	);
+	// Fields which are not part of the content are left untouched
+	let mut response = db
+		.query("SELECT VALUE owner FROM ONLY $id")
+		.bind(("id", RecordId::from(record_id)))
+		.await
+		.unwrap();
+	let owner: Option<String> = response.take(0).unwrap();
+	assert_eq!(owner.as_deref(), Some("Tobie"));
+}
+
+#[test_log::test(tokio::test)]
+async fn update_if_version_conflict() {
+	#[derive(Debug, Serialize, Deserialize, PartialEq)]
+	struct Account {
+		balance: i64,
+		version: i64,
+	}
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let record_id = ("account", "tobie");
+	let _: Option<ApiRecordId> =
+		db.create(record_id).content(json!({ "balance": 100, "version": 3 })).await.unwrap();
+	let account = Account {
+		balance: 200,
+		version: 2,
+	};
+	let account = db.update_if_version(record_id, 2, account).await.unwrap();
+	assert_eq!(account, None);
+	let account: Option<Account> = db.select(record_id).await.unwrap();
+	assert_eq!(
+		account,
+		Some(Account {
+			balance: 100,
+			version: 3,
+		})
+	);
+}
+
+#[test_log::test(tokio::test)]
+async fn update_if_version_overflow() {
+	#[derive(Debug, Serialize, Deserialize, PartialEq)]
+	struct Account {
+		balance: i64,
+		version: i64,
+	}
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let record_id = ("account", "tobie");
+	let _: Option<ApiRecordId> =
+		db.create(record_id).content(json!({ "balance": 100, "version": i64::MAX })).await.unwrap();
+	let account = Account {
+		balance: 200,
+		version: i64::MAX,
+	};
+	db.update_if_version(record_id, i64::MAX, account).await.unwrap_err();
+	let account: Option<Account> = db.select(record_id).await.unwrap();
+	assert_eq!(
+		account,
+		Some(Account {
+			balance: 100,
+			version: i64::MAX,
+		})
+	);
+}
+
+#[test_log::test(tokio::test)]
+async fn with_transaction_retry_non_retryable() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let attempts = std::sync::atomic::AtomicUsize::new(0);
+	let error = db
+		.with_transaction_retry(3, || async {
+			attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+			db.query("THROW 'not a conflict'").await?.check()?;
+			Ok(())
+		})
+		.await
+		.unwrap_err();
+	assert!(!error.is_retryable());
+	assert_eq!(attempts.into_inner(), 1);
+}
+
 #[test_log::test(tokio::test)]
 async fn patch_record_id() {
 	#[derive(Debug, Deserialize, PartialEq)]
@@ -1137,6 +2052,70 @@ async fn patch_record_id() {
 	);
 }
 
+#[test_log::test(tokio::test)]
+async fn patch_one_record() {
+	#[derive(Debug, Deserialize, PartialEq)]
+	struct Record {
+		id: RecordId,
+		baz: String,
+		hello: Vec<String>,
+	}
+
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let _: Option<ApiRecordId> = db
+		.create(("user", "john"))
+		.content(json!({
+			"baz": "qux",
+			"foo": "bar"
+		}))
+		.await
+		.unwrap();
+	let record: Record = db
+		.patch(
+			("user", "john"),
+			vec![
+				PatchOp::replace("/baz", "boo"),
+				PatchOp::add("/hello", ["world"]),
+				PatchOp::remove("/foo"),
+			],
+		)
+		.await
+		.unwrap();
+	assert_eq!(
+		record,
+		Record {
+			id: "user:john".parse().unwrap(),
+			baz: "boo".to_owned(),
+			hello: vec!["world".to_owned()],
+		}
+	);
+}
+
+#[test_log::test(tokio::test)]
+async fn patch_one_record_invalid() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let _: Option<ApiRecordId> = db.create(("user", "john")).await.unwrap();
+	match db.patch::<Value>(("user", "john"), vec![PatchOp::remove("foo")]).await.unwrap_err() {
+		Error::Api(ApiError::InvalidPatchPath(path)) => assert_eq!(path, "foo"),
+		error => panic!("unexpected error: {error:?}"),
+	}
+	match db.patch::<Value>(("user", "john"), vec![PatchOp::remove("/a~2b")]).await.unwrap_err() {
+		Error::Api(ApiError::InvalidPatchPath(path)) => assert_eq!(path, "/a~2b"),
+		error => panic!("unexpected error: {error:?}"),
+	}
+	let id: RecordId = ("user", "jane").into();
+	match db.patch::<Value>(id.clone(), vec![PatchOp::remove("/foo")]).await.unwrap_err() {
+		Error::Api(ApiError::RecordNotFound {
+			record,
+		}) => assert_eq!(record, id),
+		error => panic!("unexpected error: {error:?}"),
+	}
+}
+
 #[test_log::test(tokio::test)]
 async fn delete_table() {
 	let (permit, db) = new_db().await;
@@ -1217,6 +2196,33 @@ async fn delete_record_range() {
 	);
 }
 
+#[test_log::test(tokio::test)]
+async fn delete_range_sequential_keys() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let table = "item";
+	for i in 1..=10 {
+		let _: Option<ApiRecordId> = db.create((table, i)).await.unwrap();
+	}
+	let convert = |items: Vec<ApiRecordId>| -> Vec<i64> {
+		items
+			.into_iter()
+			.map(|item| {
+				let Id::Number(x) = item.id.into_inner().id else {
+					panic!()
+				};
+				x
+			})
+			.collect()
+	};
+	assert_eq!(db.delete_range(table, 3..6).await.unwrap(), 3);
+	assert_eq!(db.delete_range(table, 8..=9).await.unwrap(), 2);
+	assert_eq!(db.delete_range(table, 4..5).await.unwrap(), 0);
+	let items: Vec<ApiRecordId> = db.select(table).await.unwrap();
+	assert_eq!(convert(items), vec![1, 2, 6, 7, 10]);
+}
+
 #[test_log::test(tokio::test)]
 async fn changefeed() {
 	let (permit, db) = new_db().await;
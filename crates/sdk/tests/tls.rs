@@ -0,0 +1,79 @@
+#![cfg(all(feature = "protocol-http", feature = "rustls", not(target_arch = "wasm32")))]
+
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer};
+use std::sync::Arc;
+use surrealdb::engine::remote::http::Https;
+use surrealdb::Surreal;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+use tokio_rustls::TlsAcceptor;
+
+/// Starts a server with a self-signed certificate for `localhost` which answers every request
+/// with an empty `200 OK`
+///
+/// Returns the port, the certificate and a receiver yielding the request line of every request
+/// received over an established TLS session.
+async fn self_signed_server() -> (u16, CertificateDer<'static>, UnboundedReceiver<String>) {
+	let cert = rcgen::generate_simple_self_signed(vec!["localhost".to_owned()]).unwrap();
+	let cert_der = CertificateDer::from(cert.serialize_der().unwrap());
+	let key_der = PrivateKeyDer::from(PrivatePkcs8KeyDer::from(cert.serialize_private_key_der()));
+	let provider = Arc::new(rustls::crypto::ring::default_provider());
+	let config = rustls::ServerConfig::builder_with_provider(provider)
+		.with_safe_default_protocol_versions()
+		.unwrap()
+		.with_no_client_auth()
+		.with_single_cert(vec![cert_der.clone()], key_der)
+		.unwrap();
+	let acceptor = TlsAcceptor::from(Arc::new(config));
+	let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+	let port = listener.local_addr().unwrap().port();
+	let (tx, rx) = mpsc::unbounded_channel();
+	tokio::spawn(async move {
+		loop {
+			let Ok((stream, _)) = listener.accept().await else {
+				return;
+			};
+			let acceptor = acceptor.clone();
+			let tx = tx.clone();
+			tokio::spawn(async move {
+				let Ok(mut stream) = acceptor.accept(stream).await else {
+					return;
+				};
+				let mut buf = [0; 4096];
+				if let Ok(len) = stream.read(&mut buf).await {
+					let request = String::from_utf8_lossy(&buf[..len]);
+					let _ = tx.send(request.lines().next().unwrap_or_default().to_owned());
+					let response =
+						"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+					let _ = stream.write_all(response.as_bytes()).await;
+					let _ = stream.shutdown().await;
+				}
+			});
+		}
+	});
+	(port, cert_der, rx)
+}
+
+#[tokio::test]
+async fn connect_with_custom_root() {
+	let (port, cert, mut requests) = self_signed_server().await;
+	let mut roots = rustls::RootCertStore::empty();
+	roots.add(cert).unwrap();
+	let provider = Arc::new(rustls::crypto::ring::default_provider());
+	let config = rustls::ClientConfig::builder_with_provider(provider)
+		.with_safe_default_protocol_versions()
+		.unwrap()
+		.with_root_certificates(roots)
+		.with_no_client_auth();
+	// The server doesn't speak the RPC protocol, so only the health check is expected to succeed
+	let _ = Surreal::new::<Https>(format!("localhost:{port}")).with_tls_config(config).await;
+	assert_eq!(requests.recv().await.unwrap(), "GET /health HTTP/1.1");
+}
+
+#[tokio::test]
+async fn connect_without_custom_root() {
+	let (port, _, mut requests) = self_signed_server().await;
+	Surreal::new::<Https>(format!("localhost:{port}")).await.unwrap_err();
+	assert!(requests.try_recv().is_err());
+}